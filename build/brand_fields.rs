@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+// Components named by more than one word. Every other key belongs to the component named by
+// its first word, e.g. `button_primary` to `Button`.
+const MULTI_WORD_COMPONENTS: &[&str] = &[
+    "group_button",
+    "multicol_sidebar",
+    "page_header",
+    "panel_group",
+    "tag_input",
+    "tailwind_color_picker",
+];
+
+// Keys that don't belong to any single component
+const GENERAL_KEYS: &[&str] = &["default_separator"];
+const GENERAL: &str = "General";
+
+/// The component a brand guide key styles and a label for the key within it, e.g.
+/// `("TagInput", "Remove button")` for `tag_input_remove_button`.
+pub fn component_of(key: &str) -> (String, String) {
+    if GENERAL_KEYS.contains(&key) {
+        return (GENERAL.to_string(), humanize(key));
+    }
+    let prefix = MULTI_WORD_COMPONENTS
+        .iter()
+        .copied()
+        .find(|prefix| key.starts_with(&format!("{}_", prefix)))
+        .unwrap_or_else(|| key.split('_').next().unwrap_or(key));
+    let rest = key[prefix.len()..].trim_start_matches('_');
+    let component: String = prefix.split('_').map(capitalize).collect();
+    let label = if rest.is_empty() {
+        component.clone()
+    } else {
+        humanize(rest)
+    };
+    (component, label)
+}
+
+/// A key of the brand guide with the component it styles.
+pub struct BrandKey {
+    pub key: String,
+    pub component: String,
+    pub label: String,
+    /// Declared under `"components"` in the user config rather than built in
+    pub custom: bool,
+}
+
+impl BrandKey {
+    pub fn builtin(key: &str) -> Self {
+        let (component, label) = component_of(key);
+        BrandKey {
+            key: key.to_string(),
+            component,
+            label,
+            custom: false,
+        }
+    }
+
+    /// A key of a component outside wonopui, labelled without the component prefix, e.g.
+    /// `"Header"` for `data_grid_header` of `DataGrid`.
+    pub fn custom(component: &str, key: &str) -> Self {
+        let prefix = format!("{}_", snake_case(component));
+        let label = match key.strip_prefix(&prefix) {
+            Some(rest) if !rest.is_empty() => humanize(rest),
+            _ => humanize(key),
+        };
+        BrandKey {
+            key: key.to_string(),
+            component: component.to_string(),
+            label,
+            custom: true,
+        }
+    }
+}
+
+/// Writes `BrandGuide` with a field per key, `BRANDGUIDE` holding the rendered `values`,
+/// `BRAND_FIELDS` and the field accessors to a new generated file.
+pub fn write_brand_guide(
+    filename: &Path,
+    keys: &[BrandKey],
+    values: &HashMap<String, String>,
+) -> std::io::Result<()> {
+    let mut f = fs::File::create(filename)?;
+    writeln!(
+        f,
+        "/// The classes of every component, one field per brand guide key."
+    )?;
+    writeln!(f, "#[cfg_attr(feature = \"ThemeProvider\", derive(Clone))]")?;
+    writeln!(f, "#[derive(PartialEq)]")?;
+    writeln!(f, "pub struct BrandGuide<T> {{")?;
+    for BrandKey { key, .. } in keys {
+        writeln!(f, "    pub {}: ClassesContainer<T>,", key)?;
+    }
+    writeln!(f, "}}")?;
+
+    writeln!(f, "impl BrandGuide<&'static str> {{")?;
+    writeln!(f, "    pub fn to_owned(&self) -> BrandGuide<String> {{")?;
+    writeln!(f, "        BrandGuide {{")?;
+    for BrandKey { key, .. } in keys {
+        writeln!(f, "            {}: self.{}.to_owned(),", key, key)?;
+    }
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+
+    writeln!(
+        f,
+        "pub const BRANDGUIDE: BrandGuide<&'static str> = BrandGuide::<&'static str> {{"
+    )?;
+    for BrandKey { key, .. } in keys {
+        writeln!(
+            f,
+            "    {}: ClassesContainer::<&'static str> {{ value: {:?} }},",
+            key, values[key]
+        )?;
+    }
+    writeln!(f, "}};")?;
+
+    writeln!(f, "pub static BRAND_FIELDS: &[BrandField] = &[")?;
+    for BrandKey {
+        key,
+        component,
+        label,
+        custom,
+    } in keys
+    {
+        writeln!(
+            f,
+            "    BrandField {{ key: {:?}, component: {:?}, label: {:?}, custom: {} }},",
+            key, component, label, custom
+        )?;
+    }
+    writeln!(f, "];")?;
+
+    writeln!(f, "#[cfg(feature = \"ThemeProvider\")]")?;
+    writeln!(
+        f,
+        "impl crate::component_editor::BrandGuideValueAccessor for BrandGuide<String> {{"
+    )?;
+    writeln!(f, "    fn get_value(&self, field_id: &str) -> String {{")?;
+    writeln!(f, "        match field_id {{")?;
+    for BrandKey { key, .. } in keys {
+        writeln!(f, "            {:?} => self.{}.value.clone(),", key, key)?;
+    }
+    writeln!(f, "            _ => String::new(),")?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(
+        f,
+        "    fn set_value(&mut self, field_id: &str, value: String) {{"
+    )?;
+    writeln!(f, "        match field_id {{")?;
+    for BrandKey { key, .. } in keys {
+        writeln!(f, "            {:?} => self.{}.value = value,", key, key)?;
+    }
+    writeln!(f, "            _ => {{}}")?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    Ok(())
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn humanize(key: &str) -> String {
+    capitalize(&key.replace('_', " "))
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+/// Tailwind's screens from small to large, with the token holding the minimum width of each.
+const SCREENS: [(&str, &str); 5] = [
+    ("sm", "breakpoint_sm"),
+    ("md", "breakpoint_md"),
+    ("lg", "breakpoint_lg"),
+    ("xl", "breakpoint_xl"),
+    ("2xl", "breakpoint_2xl"),
+];
+
+/// The screens and their minimum widths, e.g. `("lg", "1024px")`, taken from the rendered tokens.
+/// Fails when a width isn't a length Tailwind and media queries both accept.
+pub fn widths(tokens: &HashMap<String, String>) -> Result<Vec<(&'static str, String)>, String> {
+    SCREENS
+        .iter()
+        .map(|(screen, token)| {
+            let width = tokens[*token].trim();
+            if is_width(width) {
+                Ok((*screen, width.to_string()))
+            } else {
+                Err(format!(
+                    "`{}` must be a width such as `1024px` or `64rem`, not `{}`",
+                    token, width
+                ))
+            }
+        })
+        .collect()
+}
+
+// A non-negative number in `px`, `rem` or `em`
+fn is_width(width: &str) -> bool {
+    ["px", "rem", "em"].iter().any(|unit| {
+        width
+            .strip_suffix(unit)
+            .is_some_and(|number| number.parse::<f64>().is_ok_and(|n| n >= 0.0))
+    })
+}
+
+/// Appends the widths to the generated config, in the order of `Breakpoint`.
+pub fn write_widths(filename: &Path, widths: &[(&str, String)]) -> std::io::Result<()> {
+    let mut f = fs::OpenOptions::new().append(true).open(filename)?;
+    writeln!(f, "#[cfg(feature = \"Breakpoint\")]")?;
+    writeln!(
+        f,
+        "pub static BREAKPOINT_WIDTHS: [&str; {}] = [",
+        widths.len()
+    )?;
+    for (_, width) in widths {
+        writeln!(f, "    {:?},", width)?;
+    }
+    writeln!(f, "];")?;
+    Ok(())
+}
+
+/// Writes the widths as `theme.screens` for a Tailwind v3 config, so `lg:` classes switch where
+/// `use_breakpoint` does.
+pub fn write_screens(dir: &Path, widths: &[(&str, String)]) -> std::io::Result<()> {
+    let mut f = fs::File::create(dir.join("tailwind.screens.js"))?;
+    writeln!(f, "// Generated by the wonopui build script")?;
+    writeln!(f, "module.exports = {{")?;
+    for (screen, width) in widths {
+        writeln!(f, "  {:?}: {:?},", screen, width)?;
+    }
+    writeln!(f, "}};")?;
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+// Color families backed by variables, with the Tailwind colors they replace (light, dark, darker)
+pub const FAMILIES: &[(&str, [&str; 3])] = &[
+    ("primary", ["#6366f1", "#4f46e5", "#4338ca"]),
+    ("secondary", ["#71717a", "#52525b", "#3f3f46"]),
+    ("error", ["#ef4444", "#dc2626", "#b91c1c"]),
+    ("success", ["#10b981", "#059669", "#047857"]),
+    ("warning", ["#f59e0b", "#d97706", "#b45309"]),
+];
+
+/// Whether the `CssVariables` feature is enabled.
+pub fn enabled() -> bool {
+    env::var("CARGO_FEATURE_CSSVARIABLES").is_ok()
+}
+
+/// Every variable with its default color, e.g. `("--wonop-primary", "#6366f1")`.
+pub fn css_variables() -> Vec<(String, String)> {
+    let mut variables = Vec::new();
+    for (family, [light, dark, darker]) in FAMILIES {
+        variables.push((format!("--wonop-{}", family), light.to_string()));
+        variables.push((format!("--wonop-{}-dark", family), dark.to_string()));
+        variables.push((format!("--wonop-{}-darker", family), darker.to_string()));
+    }
+    variables
+}
+
+/// Token templates that point the color tokens at the variables instead of the palette.
+pub fn css_variable_templates() -> Vec<(String, String)> {
+    let mut templates = Vec::new();
+    for (family, _) in FAMILIES {
+        templates.push((
+            format!("{}_light", family),
+            format!("[var(--wonop-{})]", family),
+        ));
+        templates.push((
+            format!("{}_dark", family),
+            format!("[var(--wonop-{}-dark)]", family),
+        ));
+        templates.push((
+            format!("{}_darker", family),
+            format!("[var(--wonop-{}-darker)]", family),
+        ));
+    }
+    // Tailwind can't apply an opacity modifier to an arbitrary `var()` color
+    templates.push(("default_opacity_addon".to_string(), String::new()));
+    templates.push(("default_opacity_addon_hover".to_string(), String::new()));
+    templates
+}
+
+/// Writes the variables as a `:root` rule for apps that don't set them at runtime.
+pub fn write_css_file(path: &Path, variables: &[(String, String)]) -> std::io::Result<()> {
+    let mut f = fs::File::create(path)?;
+    writeln!(f, ":root {{")?;
+    for (name, value) in variables {
+        writeln!(f, "  {}: {};", name, value)?;
+    }
+    writeln!(f, "}}")?;
+    Ok(())
+}
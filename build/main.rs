@@ -1,134 +1,94 @@
-mod brand_config;
+mod brand_fields;
+mod breakpoints;
+mod css_variables;
 mod default_config;
+mod safelist;
+mod tailwind_v4;
+mod user_config;
 mod valid_classes;
-use brand_config::BrandConfig as Config;
-use default_config::get_default_config;
-use valid_classes::is_valid_tailwind_class;
+use brand_fields::BrandKey;
+use default_config::{default_templates, get_config_with_overrides};
+use valid_classes::check_class;
 
 // build.rs
+use std::collections::BTreeSet;
 use std::collections::HashMap;
-use std::collections::HashSet;
 use std::env;
 use std::fs;
 use std::fs::File;
-use std::io::Read;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
-use std::time::Instant;
 
+// Where the build writes `wonopui.json`, the safelist and the CSS files: `WONOPUI_OUTPUT_DIR` if
+// set, else the target directory
 fn get_base_dir() -> PathBuf {
-    let out_dir = env::var("OUT_DIR")
-        .expect("Failed to get OUT_DIR environment variable in create_baseclasses()");
-    let target_dir = if out_dir.contains("/target/") {
-        println!("Found '/target/' in OUT_DIR: {}", out_dir);
-        let parts: Vec<&str> = out_dir.split("/target/").collect();
-        Path::new(parts[0]).join("target")
-    } else if out_dir.contains("/release/") || out_dir.contains("/debug/") {
-        let parts: Vec<&str> = out_dir.split("/release/").collect();
-        let parts = if parts.len() == 1 {
-            println!("Found '/debug/' in OUT_DIR: {}", out_dir);
-            out_dir.split("/debug/").collect()
-        } else {
-            println!("Found '/release/' in OUT_DIR: {}", out_dir);
-            parts
-        };
-        Path::new(parts[0]).to_path_buf()
-    } else {
-        println!("Found 'target' in OUT_DIR: {}", out_dir);
-
-        let target_pos = out_dir
-            .find("target")
-            .expect("Failed to find 'target' in OUT_DIR");
-        let remaining = &out_dir[target_pos..];
-        let next_slash = remaining.find('/').unwrap_or(remaining.len());
-        Path::new(&out_dir[..target_pos + next_slash]).to_path_buf()
+    let dir = match env::var_os("WONOPUI_OUTPUT_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => get_target_dir(),
     };
-    fs::canonicalize(&target_dir).unwrap_or_else(|_| target_dir.to_path_buf())
+    fs::create_dir_all(&dir)
+        .unwrap_or_else(|error| panic!("Unable to create output directory {:?}: {}", dir, error));
+    fs::canonicalize(&dir).unwrap_or(dir)
 }
 
-fn create_baseclasses() {
-    let target_dir = get_base_dir();
-    println!("Target dir: {:#?}", target_dir);
-
-    let baseclasses_path = target_dir.join("tailwindcss.txt");
-
-    let mut classes = HashSet::new();
-
-    // Recursively find all .rs files and extract Tailwind CSS classes
-
-    visit_dirs(Path::new("."), &mut |file_path| {
-        let absolute_path = fs::canonicalize(file_path).unwrap_or_else(|_| file_path.to_path_buf());
-
-        // Ignore the actual target folder
-        if absolute_path.starts_with(&target_dir)
-            || file_path.starts_with("./target/")
-            || file_path.starts_with("./target/")
-        {
-            return;
-        }
-        if file_path.extension().and_then(|s| s.to_str()) == Some("rs") {
-            let start = Instant::now();
-
-            let content = fs::read_to_string(file_path)
-                .expect(&format!("Unable to read file: {:?}", file_path));
-            let mut word = String::new();
-            for c in content.chars() {
-                if c.is_whitespace() || " ,._;(){}\"'`".contains(c) {
-                    if !classes.contains(&word)
-                        && !word.is_empty()
-                        && is_valid_tailwind_class(&word)
-                    {
-                        // println!("Adding {}", word);
-                        classes.insert(word.clone());
-                    }
-                    word.clear();
-                } else {
-                    word.push(c);
-                }
-            }
-            // Check the last word if the file doesn't end with a delimiter
-            if !word.is_empty() && is_valid_tailwind_class(&word) {
-                classes.insert(word);
-            }
-            let _duration = start.elapsed();
+// The target directory of the build. Cargo only passes `OUT_DIR`, which is
+// `<target>/[<triple>/]<profile>/build/<package>-<hash>/out`, so unless `CARGO_TARGET_DIR` is an
+// absolute path, walk up to the directory cargo tagged with `CACHEDIR.TAG`.
+fn get_target_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("CARGO_TARGET_DIR").map(PathBuf::from) {
+        if dir.is_absolute() {
+            return dir;
         }
-    })
-    .expect("Error visiting directories in create_baseclasses()");
-
-    let mut classes: Vec<_> = classes.into_iter().collect();
-    classes.sort();
-    let mut file = fs::File::create(&baseclasses_path)
-        .expect(&format!("Unable to create file: {:?}", baseclasses_path));
-    for class in &classes {
-        writeln!(file, "{}", class)
-            .expect(&format!("Unable to write to file: {:?}", baseclasses_path));
     }
+    let out_dir = PathBuf::from(
+        env::var_os("OUT_DIR")
+            .expect("Failed to get OUT_DIR environment variable in get_target_dir()"),
+    );
+    let Some(profile_dir) = out_dir
+        .ancestors()
+        .find(|dir| dir.file_name() == Some("build".as_ref()))
+        .and_then(Path::parent)
+    else {
+        return out_dir;
+    };
+    profile_dir
+        .ancestors()
+        .skip(1)
+        .find(|dir| dir.join("CACHEDIR.TAG").is_file())
+        .or_else(|| profile_dir.parent())
+        .unwrap_or(profile_dir)
+        .to_path_buf()
 }
 
-// Function to recursively visit directories and apply a callback to each file
-fn visit_dirs(dir: &Path, cb: &mut dyn FnMut(&Path)) -> std::io::Result<()> {
-    if dir.is_dir() {
-        for entry in fs::read_dir(dir)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.is_dir() {
-                visit_dirs(&path, cb)?;
-            } else {
-                cb(&path);
-            }
-        }
+// Writes the safelist: the brand config classes and the classes hard-coded in the components of
+// the enabled features
+fn create_baseclasses<'a>(brand_classes: impl Iterator<Item = &'a str>) {
+    let target_dir = get_base_dir();
+
+    let mut classes: BTreeSet<String> = brand_classes.map(String::from).collect();
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR")
+        .expect("Failed to get CARGO_MANIFEST_DIR environment variable in create_baseclasses()");
+    let lib_rs = Path::new(&manifest_dir).join("src").join("lib.rs");
+    for file_path in safelist::enabled_sources(&lib_rs) {
+        println!("cargo:rerun-if-changed={}", file_path.display());
+        let content = fs::read_to_string(&file_path)
+            .unwrap_or_else(|_| panic!("Unable to read file: {:?}", file_path));
+        classes.extend(
+            safelist::literal_classes(&content)
+                .into_iter()
+                .filter(|word| check_class(word, &[]).is_ok())
+                .map(String::from),
+        );
     }
-    Ok(())
-}
 
-fn get_default_value(key: &str, map: &HashMap<String, String>) -> String {
-    map.get(key)
-        .expect(&format!(
-            "Template parameter missing: {} in get_default_value()",
-            key
-        ))
-        .clone()
+    safelist::write_safelist(&target_dir, &classes).unwrap_or_else(|error| {
+        panic!(
+            "Unable to write the safelist to {:?}: {}",
+            target_dir, error
+        )
+    });
 }
 
 fn main() {
@@ -136,349 +96,6 @@ fn main() {
         env::var("OUT_DIR").expect("Failed to get OUT_DIR environment variable in main()");
     let dest_path = Path::new(&out_dir).join("config.rs");
 
-    create_baseclasses();
-
-    // Default values if config file is not provided
-    let default_config_hm =
-        get_default_config().expect("Unable to generate config - error in template in main()");
-    let default_config = Config {
-        default_separator: get_default_value("default_separator", &default_config_hm),
-        typography_h1: get_default_value("typography_h1", &default_config_hm),
-        typography_h2: get_default_value("typography_h2", &default_config_hm),
-        typography_h3: get_default_value("typography_h3", &default_config_hm),
-        typography_h4: get_default_value("typography_h4", &default_config_hm),
-        typography_h5: get_default_value("typography_h5", &default_config_hm),
-        typography_h6: get_default_value("typography_h6", &default_config_hm),
-        typography_p: get_default_value("typography_p", &default_config_hm),
-        input_base: get_default_value("input_base", &default_config_hm),
-        label_base: get_default_value("label_base", &default_config_hm),
-        textarea_base: get_default_value("textarea_base", &default_config_hm),
-        textarea_disabled: get_default_value("textarea_disabled", &default_config_hm),
-        alert_success: get_default_value("alert_success", &default_config_hm),
-        alert_warning: get_default_value("alert_warning", &default_config_hm),
-        alert_error: get_default_value("alert_error", &default_config_hm),
-        alert_info: get_default_value("alert_info", &default_config_hm),
-        alert_base: get_default_value("alert_base", &default_config_hm),
-        alert_title: get_default_value("alert_title", &default_config_hm),
-        alert_description: get_default_value("alert_description", &default_config_hm),
-        avatar_small: get_default_value("avatar_small", &default_config_hm),
-        avatar_medium: get_default_value("avatar_medium", &default_config_hm),
-        avatar_large: get_default_value("avatar_large", &default_config_hm),
-        avatar_base: get_default_value("avatar_base", &default_config_hm),
-        badge_success: get_default_value("badge_success", &default_config_hm),
-        badge_warning: get_default_value("badge_warning", &default_config_hm),
-        badge_error: get_default_value("badge_error", &default_config_hm),
-        badge_info: get_default_value("badge_info", &default_config_hm),
-        badge_default: get_default_value("badge_default", &default_config_hm),
-        badge_base: get_default_value("badge_base", &default_config_hm),
-        breadcrumb_nav: get_default_value("breadcrumb_nav", &default_config_hm),
-        breadcrumb_list: get_default_value("breadcrumb_list", &default_config_hm),
-        breadcrumb_item: get_default_value("breadcrumb_item", &default_config_hm),
-        breadcrumb_separator: get_default_value("breadcrumb_separator", &default_config_hm),
-        button_primary: get_default_value("button_primary", &default_config_hm),
-        button_secondary: get_default_value("button_secondary", &default_config_hm),
-        button_danger: get_default_value("button_danger", &default_config_hm),
-        button_success: get_default_value("button_success", &default_config_hm),
-        button_warning: get_default_value("button_warning", &default_config_hm),
-        button_ghost: get_default_value("button_ghost", &default_config_hm),
-        button_default: get_default_value("button_default", &default_config_hm),
-        button_base: get_default_value("button_base", &default_config_hm),
-        button_small: get_default_value("button_small", &default_config_hm),
-        button_medium: get_default_value("button_medium", &default_config_hm),
-        button_large: get_default_value("button_large", &default_config_hm),
-        calendar_container: get_default_value("calendar_container", &default_config_hm),
-        calendar_wrapper: get_default_value("calendar_wrapper", &default_config_hm),
-        calendar_header: get_default_value("calendar_header", &default_config_hm),
-        calendar_title: get_default_value("calendar_title", &default_config_hm),
-        calendar_month_year: get_default_value("calendar_month_year", &default_config_hm),
-        calendar_nav: get_default_value("calendar_nav", &default_config_hm),
-        calendar_nav_button: get_default_value("calendar_nav_button", &default_config_hm),
-        calendar_grid: get_default_value("calendar_grid", &default_config_hm),
-        calendar_thead: get_default_value("calendar_thead", &default_config_hm),
-        calendar_weekdays: get_default_value("calendar_weekdays", &default_config_hm),
-        calendar_weekday: get_default_value("calendar_weekday", &default_config_hm),
-        calendar_tbody: get_default_value("calendar_tbody", &default_config_hm),
-        calendar_week: get_default_value("calendar_week", &default_config_hm),
-        calendar_day: get_default_value("calendar_day", &default_config_hm),
-        calendar_day_button: get_default_value("calendar_day_button", &default_config_hm),
-        calendar_day_today: get_default_value("calendar_day_today", &default_config_hm),
-        calendar_day_selected: get_default_value("calendar_day_selected", &default_config_hm),
-        calendar_day_outside: get_default_value("calendar_day_outside", &default_config_hm),
-        carousel_container: get_default_value("carousel_container", &default_config_hm),
-        carousel_inner: get_default_value("carousel_inner", &default_config_hm),
-        carousel_item: get_default_value("carousel_item", &default_config_hm),
-        carousel_item_active: get_default_value("carousel_item_active", &default_config_hm),
-        carousel_controls: get_default_value("carousel_controls", &default_config_hm),
-        card_container: get_default_value("card_container", &default_config_hm),
-        card_header: get_default_value("card_header", &default_config_hm),
-        card_title: get_default_value("card_title", &default_config_hm),
-        card_body: get_default_value("card_body", &default_config_hm),
-        checkbox_base: get_default_value("checkbox_base", &default_config_hm),
-        checkbox_checked: get_default_value("checkbox_checked", &default_config_hm),
-        checkbox_unchecked: get_default_value("checkbox_unchecked", &default_config_hm),
-        checkbox_disabled: get_default_value("checkbox_disabled", &default_config_hm),
-        checkbox_label: get_default_value("checkbox_label", &default_config_hm),
-        col_container: get_default_value("col_container", &default_config_hm),
-        collapsible_container: get_default_value("collapsible_container", &default_config_hm),
-        collapsible_header: get_default_value("collapsible_header", &default_config_hm),
-        collapsible_title: get_default_value("collapsible_title", &default_config_hm),
-        collapsible_button: get_default_value("collapsible_button", &default_config_hm),
-        collapsible_content: get_default_value("collapsible_content", &default_config_hm),
-        collapsible_item: get_default_value("collapsible_item", &default_config_hm),
-        container_padding_x: get_default_value("container_padding_x", &default_config_hm),
-        container_padding_y: get_default_value("container_padding_y", &default_config_hm),
-        container_expanding: get_default_value("container_expanding", &default_config_hm),
-        container_small: get_default_value("container_small", &default_config_hm),
-        container_narrow: get_default_value("container_narrow", &default_config_hm),
-        container_large: get_default_value("container_large", &default_config_hm),
-        container_responsive: get_default_value("container_responsive", &default_config_hm),
-        content_with_aside: get_default_value("content_with_aside", &default_config_hm),
-        content_aside: get_default_value("content_aside", &default_config_hm),
-        content_aside_container: get_default_value("content_aside_container", &default_config_hm),
-        combobox_button: get_default_value("combobox_button", &default_config_hm),
-        combobox_button_open: get_default_value("combobox_button_open", &default_config_hm),
-        combobox_button_disabled: get_default_value("combobox_button_disabled", &default_config_hm),
-        combobox_list: get_default_value("combobox_list", &default_config_hm),
-        combobox_item: get_default_value("combobox_item", &default_config_hm),
-        combobox_item_selected: get_default_value("combobox_item_selected", &default_config_hm),
-        command_container: get_default_value("command_container", &default_config_hm),
-        command_input_wrapper: get_default_value("command_input_wrapper", &default_config_hm),
-        command_icon: get_default_value("command_icon", &default_config_hm),
-        command_input: get_default_value("command_input", &default_config_hm),
-        command_list: get_default_value("command_list", &default_config_hm),
-        command_item: get_default_value("command_item", &default_config_hm),
-        command_selected_item: get_default_value("command_selected_item", &default_config_hm),
-        command_item_icon: get_default_value("command_item_icon", &default_config_hm),
-        dropdown_content: get_default_value("dropdown_content", &default_config_hm),
-        dropdown_item: get_default_value("dropdown_item", &default_config_hm),
-        dropdown_item_icon: get_default_value("dropdown_item_icon", &default_config_hm),
-        dropdown_item_disabled: get_default_value("dropdown_item_disabled", &default_config_hm),
-        dropdown_item_widget: get_default_value("dropdown_item_widget", &default_config_hm),
-        dropdown_separator: get_default_value("dropdown_separator", &default_config_hm),
-        drawer_provider: get_default_value("drawer_provider", &default_config_hm),
-        drawer_container: get_default_value("drawer_container", &default_config_hm),
-        drawer_header: get_default_value("drawer_header", &default_config_hm),
-        drawer_title: get_default_value("drawer_title", &default_config_hm),
-        drawer_description: get_default_value("drawer_description", &default_config_hm),
-        drawer_footer: get_default_value("drawer_footer", &default_config_hm),
-        drawer_right: get_default_value("drawer_right", &default_config_hm),
-        drawer_top: get_default_value("drawer_top", &default_config_hm),
-        drawer_bottom: get_default_value("drawer_bottom", &default_config_hm),
-        drawer_left: get_default_value("drawer_left", &default_config_hm),
-        dialog_container: get_default_value("dialog_container", &default_config_hm),
-        dialog_content: get_default_value("dialog_content", &default_config_hm),
-        dialog_header: get_default_value("dialog_header", &default_config_hm),
-        dialog_title: get_default_value("dialog_title", &default_config_hm),
-        dialog_description: get_default_value("dialog_description", &default_config_hm),
-        dialog_footer: get_default_value("dialog_footer", &default_config_hm),
-        group_button_container: get_default_value("group_button_container", &default_config_hm),
-        group_button_list: get_default_value("group_button_list", &default_config_hm),
-        group_button_trigger: get_default_value("group_button_trigger", &default_config_hm),
-        group_button_trigger_active: get_default_value(
-            "group_button_trigger_active",
-            &default_config_hm,
-        ),
-        group_button_trigger_inactive: get_default_value(
-            "group_button_trigger_inactive",
-            &default_config_hm,
-        ),
-        group_button_demo: get_default_value("group_button_demo", &default_config_hm),
-        kanban_container: get_default_value("kanban_container", &default_config_hm),
-        kanban_column: get_default_value("kanban_column", &default_config_hm),
-        kanban_column_header: get_default_value("kanban_column_header", &default_config_hm),
-        kanban_column_body: get_default_value("kanban_column_body", &default_config_hm),
-        kanban_column_over: get_default_value("kanban_column_over", &default_config_hm),
-        kanban_card: get_default_value("kanban_card", &default_config_hm),
-        kanban_card_title: get_default_value("kanban_card_title", &default_config_hm),
-        kanban_card_content: get_default_value("kanban_card_content", &default_config_hm),
-        kanban_card_dragging: get_default_value("kanban_card_dragging", &default_config_hm),
-        notification_container: get_default_value("notification_container", &default_config_hm),
-        notification_content: get_default_value("notification_content", &default_config_hm),
-        notification_title: get_default_value("notification_title", &default_config_hm),
-        notification_description: get_default_value("notification_description", &default_config_hm),
-        notification_timestamp: get_default_value("notification_timestamp", &default_config_hm),
-        notification_close_button: get_default_value(
-            "notification_close_button",
-            &default_config_hm,
-        ),
-        notification_close_icon: get_default_value("notification_close_icon", &default_config_hm),
-        notification_action_container: get_default_value(
-            "notification_action_container",
-            &default_config_hm,
-        ),
-        notification_list_container: get_default_value(
-            "notification_list_container",
-            &default_config_hm,
-        ),
-        page_header_container: get_default_value("page_header_container", &default_config_hm),
-        page_header_title: get_default_value("page_header_title", &default_config_hm),
-        page_header_actions: get_default_value("page_header_actions", &default_config_hm),
-
-        pagination_container: get_default_value("pagination_container", &default_config_hm),
-        pagination_list: get_default_value("pagination_list", &default_config_hm),
-        pagination_item: get_default_value("pagination_item", &default_config_hm),
-        pagination_item_current: get_default_value("pagination_item_current", &default_config_hm),
-
-        placeholder_container: get_default_value("placeholder_container", &default_config_hm),
-        placeholder_svg: get_default_value("placeholder_svg", &default_config_hm),
-        placeholder_text: get_default_value("placeholder_text", &default_config_hm),
-        popover_container: get_default_value("popover_container", &default_config_hm),
-        popover_trigger: get_default_value("popover_trigger", &default_config_hm),
-        popover_content: get_default_value("popover_content", &default_config_hm),
-        popover_position_north_start: get_default_value(
-            "popover_position_north_start",
-            &default_config_hm,
-        ),
-        popover_position_north_middle: get_default_value(
-            "popover_position_north_middle",
-            &default_config_hm,
-        ),
-        popover_position_north_end: get_default_value(
-            "popover_position_north_end",
-            &default_config_hm,
-        ),
-        popover_position_south_start: get_default_value(
-            "popover_position_south_start",
-            &default_config_hm,
-        ),
-        popover_position_south_middle: get_default_value(
-            "popover_position_south_middle",
-            &default_config_hm,
-        ),
-        popover_position_south_end: get_default_value(
-            "popover_position_south_end",
-            &default_config_hm,
-        ),
-        popover_position_east_start: get_default_value(
-            "popover_position_east_start",
-            &default_config_hm,
-        ),
-        popover_position_east_middle: get_default_value(
-            "popover_position_east_middle",
-            &default_config_hm,
-        ),
-        popover_position_east_end: get_default_value(
-            "popover_position_east_end",
-            &default_config_hm,
-        ),
-        popover_position_west_start: get_default_value(
-            "popover_position_west_start",
-            &default_config_hm,
-        ),
-        popover_position_west_middle: get_default_value(
-            "popover_position_west_middle",
-            &default_config_hm,
-        ),
-        popover_position_west_end: get_default_value(
-            "popover_position_west_end",
-            &default_config_hm,
-        ),
-        resizable_container: get_default_value("resizable_container", &default_config_hm),
-        resizable_box: get_default_value("resizable_box", &default_config_hm),
-        resizable_handle_visible: get_default_value("resizable_handle_visible", &default_config_hm),
-        resizable_handle_hidden: get_default_value("resizable_handle_hidden", &default_config_hm),
-        resizable_handle_nw: get_default_value("resizable_handle_nw", &default_config_hm),
-        resizable_handle_ne: get_default_value("resizable_handle_ne", &default_config_hm),
-        resizable_handle_sw: get_default_value("resizable_handle_sw", &default_config_hm),
-        resizable_handle_se: get_default_value("resizable_handle_se", &default_config_hm),
-        resizable_handle_n: get_default_value("resizable_handle_n", &default_config_hm),
-        resizable_handle_s: get_default_value("resizable_handle_s", &default_config_hm),
-        resizable_handle_w: get_default_value("resizable_handle_w", &default_config_hm),
-        resizable_handle_e: get_default_value("resizable_handle_e", &default_config_hm),
-        selectable_indicator: get_default_value("selectable_indicator", &default_config_hm),
-        selectable_hover: get_default_value("selectable_hover", &default_config_hm),
-        selectable_selected: get_default_value("selectable_selected", &default_config_hm),
-        selectable_cursor: get_default_value("selectable_cursor", &default_config_hm),
-        toggle_container: get_default_value("toggle_container", &default_config_hm),
-        toggle_base: get_default_value("toggle_base", &default_config_hm),
-        toggle_checked: get_default_value("toggle_checked", &default_config_hm),
-        toggle_unchecked: get_default_value("toggle_unchecked", &default_config_hm),
-        toggle_disabled: get_default_value("toggle_disabled", &default_config_hm),
-        toggle_label: get_default_value("toggle_label", &default_config_hm),
-        toggle_icon: get_default_value("toggle_icon", &default_config_hm),
-        switch_base: get_default_value("switch_base", &default_config_hm),
-        switch_thumb: get_default_value("switch_thumb", &default_config_hm),
-        switch_checked: get_default_value("switch_checked", &default_config_hm),
-        switch_unchecked: get_default_value("switch_unchecked", &default_config_hm),
-        switch_translate_checked: get_default_value("switch_translate_checked", &default_config_hm),
-        switch_translate_unchecked: get_default_value(
-            "switch_translate_unchecked",
-            &default_config_hm,
-        ),
-        switch_disabled: get_default_value("switch_disabled", &default_config_hm),
-        switch_label: get_default_value("switch_label", &default_config_hm),
-        select_container: get_default_value("select_container", &default_config_hm),
-        select_trigger: get_default_value("select_trigger", &default_config_hm),
-        select_trigger_placeholder: get_default_value(
-            "select_trigger_placeholder",
-            &default_config_hm,
-        ),
-        select_trigger_icon: get_default_value("select_trigger_icon", &default_config_hm),
-        select_content_container: get_default_value("select_content_container", &default_config_hm),
-        select_content_list: get_default_value("select_content_list", &default_config_hm),
-        select_group: get_default_value("select_group", &default_config_hm),
-        select_label: get_default_value("select_label", &default_config_hm),
-        select_item: get_default_value("select_item", &default_config_hm),
-        table_container: get_default_value("table_container", &default_config_hm),
-        table: get_default_value("table", &default_config_hm),
-        table_head: get_default_value("table_head", &default_config_hm),
-        table_row: get_default_value("table_row", &default_config_hm),
-        table_head_row: get_default_value("table_head_row", &default_config_hm),
-        table_cell: get_default_value("table_cell", &default_config_hm),
-        table_body: get_default_value("table_body", &default_config_hm),
-        table_footer: get_default_value("table_footer", &default_config_hm),
-        tabs_container: get_default_value("tabs_container", &default_config_hm),
-        tabs_list: get_default_value("tabs_list", &default_config_hm),
-        tabs_list_column: get_default_value("tabs_list_column", &default_config_hm),
-        tabs_list_row: get_default_value("tabs_list_row", &default_config_hm),
-        tabs_trigger: get_default_value("tabs_trigger", &default_config_hm),
-        tabs_trigger_inactive: get_default_value("tabs_trigger_inactive", &default_config_hm),
-        tabs_trigger_active: get_default_value("tabs_trigger_active", &default_config_hm),
-        tabs_trigger_disabled: get_default_value("tabs_trigger_disabled", &default_config_hm),
-        tabs_content: get_default_value("tabs_content", &default_config_hm),
-        tag_input_container: get_default_value("tag_input_container", &default_config_hm),
-        tag_input_tags_container: get_default_value("tag_input_tags_container", &default_config_hm),
-        tag_input_tag: get_default_value("tag_input_tag", &default_config_hm),
-        tag_input_remove_button: get_default_value("tag_input_remove_button", &default_config_hm),
-        tag_input_input: get_default_value("tag_input_input", &default_config_hm),
-        tag_input_candidates_container: get_default_value(
-            "tag_input_candidates_container",
-            &default_config_hm,
-        ),
-        tag_input_candidate_button: get_default_value(
-            "tag_input_candidate_button",
-            &default_config_hm,
-        ),
-        tailwind_color_picker_container: get_default_value(
-            "tailwind_color_picker_container",
-            &default_config_hm,
-        ),
-        tailwind_color_picker_button: get_default_value(
-            "tailwind_color_picker_button",
-            &default_config_hm,
-        ),
-        tailwind_color_picker_selected_color: get_default_value(
-            "tailwind_color_picker_selected_color",
-            &default_config_hm,
-        ),
-        tailwind_color_picker_dropdown: get_default_value(
-            "tailwind_color_picker_dropdown",
-            &default_config_hm,
-        ),
-        tailwind_color_picker_row: get_default_value(
-            "tailwind_color_picker_row",
-            &default_config_hm,
-        ),
-        tailwind_color_picker_cell: get_default_value(
-            "tailwind_color_picker_cell",
-            &default_config_hm,
-        ),
-        accordion_container: get_default_value("accordion_container", &default_config_hm),
-        accordion_header: get_default_value("accordion_header", &default_config_hm),
-        accordion_title: get_default_value("accordion_title", &default_config_hm),
-        accordion_content: get_default_value("accordion_content", &default_config_hm),
-    };
-
     let base_dir = get_base_dir();
     let fallback_path = base_dir.join("wonopui.json");
     // Path to the user's configuration file
@@ -487,22 +104,84 @@ fn main() {
         Err(_) => (false, fallback_path),
     };
 
-    // Read the configuration file
-    println!("cargo:rerun-if-changed={}", config_path.display());
-    let config: Config = if !using_custom_config {
-        default_config
+    // Read the configuration file. Without one nothing is read, so there's nothing to watch but
+    // the settings.
+    println!("cargo:rerun-if-env-changed=WONOPUI_CONFIG_PATH");
+    println!("cargo:rerun-if-env-changed=WONOPUI_OUTPUT_DIR");
+    println!("cargo:rerun-if-env-changed=CARGO_TARGET_DIR");
+    if using_custom_config {
+        println!("cargo:rerun-if-changed={}", config_path.display());
+    }
+    let (overrides, variable_overrides, custom_keys, user_config_content) = if !using_custom_config
+    {
+        (HashMap::new(), HashMap::new(), Vec::new(), String::new())
     } else {
-        let mut config_file = File::open(&config_path)
-            .expect(&format!("Failed to open config file: {:?}", config_path));
-        let mut config_content = String::new();
-        config_file
-            .read_to_string(&mut config_content)
+        let config_content = fs::read_to_string(&config_path)
             .expect(&format!("Failed to read config file: {:?}", config_path));
-        serde_json::from_str(&config_content).expect(&format!(
-            "Failed to parse wonopui.json config file: {:?}",
-            config_path
-        ))
+        // The user file only needs the keys it changes; everything else keeps its default.
+        // Its entries are templates too, so tokens such as `primary_light` can be overridden
+        // or referenced as `{{ primary_light }}`.
+        let overrides = user_config::read_overrides(&config_path, &config_content)
+            .unwrap_or_else(|error| panic!("Invalid wonopui.json config file:\n{}", error));
+        // Variable colors are given under their own names, e.g. `"--wonop-primary": "#0ea5e9"`
+        let (variable_overrides, overrides): (Vec<_>, Vec<_>) = overrides
+            .into_iter()
+            .partition(|(key, _)| css_variables::enabled() && key.starts_with("--"));
+        let known_variables: Vec<String> = css_variables::css_variables()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        let variable_overrides = user_config::known_overrides(
+            variable_overrides,
+            &known_variables,
+            &config_path,
+            &config_content,
+        );
+        let known_keys: Vec<String> = default_templates()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        let overrides =
+            user_config::known_overrides(overrides, &known_keys, &config_path, &config_content);
+        // Keys of the user's own components, themed like the built-in ones
+        let custom_keys = user_config::read_custom_keys(&config_path, &config_content, &known_keys)
+            .unwrap_or_else(|error| panic!("Invalid wonopui.json config file:\n{}", error));
+        let custom_templates: Vec<(String, String)> = custom_keys
+            .iter()
+            .map(|custom| (custom.key.clone(), custom.template.clone()))
+            .collect();
+        if let Err((key, error)) = get_config_with_overrides(&custom_templates, &overrides) {
+            panic!(
+                "Invalid wonopui.json config file:\n{}",
+                user_config::template_error(&config_path, &config_content, &key, &error)
+            );
+        }
+        (overrides, variable_overrides, custom_keys, config_content)
     };
+    let custom_templates: Vec<(String, String)> = custom_keys
+        .iter()
+        .map(|custom| (custom.key.clone(), custom.template.clone()))
+        .collect();
+    let config_hm =
+        get_config_with_overrides(&custom_templates, &overrides).unwrap_or_else(|(key, error)| {
+            panic!(
+                "Unable to generate config - error in template `{}` in main(): {:?}",
+                key, error
+            )
+        });
+    let breakpoint_widths = breakpoints::widths(&config_hm)
+        .unwrap_or_else(|error| panic!("Invalid wonopui.json config file:\n{}", error));
+    // Every key of the brand guide: the built-in ones, then the ones of custom components
+    let brand_keys: Vec<BrandKey> = default_config::brand_keys()
+        .into_iter()
+        .map(BrandKey::builtin)
+        .chain(
+            custom_keys
+                .iter()
+                .map(|custom| BrandKey::custom(&custom.component, &custom.key)),
+        )
+        .collect();
+    let fields: BTreeSet<&str> = brand_keys.iter().map(|key| key.key.as_str()).collect();
 
     // Write the configuration to [base_dir]/target/wonopui.json
     let target_config_path = Path::new(&base_dir).join("wonopui.json");
@@ -510,14 +189,200 @@ fn main() {
         "Failed to create target wonopui.json file: {:?}",
         target_config_path
     ));
-    let config_content =
-        serde_json::to_string_pretty(&config).expect("Failed to serialize config to JSON");
+    let entries: Vec<(&str, &str)> = brand_keys
+        .iter()
+        .filter(|key| !key.custom)
+        .map(|key| (key.key.as_str(), config_hm[&key.key].as_str()))
+        .collect();
+    let mut components: Vec<(&str, Vec<(&str, &str)>)> = Vec::new();
+    for custom in &custom_keys {
+        let entry = (custom.key.as_str(), config_hm[&custom.key].as_str());
+        match components
+            .iter_mut()
+            .find(|(component, _)| *component == custom.component)
+        {
+            Some((_, keys)) => keys.push(entry),
+            None => components.push((&custom.component, vec![entry])),
+        }
+    }
+    let config_content = user_config::to_json(&entries, &components);
     target_config_file
         .write_all(config_content.as_bytes())
         .expect("Failed to write config to target wonopui.json file");
 
+    // Write the color variables to [base_dir]/target/wonopui.css
+    let variables: Vec<(String, String)> = css_variables::css_variables()
+        .into_iter()
+        .map(|(name, value)| {
+            let value = variable_overrides.get(&name).cloned().unwrap_or(value);
+            (name, value)
+        })
+        .collect();
+    if css_variables::enabled() {
+        let target_css_path = Path::new(&base_dir).join("wonopui.css");
+        css_variables::write_css_file(&target_css_path, &variables).unwrap_or_else(|error| {
+            panic!(
+                "Failed to write target wonopui.css file {:?}: {}",
+                target_css_path, error
+            )
+        });
+    }
+
+    // Keep the templates for re-deriving the brand guide from tokens at runtime
+    let templates: Vec<(String, String)> = default_templates()
+        .into_iter()
+        .map(|(key, template)| {
+            let template = overrides.get(&key).cloned().unwrap_or(template);
+            (key, template)
+        })
+        .chain(custom_templates.iter().cloned())
+        .collect();
+
+    // Check the classes the user config introduces against the Tailwind grammar
+    println!("cargo:rerun-if-env-changed=WONOPUI_STRICT");
+    println!("cargo:rerun-if-env-changed=WONOPUI_THEME_COLORS");
+    if using_custom_config {
+        let defaults =
+            get_config_with_overrides(&[], &HashMap::new()).unwrap_or_else(|(key, error)| {
+                panic!("Invalid default template `{}`: {:?}", key, error)
+            });
+        let entries: Vec<(&str, &str, &str)> = templates
+            .iter()
+            .filter(|(key, _)| fields.contains(key.as_str()))
+            .map(|(key, _)| {
+                (
+                    key.as_str(),
+                    config_hm[key].as_str(),
+                    defaults.get(key).map_or("", String::as_str),
+                )
+            })
+            .collect();
+        // Colors of the user's Tailwind theme, e.g. `WONOPUI_THEME_COLORS=brand,surface`
+        let extra_colors: Vec<String> = env::var("WONOPUI_THEME_COLORS")
+            .unwrap_or_default()
+            .split(',')
+            .map(|color| color.trim().to_string())
+            .filter(|color| !color.is_empty())
+            .collect();
+        // Custom keys are reported at their declaration like the overridden ones
+        let user_templates: HashMap<String, String> = overrides
+            .clone()
+            .into_iter()
+            .chain(custom_templates.iter().cloned())
+            .collect();
+        let problems = user_config::class_problems(
+            &entries,
+            &user_templates,
+            &extra_colors,
+            &config_path,
+            &user_config_content,
+        );
+        let strict = env::var("WONOPUI_STRICT").is_ok_and(|value| value != "0" && value != "false");
+        if strict && !problems.is_empty() {
+            panic!(
+                "Invalid classes in wonopui.json config file (WONOPUI_STRICT is set):\n{}",
+                problems.join("\n")
+            );
+        }
+        for problem in &problems {
+            println!("cargo:warning={}", problem);
+        }
+    }
+
     // Write the configuration to config.rs
-    config
-        .write_config_to_file(&dest_path)
-        .expect("Failed to write config to file");
+    brand_fields::write_brand_guide(&dest_path, &brand_keys, &config_hm)
+        .expect("Failed to write the brand guide to file");
+    write_theme_templates(&dest_path, &templates, |key| fields.contains(key))
+        .expect("Failed to write theme templates to file");
+    write_css_variables(&dest_path, &variables).expect("Failed to write CSS variables to file");
+    breakpoints::write_widths(&dest_path, &breakpoint_widths)
+        .expect("Failed to write breakpoints to file");
+    breakpoints::write_screens(&base_dir, &breakpoint_widths).unwrap_or_else(|error| {
+        panic!(
+            "Failed to write tailwind.screens.js to {:?}: {}",
+            base_dir, error
+        )
+    });
+
+    // Only the entries of enabled components go into the safelist
+    create_baseclasses(
+        brand_keys
+            .iter()
+            .filter(|key| {
+                key.custom
+                    || key.component == "General"
+                    || safelist::feature_enabled(&key.component)
+            })
+            .flat_map(|key| config_hm[&key.key].split_whitespace()),
+    );
+    if tailwind_v4::enabled() {
+        let target_css_path = base_dir.join("wonopui.tailwind.css");
+        tailwind_v4::write_css_file(&target_css_path, &config_hm, &breakpoint_widths)
+            .unwrap_or_else(|error| {
+                panic!(
+                    "Failed to write target wonopui.tailwind.css file {:?}: {}",
+                    target_css_path, error
+                )
+            });
+    }
+
+    // The entries of the user config, so runtime exports can include them
+    let mut user_entries: Vec<(String, String)> = templates
+        .iter()
+        .filter(|(key, _)| {
+            overrides.contains_key(key) || custom_keys.iter().any(|custom| custom.key == *key)
+        })
+        .cloned()
+        .collect();
+    user_entries.extend(
+        variables
+            .iter()
+            .filter(|(name, _)| variable_overrides.contains_key(name))
+            .cloned(),
+    );
+    write_theme_overrides(&dest_path, &user_entries)
+        .expect("Failed to write theme overrides to file");
+}
+
+fn write_theme_overrides(filename: &Path, entries: &[(String, String)]) -> std::io::Result<()> {
+    let mut f = fs::OpenOptions::new().append(true).open(filename)?;
+    writeln!(f, "#[cfg(feature = \"ThemeProvider\")]")?;
+    writeln!(f, "pub static THEME_OVERRIDES: &[(&str, &str)] = &[")?;
+    for (key, template) in entries {
+        writeln!(f, "    ({:?}, {:?}),", key, template)?;
+    }
+    writeln!(f, "];")?;
+    Ok(())
+}
+
+fn write_theme_templates(
+    filename: &Path,
+    templates: &[(String, String)],
+    is_field: impl Fn(&str) -> bool,
+) -> std::io::Result<()> {
+    let mut f = fs::OpenOptions::new().append(true).open(filename)?;
+    writeln!(f, "#[cfg(feature = \"ThemeProvider\")]")?;
+    writeln!(f, "pub static THEME_TEMPLATES: &[(&str, &str)] = &[")?;
+    for (key, template) in templates {
+        writeln!(f, "    ({:?}, {:?}),", key, template)?;
+    }
+    writeln!(f, "];")?;
+    writeln!(f, "#[cfg(feature = \"ThemeProvider\")]")?;
+    writeln!(f, "pub static THEME_TOKENS: &[&str] = &[")?;
+    for (key, _) in templates.iter().filter(|(key, _)| !is_field(key)) {
+        writeln!(f, "    {:?},", key)?;
+    }
+    writeln!(f, "];")?;
+    Ok(())
+}
+
+fn write_css_variables(filename: &Path, variables: &[(String, String)]) -> std::io::Result<()> {
+    let mut f = fs::OpenOptions::new().append(true).open(filename)?;
+    writeln!(f, "#[cfg(feature = \"CssVariables\")]")?;
+    writeln!(f, "pub static CSS_VARIABLES: &[(&str, &str)] = &[")?;
+    for (name, value) in variables {
+        writeln!(f, "    ({:?}, {:?}),", name, value)?;
+    }
+    writeln!(f, "];")?;
+    Ok(())
 }
@@ -0,0 +1,156 @@
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Whether the cargo feature `name` is enabled, e.g. `TagInput`.
+pub fn feature_enabled(name: &str) -> bool {
+    let var = format!("CARGO_FEATURE_{}", name.to_uppercase().replace('-', "_"));
+    env::var(var).is_ok()
+}
+
+/// The source files compiled with the enabled features, found by following the `mod`
+/// declarations from `lib_rs` and skipping the ones behind a disabled feature.
+pub fn enabled_sources(lib_rs: &Path) -> Vec<PathBuf> {
+    let mut sources = Vec::new();
+    let mut pending = vec![lib_rs.to_path_buf()];
+    while let Some(file) = pending.pop() {
+        let Ok(content) = fs::read_to_string(&file) else {
+            continue;
+        };
+        let dir = module_dir(&file);
+        let mut enabled = true;
+        for line in content.lines().map(str::trim) {
+            if line.starts_with("#[cfg(") {
+                enabled = cfg_enabled(line);
+            } else if let Some(name) = mod_declaration(line) {
+                if enabled {
+                    let candidates = [
+                        dir.join(format!("{}.rs", name)),
+                        dir.join(name).join("mod.rs"),
+                    ];
+                    if let Some(path) = candidates.into_iter().find(|path| path.is_file()) {
+                        pending.push(path);
+                    }
+                }
+                enabled = true;
+            } else if !line.is_empty() && !line.starts_with("#[") && !line.starts_with("//") {
+                enabled = true;
+            }
+        }
+        sources.push(file);
+    }
+    sources.sort();
+    sources
+}
+
+/// Class-like words in the string literals of `source`.
+pub fn literal_classes(source: &str) -> Vec<&str> {
+    string_literals(source)
+        .into_iter()
+        .flat_map(str::split_whitespace)
+        .filter(|word| !word.contains(['{', '}', '\\']))
+        .collect()
+}
+
+/// Writes the safelist as `tailwindcss.txt` (one class per line, for Tailwind's `content`),
+/// `tailwind.safelist.json` and `tailwind.safelist.js` (for Tailwind's `safelist`).
+pub fn write_safelist(dir: &Path, classes: &BTreeSet<String>) -> std::io::Result<()> {
+    let mut txt = fs::File::create(dir.join("tailwindcss.txt"))?;
+    for class in classes {
+        writeln!(txt, "{}", class)?;
+    }
+
+    let quoted: Vec<String> = classes
+        .iter()
+        .map(|class| format!("  {}", serde_json::Value::from(class.as_str())))
+        .collect();
+    let array = if quoted.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", quoted.join(",\n"))
+    };
+    fs::write(dir.join("tailwind.safelist.json"), format!("{}\n", array))?;
+    fs::write(
+        dir.join("tailwind.safelist.js"),
+        format!(
+            "// Generated by the wonopui build script for the enabled features\nmodule.exports = {};\n",
+            array
+        ),
+    )?;
+    Ok(())
+}
+
+// Where the child modules of `file` live: next to `lib.rs` and `mod.rs`, else in a directory
+// named like the file
+fn module_dir(file: &Path) -> PathBuf {
+    let parent = file.parent().unwrap_or(Path::new("."));
+    match file.file_stem().and_then(|stem| stem.to_str()) {
+        Some("lib") | Some("mod") | None => parent.to_path_buf(),
+        Some(stem) => parent.join(stem),
+    }
+}
+
+// `#[cfg(feature = "X")]` and `#[cfg(not(feature = "X"))]` follow the features, tests are
+// skipped and anything else is kept
+fn cfg_enabled(line: &str) -> bool {
+    let feature = line
+        .split("feature = \"")
+        .nth(1)
+        .and_then(|rest| rest.split('"').next());
+    match feature {
+        Some(feature) if line.starts_with("#[cfg(feature") => feature_enabled(feature),
+        Some(feature) if line.starts_with("#[cfg(not(feature") => !feature_enabled(feature),
+        _ => line != "#[cfg(test)]",
+    }
+}
+
+// The name of `mod name;`, `pub mod name;` or `pub(crate) mod name;`
+fn mod_declaration(line: &str) -> Option<&str> {
+    let rest = line
+        .strip_prefix("pub(crate) ")
+        .or_else(|| line.strip_prefix("pub "))
+        .unwrap_or(line);
+    let name = rest.strip_prefix("mod ")?.strip_suffix(';')?.trim();
+    name.chars()
+        .all(|c| c.is_alphanumeric() || c == '_')
+        .then_some(name)
+}
+
+// Contents of the `"..."` and `r#"..."#` literals, skipping comments and `'"'`
+fn string_literals(source: &str) -> Vec<&str> {
+    let mut literals = Vec::new();
+    let bytes = source.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = source[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            }
+            b'\'' if bytes.get(i + 1) == Some(&b'"') && bytes.get(i + 2) == Some(&b'\'') => {
+                i += 3;
+            }
+            b'r' if source[i..].starts_with("r#\"") => {
+                let start = i + 3;
+                let end = source[start..]
+                    .find("\"#")
+                    .map_or(bytes.len(), |end| start + end);
+                literals.push(&source[start..end]);
+                i = end + 2;
+            }
+            b'"' => {
+                let start = i + 1;
+                let mut end = start;
+                while end < bytes.len() && bytes[end] != b'"' {
+                    end += if bytes[end] == b'\\' { 2 } else { 1 };
+                }
+                let end = end.min(bytes.len());
+                literals.push(&source[start..end]);
+                i = end + 1;
+            }
+            _ => i += 1,
+        }
+    }
+    literals
+}
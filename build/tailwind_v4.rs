@@ -0,0 +1,53 @@
+use crate::css_variables::FAMILIES;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+/// Whether the `TailwindV4` feature is enabled.
+pub fn enabled() -> bool {
+    env::var("CARGO_FEATURE_TAILWINDV4").is_ok()
+}
+
+/// Writes a stylesheet for Tailwind v4: an `@source` for the safelist next to it and an `@theme`
+/// with the brand colors, e.g. `--color-primary`, taken from the rendered tokens, and the
+/// breakpoints, e.g. `--breakpoint-lg`.
+pub fn write_css_file(
+    path: &Path,
+    tokens: &HashMap<String, String>,
+    breakpoints: &[(&str, String)],
+) -> std::io::Result<()> {
+    let mut f = fs::File::create(path)?;
+    writeln!(
+        f,
+        "/* Generated by the wonopui build script. Import it after `@import \"tailwindcss\";` */"
+    )?;
+    writeln!(f, "@source \"./tailwindcss.txt\";")?;
+    writeln!(f)?;
+    writeln!(f, "@theme {{")?;
+    for (family, _) in FAMILIES {
+        for (suffix, token) in [("", "light"), ("-dark", "dark"), ("-darker", "darker")] {
+            let Some(color) = tokens.get(&format!("{}_{}", family, token)) else {
+                continue;
+            };
+            writeln!(f, "  --color-{}{}: {};", family, suffix, css_color(color))?;
+        }
+    }
+    for (screen, width) in breakpoints {
+        writeln!(f, "  --breakpoint-{}: {};", screen, width)?;
+    }
+    writeln!(f, "}}")?;
+    Ok(())
+}
+
+// `indigo-500` becomes `var(--color-indigo-500)` and `[var(--wonop-primary)]` its contents
+fn css_color(token: &str) -> String {
+    match token
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+    {
+        Some(arbitrary) => arbitrary.replace('_', " "),
+        None => format!("var(--color-{})", token),
+    }
+}
@@ -0,0 +1,379 @@
+use crate::valid_classes::{check_class, ClassProblem};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+/// The section of a user config declaring the keys of components outside wonopui
+pub const COMPONENTS: &str = "components";
+
+/// A key of a component outside wonopui, declared under `"components"` in a user config.
+pub struct CustomKey {
+    pub component: String,
+    pub key: String,
+    pub template: String,
+}
+
+/// A problem in a user `wonopui.json`, pointing at the offending line.
+pub struct ConfigError {
+    path: String,
+    line: usize,
+    column: usize,
+    message: String,
+    source_line: String,
+}
+
+impl ConfigError {
+    fn new(path: &Path, content: &str, line: usize, column: usize, message: String) -> Self {
+        ConfigError {
+            path: path.display().to_string(),
+            line,
+            column,
+            message,
+            source_line: content
+                .lines()
+                .nth(line.saturating_sub(1))
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}:{}:{}: {}",
+            self.path, self.line, self.column, self.message
+        )?;
+        writeln!(f, "{:>5} | {}", self.line, self.source_line)?;
+        write!(f, "      | {}^", " ".repeat(self.column.saturating_sub(1)))
+    }
+}
+
+impl fmt::Debug for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Reads the entries of a user config. Any subset of the keys may be given. The `"components"`
+/// section is read by `read_custom_keys`.
+pub fn read_overrides(path: &Path, content: &str) -> Result<Vec<(String, String)>, ConfigError> {
+    let value: Value = serde_json::from_str(content).map_err(|error| {
+        let message = error.to_string();
+        // serde_json appends " at line L column C", which the location already says.
+        let message = match message.rfind(" at line ") {
+            Some(end) => message[..end].to_string(),
+            None => message,
+        };
+        ConfigError::new(path, content, error.line(), error.column(), message)
+    })?;
+    let Value::Object(entries) = value else {
+        return Err(ConfigError::new(
+            path,
+            content,
+            1,
+            1,
+            "expected a JSON object mapping keys to class strings".to_string(),
+        ));
+    };
+    entries
+        .into_iter()
+        .filter(|(key, _)| key != COMPONENTS)
+        .map(|(key, value)| match value {
+            Value::String(classes) => Ok((key, classes)),
+            other => {
+                let (line, column) = locate_key(content, &key).unwrap_or((1, 1));
+                Err(ConfigError::new(
+                    path,
+                    content,
+                    line,
+                    column,
+                    format!(
+                        "`{}` must be a string of classes, found {}",
+                        key,
+                        kind(&other)
+                    ),
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Reads the keys declared under `"components"`, e.g.
+/// `"components": { "DataGrid": { "data_grid_header": "{{ border }} px-4" } }`, in file order.
+///
+/// Keys must be snake_case names that clash neither with the built-in entries nor with each
+/// other. Their templates see every built-in entry and the custom keys declared before them.
+pub fn read_custom_keys(
+    path: &Path,
+    content: &str,
+    builtin_keys: &[String],
+) -> Result<Vec<CustomKey>, ConfigError> {
+    // `read_overrides` has already reported anything that isn't a JSON object
+    let Ok(Value::Object(mut entries)) = serde_json::from_str::<Value>(content) else {
+        return Ok(Vec::new());
+    };
+    let Some(components) = entries.remove(COMPONENTS) else {
+        return Ok(Vec::new());
+    };
+    let error = |key: &str, message: String| {
+        let (line, column) = locate_key(content, key).unwrap_or((1, 1));
+        ConfigError::new(path, content, line, column, message)
+    };
+    let Value::Object(components) = components else {
+        return Err(error(
+            COMPONENTS,
+            format!(
+                "`{}` must map component names to their keys, found {}",
+                COMPONENTS,
+                kind(&components)
+            ),
+        ));
+    };
+
+    let mut custom_keys: Vec<CustomKey> = Vec::new();
+    for (component, keys) in components {
+        if !component.starts_with(|c: char| c.is_ascii_uppercase())
+            || !component.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(error(
+                &component,
+                format!(
+                    "`{}` must be a component name such as `DataGrid`",
+                    component
+                ),
+            ));
+        }
+        let Value::Object(keys) = keys else {
+            return Err(error(
+                &component,
+                format!(
+                    "`{}` must map keys to class strings, found {}",
+                    component,
+                    kind(&keys)
+                ),
+            ));
+        };
+        for (key, template) in keys {
+            let Value::String(template) = template else {
+                return Err(error(
+                    &key,
+                    format!(
+                        "`{}` must be a string of classes, found {}",
+                        key,
+                        kind(&template)
+                    ),
+                ));
+            };
+            if !key.starts_with(|c: char| c.is_ascii_lowercase())
+                || !key
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            {
+                return Err(error(
+                    &key,
+                    format!(
+                        "`{}` must be a snake_case name such as `data_grid_header`",
+                        key
+                    ),
+                ));
+            }
+            if builtin_keys.contains(&key) || custom_keys.iter().any(|custom| custom.key == key) {
+                return Err(error(&key, format!("`{}` is already defined", key)));
+            }
+            custom_keys.push(CustomKey {
+                component: component.clone(),
+                key,
+                template,
+            });
+        }
+    }
+    custom_keys.sort_by_key(|custom| locate_key(content, &custom.key));
+    Ok(custom_keys)
+}
+
+/// A `wonopui.json` with `entries` at the top level and the keys of each custom component under
+/// `"components"`, in the order given.
+pub fn to_json(entries: &[(&str, &str)], components: &[(&str, Vec<(&str, &str)>)]) -> String {
+    let quote = |text: &str| Value::from(text).to_string();
+    let mut lines: Vec<String> = entries
+        .iter()
+        .map(|(key, value)| format!("  {}: {}", quote(key), quote(value)))
+        .collect();
+    if !components.is_empty() {
+        let components: Vec<String> = components
+            .iter()
+            .map(|(component, keys)| {
+                let keys: Vec<String> = keys
+                    .iter()
+                    .map(|(key, value)| format!("      {}: {}", quote(key), quote(value)))
+                    .collect();
+                format!("    {}: {{\n{}\n    }}", quote(component), keys.join(",\n"))
+            })
+            .collect();
+        lines.push(format!(
+            "  {}: {{\n{}\n  }}",
+            quote(COMPONENTS),
+            components.join(",\n")
+        ));
+    }
+    if lines.is_empty() {
+        return "{}".to_string();
+    }
+    format!("{{\n{}\n}}", lines.join(",\n"))
+}
+
+/// Keeps the entries whose key exists, warning about the others.
+pub fn known_overrides(
+    overrides: Vec<(String, String)>,
+    known_keys: &[String],
+    path: &Path,
+    content: &str,
+) -> HashMap<String, String> {
+    let mut known = HashMap::new();
+    for (key, template) in overrides {
+        if known_keys.contains(&key) {
+            known.insert(key, template);
+            continue;
+        }
+        let location = match locate_key(content, &key) {
+            Some((line, column)) => format!("{}:{}:{}", path.display(), line, column),
+            None => path.display().to_string(),
+        };
+        let hint = match suggest(&key, known_keys.iter().map(String::as_str)) {
+            Some(known) => format!(", did you mean `{}`?", known),
+            None => String::new(),
+        };
+        println!(
+            "cargo:warning={}: unknown key `{}` is ignored{}",
+            location, key, hint
+        );
+    }
+    known
+}
+
+/// Warnings for the classes of brand config entries that don't fit the Tailwind grammar.
+///
+/// `entries` holds each key with its rendered value and its default value. Only classes the user
+/// config introduces are checked, and each problem is reported once, at the entry of the user
+/// config it comes from.
+pub fn class_problems(
+    entries: &[(&str, &str, &str)],
+    overrides: &HashMap<String, String>,
+    extra_colors: &[String],
+    path: &Path,
+    content: &str,
+) -> Vec<String> {
+    let mut problems: Vec<(&str, Vec<&str>, ClassProblem)> = Vec::new();
+    for (key, value, default) in entries {
+        for class in value.split_whitespace() {
+            if default.split_whitespace().any(|c| c == class) {
+                continue;
+            }
+            if let Some((_, keys, _)) = problems.iter_mut().find(|(c, _, _)| *c == class) {
+                keys.push(key);
+                continue;
+            }
+            if let Err(problem) = check_class(class, extra_colors) {
+                problems.push((class, vec![key], problem));
+            }
+        }
+    }
+
+    problems
+        .into_iter()
+        .map(|(class, keys, problem)| {
+            // The entry itself if the user config sets it, else the token it got the class from
+            let source = if overrides.contains_key(keys[0]) {
+                locate_key(content, keys[0])
+            } else {
+                overrides
+                    .iter()
+                    .filter(|(_, template)| template.contains(problem.fragment.as_str()))
+                    .filter_map(|(key, _)| locate_key(content, key))
+                    .min()
+            };
+            let location = match source {
+                Some((line, column)) => format!("{}:{}:{}", path.display(), line, column),
+                None => path.display().to_string(),
+            };
+            let used_by = match keys.len() {
+                1 => format!("`{}`", keys[0]),
+                2 => format!("`{}` and `{}`", keys[0], keys[1]),
+                n => format!("`{}` and {} more entries", keys[0], n - 1),
+            };
+            format!(
+                "{}: `{}` in {}: {}",
+                location, class, used_by, problem.message
+            )
+        })
+        .collect()
+}
+
+/// Describes a user template that failed to render, e.g. because it names an unknown token.
+pub fn template_error(path: &Path, content: &str, key: &str, error: &tera::Error) -> ConfigError {
+    let mut message = format!("`{}` could not be rendered", key);
+    let mut source: Option<&dyn Error> = Some(error);
+    while let Some(error) = source {
+        message.push_str(&format!(": {}", error));
+        source = error.source();
+    }
+    let (line, column) = locate_key(content, key).unwrap_or((1, 1));
+    ConfigError::new(path, content, line, column, message)
+}
+
+// Line and column (both 1-based) of `"key":` in the raw JSON.
+fn locate_key(content: &str, key: &str) -> Option<(usize, usize)> {
+    let quoted = format!("\"{}\"", key);
+    let mut from = 0;
+    while let Some(found) = content[from..].find(&quoted) {
+        let start = from + found;
+        let end = start + quoted.len();
+        if content[end..].trim_start().starts_with(':') {
+            let before = &content[..start];
+            let line = before.matches('\n').count() + 1;
+            let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+            return Some((line, column));
+        }
+        from = end;
+    }
+    None
+}
+
+// Closest known key, if it is near enough to be a plausible typo.
+pub fn suggest<'a>(key: &str, known: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let limit = (key.chars().count() / 3).max(2);
+    known
+        .map(|candidate| (edit_distance(key, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
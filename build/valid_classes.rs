@@ -1,278 +1,596 @@
-pub fn is_valid_tailwind_class(class: &str) -> bool {
-    if class.ends_with(":") || class.ends_with("-") {
-        return false;
+/// A class that doesn't fit the Tailwind grammar, with the part at fault.
+pub struct ClassProblem {
+    /// The variant, utility or color that isn't known, e.g. `indgo` in `bg-indgo-500`.
+    pub fragment: String,
+    pub message: String,
+}
+
+impl ClassProblem {
+    fn new(fragment: &str, message: String, candidates: &[&str]) -> Self {
+        let hint = match crate::user_config::suggest(fragment, candidates.iter().copied()) {
+            Some(candidate) => format!(", did you mean `{}`?", candidate),
+            None => String::new(),
+        };
+        ClassProblem {
+            fragment: fragment.to_string(),
+            message: format!("{}{}", message, hint),
+        }
+    }
+}
+
+const VARIANTS: &[&str] = &[
+    "hover",
+    "focus",
+    "focus-within",
+    "focus-visible",
+    "active",
+    "visited",
+    "target",
+    "first",
+    "last",
+    "only",
+    "odd",
+    "even",
+    "first-of-type",
+    "last-of-type",
+    "only-of-type",
+    "empty",
+    "disabled",
+    "enabled",
+    "checked",
+    "indeterminate",
+    "default",
+    "required",
+    "optional",
+    "valid",
+    "invalid",
+    "user-valid",
+    "user-invalid",
+    "in-range",
+    "out-of-range",
+    "placeholder-shown",
+    "autofill",
+    "read-only",
+    "read-write",
+    "open",
+    "inert",
+    "dark",
+    "sm",
+    "md",
+    "lg",
+    "xl",
+    "2xl",
+    "motion-safe",
+    "motion-reduce",
+    "contrast-more",
+    "contrast-less",
+    "forced-colors",
+    "print",
+    "portrait",
+    "landscape",
+    "rtl",
+    "ltr",
+    "before",
+    "after",
+    "placeholder",
+    "file",
+    "marker",
+    "selection",
+    "first-line",
+    "first-letter",
+    "backdrop",
+    "starting",
+    "*",
+];
+
+const BREAKPOINTS: &[&str] = &["sm", "md", "lg", "xl", "2xl"];
+
+// Utilities that take no value
+const STANDALONE: &[&str] = &[
+    "container",
+    "block",
+    "inline",
+    "inline-block",
+    "flex",
+    "inline-flex",
+    "grid",
+    "inline-grid",
+    "table",
+    "inline-table",
+    "contents",
+    "flow-root",
+    "list-item",
+    "hidden",
+    "static",
+    "fixed",
+    "absolute",
+    "relative",
+    "sticky",
+    "visible",
+    "invisible",
+    "collapse",
+    "isolate",
+    "truncate",
+    "uppercase",
+    "lowercase",
+    "capitalize",
+    "normal-case",
+    "underline",
+    "overline",
+    "line-through",
+    "no-underline",
+    "italic",
+    "not-italic",
+    "antialiased",
+    "subpixel-antialiased",
+    "ordinal",
+    "slashed-zero",
+    "lining-nums",
+    "oldstyle-nums",
+    "proportional-nums",
+    "tabular-nums",
+    "diagonal-fractions",
+    "stacked-fractions",
+    "normal-nums",
+    "border",
+    "rounded",
+    "shadow",
+    "outline",
+    "ring",
+    "blur",
+    "grayscale",
+    "invert",
+    "sepia",
+    "filter",
+    "transform",
+    "transform-gpu",
+    "transform-none",
+    "transition",
+    "grow",
+    "shrink",
+    "sr-only",
+    "not-sr-only",
+    "group",
+    "peer",
+    "prose",
+    "resize",
+    "drop-shadow",
+];
+
+// Utilities that take a value, longest first where one prefixes another
+const UTILITIES: &[&str] = &[
+    "ring-offset-",
+    "outline-offset-",
+    "underline-offset-",
+    "border-spacing-",
+    "min-w-",
+    "max-w-",
+    "min-h-",
+    "max-h-",
+    "line-clamp-",
+    "auto-cols-",
+    "auto-rows-",
+    "justify-items-",
+    "justify-self-",
+    "place-content-",
+    "place-items-",
+    "place-self-",
+    "grid-cols-",
+    "grid-rows-",
+    "grid-flow-",
+    "col-",
+    "row-",
+    "gap-",
+    "space-",
+    "divide-",
+    "inset-",
+    "top-",
+    "right-",
+    "bottom-",
+    "left-",
+    "start-",
+    "end-",
+    "z-",
+    "order-",
+    "basis-",
+    "flex-",
+    "grow-",
+    "shrink-",
+    "justify-",
+    "items-",
+    "content-",
+    "self-",
+    "place-",
+    "p-",
+    "px-",
+    "py-",
+    "pt-",
+    "pr-",
+    "pb-",
+    "pl-",
+    "ps-",
+    "pe-",
+    "m-",
+    "mx-",
+    "my-",
+    "mt-",
+    "mr-",
+    "mb-",
+    "ml-",
+    "ms-",
+    "me-",
+    "w-",
+    "h-",
+    "size-",
+    "font-",
+    "text-",
+    "leading-",
+    "tracking-",
+    "whitespace-",
+    "break-",
+    "hyphens-",
+    "indent-",
+    "list-",
+    "align-",
+    "decoration-",
+    "bg-",
+    "from-",
+    "via-",
+    "to-",
+    "border-",
+    "rounded-",
+    "ring-",
+    "outline-",
+    "shadow-",
+    "opacity-",
+    "mix-blend-",
+    "blur-",
+    "brightness-",
+    "contrast-",
+    "drop-shadow-",
+    "grayscale-",
+    "hue-rotate-",
+    "invert-",
+    "saturate-",
+    "sepia-",
+    "backdrop-",
+    "table-",
+    "caption-",
+    "transition-",
+    "duration-",
+    "ease-",
+    "delay-",
+    "animate-",
+    "scale-",
+    "rotate-",
+    "translate-",
+    "skew-",
+    "origin-",
+    "cursor-",
+    "select-",
+    "resize-",
+    "scroll-",
+    "snap-",
+    "touch-",
+    "pointer-events-",
+    "appearance-",
+    "caret-",
+    "accent-",
+    "will-change-",
+    "fill-",
+    "stroke-",
+    "placeholder-",
+    "overflow-",
+    "overscroll-",
+    "float-",
+    "clear-",
+    "object-",
+    "aspect-",
+    "columns-",
+    "box-",
+    "isolation-",
+    "sr-",
+    "prose-",
+    "forced-color-adjust-",
+];
+
+// Utilities whose value may be a color
+const COLOR_UTILITIES: &[&str] = &[
+    "bg-",
+    "text-",
+    "border-",
+    "ring-",
+    "ring-offset-",
+    "outline-",
+    "divide-",
+    "from-",
+    "via-",
+    "to-",
+    "fill-",
+    "stroke-",
+    "placeholder-",
+    "decoration-",
+    "accent-",
+    "caret-",
+    "shadow-",
+];
+
+const PALETTE: &[&str] = &[
+    "slate", "gray", "zinc", "neutral", "stone", "red", "orange", "amber", "yellow", "lime",
+    "green", "emerald", "teal", "cyan", "sky", "blue", "indigo", "violet", "purple", "fuchsia",
+    "pink", "rose",
+];
+
+const SHADES: &[&str] = &[
+    "50", "100", "200", "300", "400", "500", "600", "700", "800", "900", "950",
+];
+
+// Colors without shades, including the theme colors the default config relies on
+const SINGLE_COLORS: &[&str] = &[
+    "white",
+    "black",
+    "transparent",
+    "current",
+    "inherit",
+    "background",
+    "foreground",
+    "muted",
+    "muted-foreground",
+    "accent",
+    "accent-foreground",
+    "input",
+    "ring",
+];
+
+/// Checks a class against the Tailwind grammar: its variants, its utility and, for color
+/// utilities, the palette. `extra_colors` are additional theme colors to accept.
+pub fn check_class(class: &str, extra_colors: &[String]) -> Result<(), ClassProblem> {
+    let mut parts = split_variants(class);
+    let utility = parts.pop().unwrap_or_default();
+    for variant in parts {
+        if !is_valid_variant(variant) {
+            return Err(ClassProblem::new(
+                variant,
+                format!("unknown variant `{}`", variant),
+                VARIANTS,
+            ));
+        }
+    }
+
+    let utility = utility.trim_start_matches('!').trim_end_matches('!');
+    let utility = utility.strip_prefix('-').unwrap_or(utility);
+    if STANDALONE.contains(&utility) || is_arbitrary(utility) {
+        return Ok(());
     }
-    let class = if class.starts_with("-") {
-        &class[1..]
-    } else {
-        class
+    let Some(prefix) = UTILITIES
+        .iter()
+        .copied()
+        .filter(|prefix| utility.starts_with(prefix))
+        .max_by_key(|prefix| prefix.len())
+    else {
+        return Err(ClassProblem::new(
+            utility,
+            format!("unknown utility `{}`", utility),
+            STANDALONE,
+        ));
     };
-    // Define a comprehensive list of Tailwind CSS prefixes and standalone classes
-    let valid_prefixes = [
-        // Layout
-        "container",
-        "box-",
-        "block",
-        "inline",
-        "flex",
-        "grid",
-        "table",
-        "hidden",
-        // Flexbox & Grid
-        "flex-",
-        "grid-",
-        "order-",
-        "col-",
-        "row-",
-        "gap-",
-        "justify-",
-        "items-",
-        "content-",
-        "place-",
-        // Spacing
-        "p-",
-        "px-",
-        "py-",
-        "pt-",
-        "pr-",
-        "pb-",
-        "pl-",
-        "m-",
-        "mx-",
-        "my-",
-        "mt-",
-        "mr-",
-        "mb-",
-        "ml-",
-        "space-",
-        // Sizing
-        "w-",
-        "min-w-",
-        "max-w-",
-        "h-",
-        "min-h-",
-        "max-h-",
-        // Typography
-        "font-",
-        "text-",
-        "leading-",
-        "tracking-",
-        "whitespace-",
-        "break-",
-        "truncate",
-        "indent-",
-        "list-",
-        "align-",
-        "uppercase",
-        "lowercase",
-        "capitalize",
-        "normal-case",
-        // Backgrounds
-        "bg-",
-        "from-",
-        "via-",
-        "to-",
-        "gradient-",
-        // Borders
-        "border",
-        "border-",
-        "rounded",
-        "rounded-",
-        "divide-",
-        "ring-",
-        "ring-offset-",
-        // Effects
-        "shadow-",
-        "opacity-",
-        "mix-blend-",
-        "blur-",
-        "brightness-",
-        "contrast-",
-        "grayscale-",
-        "hue-rotate-",
-        "invert-",
-        "saturate-",
-        "sepia-",
-        // Filters
-        "filter",
-        "backdrop-",
-        // Tables
-        "table-",
-        // Transitions & Animation
-        "transition-",
-        "duration-",
-        "ease-",
-        "delay-",
-        "animate-",
-        // Transforms
-        "scale-",
-        "rotate-",
-        "translate-",
-        "skew-",
-        "origin-",
-        "transform",
-        // Interactivity
-        "cursor-",
-        "select-",
-        "resize-",
-        "scroll-",
-        "snap-",
-        "touch-",
-        "user-",
-        "pointer-events-",
-        "appearance-",
-        "outline-",
-        "caret-",
-        // SVG
-        "fill-",
-        "stroke-",
-        // Accessibility
-        "sr-",
-        "not-sr-",
-        // Variants
-        "hover:",
-        "focus:",
-        "active:",
-        "group-hover:",
-        "focus-within:",
-        "focus-visible:",
-        "disabled:",
-        "dark:",
-        "sm:",
-        "md:",
-        "lg:",
-        "xl:",
-        "2xl:",
-        "first:",
-        "last:",
-        "odd:",
-        "even:",
-        "visited:",
-        "checked:",
-        "indeterminate:",
-        "default:",
-        "required:",
-        "valid:",
-        "invalid:",
-        "in-range:",
-        "out-of-range:",
-        "placeholder-shown:",
-        "autofill:",
-        "read-only:",
-        // Display
-        "inline-",
-        "flow-",
-        // Position
-        "static",
-        "fixed",
-        "absolute",
-        "relative",
-        "sticky",
-        "top-",
-        "right-",
-        "bottom-",
-        "left-",
-        "inset-",
-        // Visibility
-        "visible",
-        "invisible",
-        // Z-index
-        "z-",
-        // Overflow
-        "overflow-",
-        // Float
-        "float-",
-        "clear-",
-        // Object fit
-        "object-",
-        // Aspect ratio
-        "aspect-",
-        // Columns
-        "columns-",
-        // Break
-        "break-",
-        // Additional cases
-        "prose",
-        "prose-",
-        "underline",
-        "overline",
-        "line-through",
-        "no-underline",
-        "antialiased",
-        "subpixel-antialiased",
-        "italic",
-        "not-italic",
-        "ordinal",
-        "slashed-zero",
-        "lining-nums",
-        "oldstyle-nums",
-        "proportional-nums",
-        "tabular-nums",
-        "diagonal-fractions",
-        "stacked-fractions",
-        "overscroll-",
-        "scroll-",
-        "hyphens-",
-        "write-",
-        "accent-",
-        "decoration-",
-        "placeholder-",
-        "backdrop-",
-        "will-change-",
-        "content-",
-        // Additional prefixes to cover all cases
-        "group",
-        "peer",
-        "motion-",
-        "print:",
-        "rtl:",
-        "ltr:",
-        "open:",
-        "closed:",
-        "file:",
-        "dir:",
-        "before:",
-        "after:",
-        "marker:",
-        "selection:",
-        "first-of-type:",
-        "last-of-type:",
-        "only-of-type:",
-        "only-child:",
-        "empty:",
-        "target:",
-        "enabled:",
-        "focus-visible:",
-        "optional:",
-        "placeholder:",
-        "read-write:",
-        "landscape:",
-        "portrait:",
-        "motion-safe:",
-        "motion-reduce:",
-        "contrast-more:",
-        "contrast-less:",
-        "3xl:",
-        "4xl:",
-        "5xl:",
-        "6xl:",
-        "7xl:",
-        "8xl:",
-        "9xl:",
-        "2xs:",
-        "xs:",
+    let value = &utility[prefix.len()..];
+    if value.is_empty() {
+        return Err(ClassProblem::new(
+            utility,
+            format!("`{}` is missing a value", utility),
+            &[],
+        ));
+    }
+    if !COLOR_UTILITIES.contains(&prefix) || is_arbitrary(value) || is_keyword(prefix, value) {
+        return Ok(());
+    }
+    let value = match prefix {
+        "border-" => border_side(value).map_or(value, |(_, rest)| rest),
+        _ => value,
+    };
+    check_color(value, extra_colors)
+}
+
+// Splits `t-indigo-500` into the side and the rest
+fn border_side(value: &str) -> Option<(&str, &str)> {
+    let side = value.split('-').next().unwrap_or(value);
+    ["x", "y", "t", "r", "b", "l", "s", "e"]
+        .contains(&side)
+        .then(|| (side, value[side.len()..].trim_start_matches('-')))
+}
+
+// Splits `dark:hover:bg-x` into variants and utility, leaving `:` inside brackets alone
+fn split_variants(class: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, c) in class.char_indices() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth -= 1,
+            ':' if depth == 0 => {
+                parts.push(&class[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&class[start..]);
+    parts
+}
+
+fn is_valid_variant(variant: &str) -> bool {
+    if VARIANTS.contains(&variant) || is_arbitrary(variant) {
+        return true;
+    }
+    // `group-hover`, `peer-checked/name`, `group-[.open]`
+    for prefix in ["group-", "peer-"] {
+        if let Some(rest) = variant.strip_prefix(prefix) {
+            let rest = rest.split('/').next().unwrap_or(rest);
+            return is_valid_variant(rest);
+        }
+    }
+    if let Some(rest) = variant.strip_prefix("not-") {
+        return is_valid_variant(rest);
+    }
+    if let Some(rest) = variant.strip_prefix("max-") {
+        return BREAKPOINTS.contains(&rest) || is_arbitrary(rest);
+    }
+    [
+        "aria-",
+        "data-",
         "supports-",
-        "not-",
-        "group-",
-        "peer-",
-        "all:",
-        "children:",
-        "siblings:",
-        "sibling:",
-    ];
+        "min-",
+        "has-",
+        "nth-",
+        "nth-last-",
+    ]
+    .iter()
+    .any(|prefix| variant.len() > prefix.len() && variant.starts_with(prefix))
+}
 
-    // Check if the class starts with any valid prefix or is a valid standalone class
-    valid_prefixes
-        .iter()
-        .any(|&prefix| class.starts_with(prefix) || class == prefix.trim_end_matches('-'))
+fn is_arbitrary(value: &str) -> bool {
+    let value = value.split('/').next().unwrap_or(value);
+    value.starts_with('[') && value.ends_with(']')
+}
+
+// Values of color utilities that aren't colors, e.g. `text-lg` or `border-2`
+fn is_keyword(prefix: &str, value: &str) -> bool {
+    let is_width = |value: &str| ["0", "1", "2", "4", "8"].contains(&value);
+    match prefix {
+        "text-" => [
+            "xs", "sm", "base", "lg", "xl", "2xl", "3xl", "4xl", "5xl", "6xl", "7xl", "8xl", "9xl",
+            "left", "center", "right", "justify", "start", "end", "ellipsis", "clip", "wrap",
+            "nowrap", "balance", "pretty",
+        ]
+        .contains(&value),
+        "bg-" => {
+            [
+                "fixed",
+                "local",
+                "scroll",
+                "none",
+                "auto",
+                "cover",
+                "contain",
+                "center",
+                "top",
+                "bottom",
+                "left",
+                "right",
+                "left-top",
+                "left-bottom",
+                "right-top",
+                "right-bottom",
+                "repeat",
+                "no-repeat",
+                "repeat-x",
+                "repeat-y",
+                "repeat-round",
+                "repeat-space",
+            ]
+            .contains(&value)
+                || ["clip-", "origin-", "gradient-to-", "blend-"]
+                    .iter()
+                    .any(|keyword| value.starts_with(keyword))
+        }
+        "border-" => {
+            if is_width(value)
+                || [
+                    "solid", "dashed", "dotted", "double", "hidden", "none", "collapse", "separate",
+                ]
+                .contains(&value)
+            {
+                return true;
+            }
+            // `border-t` and `border-l-8`
+            border_side(value).is_some_and(|(_, rest)| rest.is_empty() || is_width(rest))
+        }
+        "ring-" => is_width(value) || value == "inset",
+        "ring-offset-" => is_width(value),
+        "outline-" => {
+            is_width(value) || ["none", "solid", "dashed", "dotted", "double"].contains(&value)
+        }
+        "divide-" => {
+            [
+                "x",
+                "y",
+                "x-reverse",
+                "y-reverse",
+                "solid",
+                "dashed",
+                "dotted",
+                "double",
+                "none",
+            ]
+            .contains(&value)
+                || ["x-", "y-"]
+                    .iter()
+                    .any(|axis| value.strip_prefix(axis).is_some_and(is_width))
+        }
+        "from-" | "via-" | "to-" => value.ends_with('%'),
+        "fill-" => value == "none",
+        "stroke-" => ["0", "1", "2", "none"].contains(&value),
+        "decoration-" => {
+            is_width(value)
+                || [
+                    "solid",
+                    "double",
+                    "dotted",
+                    "dashed",
+                    "wavy",
+                    "auto",
+                    "from-font",
+                    "clone",
+                    "slice",
+                ]
+                .contains(&value)
+        }
+        "accent-" => value == "auto",
+        "shadow-" => ["sm", "md", "lg", "xl", "2xl", "inner", "none"].contains(&value),
+        _ => false,
+    }
+}
+
+// `indigo-500`, `indigo-500/90`, `white`, `accent/50`
+fn check_color(value: &str, extra_colors: &[String]) -> Result<(), ClassProblem> {
+    let (color, opacity) = match value.split_once('/') {
+        Some((color, opacity)) => (color, Some(opacity)),
+        None => (value, None),
+    };
+    if let Some(opacity) = opacity {
+        if opacity.parse::<f32>().is_err() && !is_arbitrary(opacity) {
+            return Err(ClassProblem::new(
+                opacity,
+                format!("invalid opacity `{}`", opacity),
+                &[],
+            ));
+        }
+    }
+    if SINGLE_COLORS.contains(&color) || extra_colors.iter().any(|extra| extra == color) {
+        return Ok(());
+    }
+    let Some((name, shade)) = color.rsplit_once('-') else {
+        return Err(ClassProblem::new(
+            color,
+            format!("unknown color `{}`", color),
+            SINGLE_COLORS,
+        ));
+    };
+    if !PALETTE.contains(&name) && !extra_colors.iter().any(|extra| extra == name) {
+        return Err(ClassProblem::new(
+            name,
+            format!("unknown color `{}`", name),
+            PALETTE,
+        ));
+    }
+    if !SHADES.contains(&shade) {
+        return Err(ClassProblem::new(
+            shade,
+            format!("unknown shade `{}` of `{}`", shade, name),
+            SHADES,
+        ));
+    }
+    Ok(())
 }
@@ -28,14 +28,21 @@ pub fn combobox_theme_editor() -> Html {
             "combobox_item_selected".to_string(),
             "Selected Item Style".to_string(),
         ),
+        ("combobox_input".to_string(), "Input Style".to_string()),
+        (
+            "combobox_item_active".to_string(),
+            "Active Item Style".to_string(),
+        ),
+        ("combobox_match".to_string(), "Match Highlight Style".to_string()),
+        ("combobox_message".to_string(), "Message Style".to_string()),
     ];
 
     let preview = html! {
-        <Combobox
+        <Combobox<ComboboxOption>
             options={vec![
-                ("1".to_string(), "Option 1".to_string()),
-                ("2".to_string(), "Option 2".to_string()),
-                ("3".to_string(), "Option 3".to_string())
+                ComboboxOption::from(("1".to_string(), "Option 1".to_string())),
+                ComboboxOption::from(("2".to_string(), "Option 2".to_string())),
+                ComboboxOption::from(("3".to_string(), "Option 3".to_string())),
             ]}
             on_select={Callback::from(|_| {})}
         />
@@ -59,13 +66,14 @@ pub fn combobox_documentation() -> Html {
             <h2 class="text-2xl font-semibold mb-4 text-zinc-900 dark:text-white">{ "Example" }</h2>
             <ExampleCode
                 preview={html! {
-                    <Combobox
+                    <Combobox<ComboboxOption>
                         options={vec![
-                            ("1".to_string(), "Option 1".to_string()),
-                            ("2".to_string(), "Option 2".to_string()),
-                            ("3".to_string(), "Option 3".to_string())
+                            ComboboxOption::from(("1".to_string(), "Option 1".to_string())),
+                            ComboboxOption::from(("2".to_string(), "Option 2".to_string())),
+                            ComboboxOption::from(("3".to_string(), "Option 3".to_string())),
                         ]}
-                        on_select={Callback::from(|selected: String| {
+                        matcher={ComboboxMatcher::Fuzzy}
+                        on_select={Callback::from(|selected: ComboboxOption| {
                             // Handle selection
                         })}
                     />
@@ -74,13 +82,14 @@ pub fn combobox_documentation() -> Html {
                     <ComboboxThemeEditor />
                 }}
                 code={r#"
-<Combobox
+<Combobox<ComboboxOption>
     options={vec![
-        ("1".to_string(), "Option 1".to_string()),
-        ("2".to_string(), "Option 2".to_string()),
-        ("3".to_string(), "Option 3".to_string())
+        ComboboxOption::from(("1".to_string(), "Option 1".to_string())),
+        ComboboxOption::from(("2".to_string(), "Option 2".to_string())),
+        ComboboxOption::from(("3".to_string(), "Option 3".to_string())),
     ]}
-    on_select={Callback::from(|selected: String| {
+    matcher={ComboboxMatcher::Fuzzy}
+    on_select={Callback::from(|selected: ComboboxOption| {
         // Handle selection
     })}
 />"#.to_string()}
@@ -97,8 +106,21 @@ pub fn combobox_documentation() -> Html {
                 description="Props for the Combobox component."
                 props={vec![
                     ("id", "String", "The unique identifier for the combobox."),
-                    ("options", "Vec<(String, String)>", "A list of options where each option is a tuple of (value, label)."),
-                    ("on_select", "Callback<String>", "The callback to be called when an option is selected."),
+                    ("options", "Vec<T>", "The options to choose from. `T` needs `Clone + PartialEq + ToString`; `ComboboxOption` covers plain (value, label) pairs."),
+                    ("value", "Option<T>", "The selected option. When set, the combobox is controlled."),
+                    ("default_value", "Option<T>", "The initially selected option for an uncontrolled combobox."),
+                    ("on_select", "Callback<T>", "The callback to be called when an option is selected."),
+                    ("get_label", "Option<Callback<T, String>>", "Returns the label shown for an option. Defaults to `ToString`."),
+                    ("get_value", "Option<Callback<T, String>>", "Returns the value used as item key and in the hidden form field. Defaults to the label."),
+                    ("name", "Option<AttrValue>", "Renders a hidden input with this name carrying the selected value."),
+                    ("matcher", "ComboboxMatcher", "How typed text is matched against labels: Substring (default), Prefix, Fuzzy or Custom."),
+                    ("allow_create", "bool", "Offers a \"Create\" item when the typed text matches no label exactly. Default is false."),
+                    ("on_create", "Callback<String>", "Called with the typed text when the \"Create\" item is chosen."),
+                    ("load_options", "Option<Callback<(String, Callback<Vec<T>>)>>", "Loads suggestions for the typed text. Replaces `options`; outdated responses are ignored."),
+                    ("debounce_ms", "u32", "Delay before `load_options` is called after typing. Default is 250."),
+                    ("placeholder", "AttrValue", "Input placeholder. Default is \"Select option...\"."),
+                    ("loading_text", "AttrValue", "Shown while suggestions load. Default is \"Loading...\"."),
+                    ("empty_text", "AttrValue", "Shown when nothing matches. Default is \"No results\"."),
                     ("disabled", "bool", "Whether the combobox is disabled. Default is false."),
                 ]}
             />
@@ -106,12 +128,13 @@ pub fn combobox_documentation() -> Html {
             <NotesSection
                 title={"Usage Notes".to_string()}
                 notes={vec![
-                    "The Combobox uses a button to toggle the options list.".to_string(),
+                    "The Combobox uses a text input with role=\"combobox\"; the chevron toggles the options list.".to_string(),
                     "Options are displayed in a dropdown list when the combobox is open.".to_string(),
                     "The component is keyboard accessible and can be navigated using arrow keys.".to_string(),
                     "The selected option is visually distinguished in the list.".to_string(),
                     "The component manages its own open/closed state internally.".to_string(),
-                    "Users can type to filter options, providing a quick way to find specific items in large lists.".to_string(),
+                    "Users can type to filter options, providing a quick way to find specific items in large lists. Matched characters are highlighted.".to_string(),
+                    "With `load_options`, the options are not filtered locally; the loader is expected to return matching suggestions.".to_string(),
                     "The Combobox supports both mouse and keyboard interactions for improved accessibility.".to_string(),
                     "Consider using Combobox when you have a large number of options or when users might need to search through the options.".to_string(),
                 ]}
@@ -126,6 +149,10 @@ pub fn combobox_documentation() -> Html {
                     ("combobox_list".to_string(), "Styles for the dropdown list container. This affects the appearance of the option list when it's visible.".to_string()),
                     ("combobox_item".to_string(), "Styles for individual items in the dropdown list. This determines how each option looks.".to_string()),
                     ("combobox_item_selected".to_string(), "Styles applied to the currently selected item. This should make the selected option stand out from the others.".to_string()),
+                    ("combobox_input".to_string(), "Styles for the text input inside the combobox button.".to_string()),
+                    ("combobox_item_active".to_string(), "Styles for the item highlighted with the keyboard or pointer.".to_string()),
+                    ("combobox_match".to_string(), "Styles for the characters of a label that match the typed text.".to_string()),
+                    ("combobox_message".to_string(), "Styles for the loading and empty messages.".to_string()),
                 ]}
             />
         </Container>
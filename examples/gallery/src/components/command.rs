@@ -27,6 +27,13 @@ pub fn command_theme_editor() -> Html {
             "command_selected_item".to_string(),
             "Selected Item".to_string(),
         ),
+        (
+            "command_group_heading".to_string(),
+            "Group Heading".to_string(),
+        ),
+        ("command_match".to_string(), "Match Highlight".to_string()),
+        ("command_empty".to_string(), "Empty Message".to_string()),
+        ("command_breadcrumb".to_string(), "Page Breadcrumb".to_string()),
     ];
 
     let preview = html! {
@@ -98,6 +105,80 @@ pub fn command_documentation() -> Html {
 />"#.to_string()}
             />
 
+            <h2 class="text-2xl font-semibold mt-8 mb-4 text-zinc-900 dark:text-white">{ "Groups, Pinned Items and Nested Pages" }</h2>
+            <ExampleCode
+                preview={html! {
+                    <Command<String>
+                        placeholder="Type a command..."
+                        expanded=true
+                        items={vec![
+                            CommandItem::new("new-file".to_string(), "New file").keywords("create, document").pinned(),
+                            CommandItem::new("open".to_string(), "Open file").group("File"),
+                            CommandItem::new("save".to_string(), "Save").group("File"),
+                            CommandItem::new("theme".to_string(), "Change theme...").group("Preferences").children(vec![
+                                CommandItem::new("theme-light".to_string(), "Light"),
+                                CommandItem::new("theme-dark".to_string(), "Dark"),
+                                CommandItem::new("theme-system".to_string(), "System"),
+                            ]),
+                        ]}
+                        on_select={Callback::from(|_| {})}
+                    />
+                }}
+                code={r#"
+<Command<String>
+    placeholder="Type a command..."
+    expanded=true
+    items={vec![
+        CommandItem::new("new-file".to_string(), "New file").keywords("create, document").pinned(),
+        CommandItem::new("open".to_string(), "Open file").group("File"),
+        CommandItem::new("save".to_string(), "Save").group("File"),
+        CommandItem::new("theme".to_string(), "Change theme...").group("Preferences").children(vec![
+            CommandItem::new("theme-light".to_string(), "Light"),
+            CommandItem::new("theme-dark".to_string(), "Dark"),
+            CommandItem::new("theme-system".to_string(), "System"),
+        ]),
+    ]}
+    on_select={Callback::from(|value: String| {
+        // Handle the selection
+    })}
+/>"#.to_string()}
+            />
+
+            <h2 class="text-2xl font-semibold mt-8 mb-4 text-zinc-900 dark:text-white">{ "Command Palette (Ctrl/Cmd+K)" }</h2>
+            <ExampleCode
+                preview={html! {
+                    <CommandProvider>
+                        <p class="text-sm text-zinc-600 dark:text-zinc-400">{ "Press Ctrl+K or Cmd+K to open the palette." }</p>
+                        <CommandDialog<String>
+                            placeholder="Search..."
+                            items={vec![
+                                CommandItem::new("home".to_string(), "Go to home").group("Navigation"),
+                                CommandItem::new("settings".to_string(), "Go to settings").group("Navigation"),
+                            ]}
+                            on_select={Callback::from(|_| {})}
+                        />
+                    </CommandProvider>
+                }}
+                code={r#"
+<CommandProvider>
+    <App />
+    <CommandDialog<String>
+        placeholder="Search..."
+        items={vec![
+            CommandItem::new("home".to_string(), "Go to home").group("Navigation"),
+            CommandItem::new("settings".to_string(), "Go to settings").group("Navigation"),
+        ]}
+        on_select={Callback::from(|route: String| {
+            // Navigate
+        })}
+    />
+</CommandProvider>
+
+// Elsewhere, e.g. a toolbar button:
+let palette = use_command_palette();
+let onclick = Callback::from(move |_| palette.open.emit(()));"#.to_string()}
+            />
+
             <Features features={vec!["Command", "Search", "Keyboard Navigation"]} />
 
             <h2 class="text-2xl font-semibold mt-8 mb-4 text-zinc-900 dark:text-white">
@@ -110,11 +191,44 @@ pub fn command_documentation() -> Html {
                 props={vec![
                     ("placeholder", "String", "The placeholder text to be displayed in the input field."),
                     ("options", "Vec<(T, String, String, Option<Html>)>", "A list of options where each option is a tuple of (value, keywords, label, icon)."),
+                    ("items", "Vec<CommandItem<T>>", "Items listed after `options`. `CommandItem` adds groups, pinned items and nested pages through `children`."),
                     ("on_select", "Callback<T>", "The callback to be called when an option is selected."),
+                    ("recent", "Vec<T>", "Recently selected values, most recent first. They are listed under a heading while the search is empty."),
+                    ("max_recent", "usize", "How many selections are remembered as recent. `0` turns the recent list off. Default is 5."),
+                    ("on_recent_change", "Callback<Vec<T>>", "Called with the updated recent values after a selection, e.g. to persist them."),
+                    ("pinned_heading", "AttrValue", "Heading of the pinned items. Default is \"Pinned\"."),
+                    ("recent_heading", "AttrValue", "Heading of the recent items. Default is \"Recent\"."),
+                    ("empty_text", "AttrValue", "Shown when nothing matches. Default is \"No results available\"."),
+                    ("expanded", "bool", "Always show the list instead of opening it on click. Default is false."),
+                    ("autofocus", "bool", "Focus the input when mounted. Default is false."),
                     ("class", "Classes", "Additional CSS classes to apply to the command container."),
                 ]}
             />
 
+            <ApiSection
+                title="CommandProvider"
+                description="Provides the palette state and opens it on Ctrl+K or Cmd+K. Read it with `use_command_palette()`."
+                props={vec![
+                    ("children", "Children", "The content the shortcut applies to."),
+                    ("shortcut_key", "AttrValue", "Key pressed together with Ctrl or Cmd. Default is \"k\"."),
+                ]}
+            />
+
+            <ApiSection
+                title="CommandDialog"
+                description="A modal Command shown while the enclosing CommandProvider is open. It closes on selection, Escape or a click outside."
+                props={vec![
+                    ("placeholder", "String", "The placeholder text of the input."),
+                    ("options", "Vec<(T, String, String, Option<Html>)>", "Options as (value, keywords, label, icon) tuples."),
+                    ("items", "Vec<CommandItem<T>>", "Items with groups, pinned state and nested pages."),
+                    ("on_select", "Callback<T>", "Called after the palette closes with the selected value."),
+                    ("recent", "Vec<T>", "Recently selected values, most recent first."),
+                    ("max_recent", "usize", "How many selections are remembered as recent. Default is 5."),
+                    ("on_recent_change", "Callback<Vec<T>>", "Called with the updated recent values after a selection."),
+                    ("class", "Classes", "Additional CSS classes for the command container."),
+                ]}
+            />
+
             <NotesSection
                 title={"Usage Notes".to_string()}
                 notes={vec![
@@ -122,7 +236,8 @@ pub fn command_documentation() -> Html {
                     "The dropdown list is only rendered when the command is open.".to_string(),
                     "The Command component supports icons for each option, which can be customized as needed.".to_string(),
                     "For accessibility, the component supports keyboard navigation (Arrow Up/Down, Enter, Escape) and screen reader announcements.".to_string(),
-                    "The search functionality is handled internally, filtering options based on user input.".to_string(),
+                    "Search is fuzzy: the typed characters must appear in order in the label or a keyword. Results are ranked, favouring word starts and adjacent characters, and matched label characters are highlighted.".to_string(),
+                    "Choosing an item with `children` opens them as a nested page. Backspace in an empty input or Escape goes back one page.".to_string(),
                     "Use the Command component when you need a powerful search and select interface, especially for applications with many commands or options.".to_string(),
                     "The component is ideal for implementing features like command palettes or advanced search functionalities.".to_string(),
                     "Consider using keyboard shortcuts to quickly open the Command component for improved user efficiency.".to_string(),
@@ -141,6 +256,12 @@ pub fn command_documentation() -> Html {
                     ("command_item".to_string(), "Styles individual items in the list. Use this to customize how each option appears.".to_string()),
                     ("command_item_icon".to_string(), "Styles icons within list items. Adjust this to control the appearance of option icons.".to_string()),
                     ("command_selected_item".to_string(), "Styles the currently selected item in the list. Use this to highlight the active option.".to_string()),
+                    ("command_group_heading".to_string(), "Styles group headings, including the pinned and recent headings.".to_string()),
+                    ("command_match".to_string(), "Styles the label characters matching the search.".to_string()),
+                    ("command_empty".to_string(), "Styles the message shown when nothing matches.".to_string()),
+                    ("command_breadcrumb".to_string(), "Styles the labels of the open nested pages in front of the input.".to_string()),
+                    ("command_dialog_overlay".to_string(), "Styles the backdrop of CommandDialog.".to_string()),
+                    ("command_dialog_content".to_string(), "Styles the box holding the Command inside CommandDialog.".to_string()),
                 ]}
             />
         </Container>
@@ -235,6 +235,9 @@ pub fn context_menu_documentation() -> Html {
                     ("children", "Children", "The child elements to be rendered inside the context menu item."),
                     ("inset", "bool", "Whether the item should be inset."),
                     ("disabled", "bool", "Whether the item is disabled."),
+                    ("onclick", "Callback<MouseEvent>", "Called when the item is activated by click, Enter or Space. The menu closes afterwards."),
+                    ("shortcut", "Option<AttrValue>", "Keyboard shortcut hint rendered at the end of the item."),
+                    ("text_value", "Option<AttrValue>", "Label used for typeahead when the children are not plain text."),
                 ]}
             />
 
@@ -252,6 +255,7 @@ pub fn context_menu_documentation() -> Html {
                 props={vec![
                     ("children", "Children", "The child elements to be rendered inside the submenu trigger."),
                     ("inset", "bool", "Whether the trigger should be inset."),
+                    ("text_value", "Option<AttrValue>", "Label used for typeahead when the children are not plain text."),
                 ]}
             />
 
@@ -276,6 +280,10 @@ pub fn context_menu_documentation() -> Html {
                 props={vec![
                     ("children", "Children", "The child elements to be rendered inside the checkbox item."),
                     ("checked", "bool", "Whether the checkbox is checked."),
+                    ("disabled", "bool", "Whether the item is disabled."),
+                    ("onchange", "Callback<bool>", "Called with the new checked state when the item is activated."),
+                    ("shortcut", "Option<AttrValue>", "Keyboard shortcut hint rendered at the end of the item."),
+                    ("text_value", "Option<AttrValue>", "Label used for typeahead when the children are not plain text."),
                 ]}
             />
 
@@ -285,6 +293,7 @@ pub fn context_menu_documentation() -> Html {
                 props={vec![
                     ("children", "Children", "The child elements to be rendered inside the radio group."),
                     ("value", "String", "The currently selected value in the radio group."),
+                    ("onchange", "Callback<String>", "Called with the value of the radio item that was activated."),
                 ]}
             />
 
@@ -303,6 +312,9 @@ pub fn context_menu_documentation() -> Html {
                 props={vec![
                     ("children", "Children", "The child elements to be rendered inside the radio item."),
                     ("value", "String", "The value associated with this radio item."),
+                    ("disabled", "bool", "Whether the item is disabled."),
+                    ("shortcut", "Option<AttrValue>", "Keyboard shortcut hint rendered at the end of the item."),
+                    ("text_value", "Option<AttrValue>", "Label used for typeahead when the children are not plain text."),
                 ]}
             />
 
@@ -319,12 +331,14 @@ pub fn context_menu_documentation() -> Html {
                 notes={vec![
                     "The ContextMenu component uses context to manage its open/closed state.".to_string(),
                     "Right-clicking on the ContextMenuTrigger opens the context menu.".to_string(),
-                    "The context menu is automatically hidden when clicking outside the menu.".to_string(),
+                    "The context menu is automatically hidden when clicking outside the menu, scrolling, resizing the window or pressing Escape.".to_string(),
                     "Use ContextMenuSub for nested submenus.".to_string(),
                     "ContextMenuCheckboxItem and ContextMenuRadioItem can be used for selectable options.".to_string(),
                     "ContextMenuShortcut can be used to display keyboard shortcuts for menu items.".to_string(),
-                    "The component supports keyboard navigation for improved accessibility.".to_string(),
-                    "Ensure that the context menu is positioned within the viewport to avoid overflow issues.".to_string(),
+                    "Arrow keys move between items, Right opens a submenu, Left closes it and Enter or Space activates the focused item.".to_string(),
+                    "Typing the first letters of an item label moves focus to it.".to_string(),
+                    "Shift+F10 or the context menu key opens the menu from a focused trigger.".to_string(),
+                    "The menu is clamped to the viewport, and submenus flip to the left when there is no room on the right.".to_string(),
                 ]}
             />
 
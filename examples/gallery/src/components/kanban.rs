@@ -3,7 +3,7 @@ use crate::api_section::ApiSection;
 use crate::features_section::Features;
 use crate::notes_section::NotesSection;
 use crate::styling_section::StylingSection;
-use std::cmp::Ordering;
+use std::collections::HashMap;
 use wonopui::prelude::{BrandGuideType as BrandGuide, ClassesStr, ComponentEditor};
 use wonopui::*;
 use yew::prelude::*;
@@ -75,149 +75,75 @@ pub fn kanban_theme_editor() -> Html {
 
 #[function_component(KanbanExample)]
 pub fn kanban_example() -> Html {
-    let cards = use_state(|| {
-        vec![
-            (
-                String::from("task1"),
-                String::from("todo"),
-                String::from("Project Setup"),
-                String::from("Configure repository and install dependencies"),
-                0,
-            ),
-            (
-                String::from("task2"),
-                String::from("todo"),
-                String::from("Design UI"),
-                String::from("Create wireframes and mockups"),
-                1,
-            ),
-            (
-                String::from("task3"),
-                String::from("progress"),
-                String::from("Implementation"),
-                String::from("Start coding the core features"),
-                0,
-            ),
+    let columns = use_state(|| {
+        let card = |id: &str, title: &str, content: &str| {
+            (id.to_string(), title.to_string(), content.to_string())
+        };
+        HashMap::from([
             (
-                String::from("task4"),
-                String::from("progress"),
-                String::from("API Integration"),
-                String::from("Connect to backend services"),
-                1,
+                "todo".to_string(),
+                vec![
+                    card("task1", "Project Setup", "Configure repository and install dependencies"),
+                    card("task2", "Design UI", "Create wireframes and mockups"),
+                ],
             ),
             (
-                String::from("task5"),
-                String::from("done"),
-                String::from("Requirements"),
-                String::from("Gather project requirements"),
-                0,
+                "progress".to_string(),
+                vec![
+                    card("task3", "Implementation", "Start coding the core features"),
+                    card("task4", "API Integration", "Connect to backend services"),
+                ],
             ),
             (
-                String::from("task6"),
-                String::from("done"),
-                String::from("Planning"),
-                String::from("Create project roadmap"),
-                1,
+                "done".to_string(),
+                vec![
+                    card("task5", "Requirements", "Gather project requirements"),
+                    card("task6", "Planning", "Create project roadmap"),
+                ],
             ),
-        ]
+        ])
     });
 
-    let ondrop = {
-        let cards = cards.clone();
-        Callback::from(
-            move |(card_id, column_id, target_card_id): (String, String, Option<String>)| {
-                let mut updated_cards = (*cards).clone();
-
-                // Find maximum order for the destination column
-                let max_order = updated_cards
-                    .iter()
-                    .filter(|(_, col, _, _, _)| *col == column_id)
-                    .map(|(_, _, _, _, order)| *order)
-                    .max()
-                    .unwrap_or(0)
-                    + 1;
-
-                // Find the card index and process the target position before modifying card
-                let card_index = updated_cards
-                    .iter()
-                    .position(|(id, _, _, _, _)| *id == card_id);
-
-                // Process target_card_id and get target order if applicable
-                let target_order = if let Some(target_id) = &target_card_id {
-                    updated_cards
-                        .iter()
-                        .position(|(id, col, _, _, _)| id == target_id && *col == column_id)
-                        .map(|pos| updated_cards[pos].4)
-                } else {
-                    None
-                };
-
-                // Now apply the changes using the card index
-                if let Some(index) = card_index {
-                    // Update column
-                    updated_cards[index].1 = column_id.clone();
-
-                    if let Some(order) = target_order {
-                        // Shift all cards with order >= target_order up by 1
-                        for c in updated_cards.iter_mut() {
-                            if c.1 == column_id && c.0 != card_id && c.4 >= order {
-                                c.4 += 1;
-                            }
-                        }
-
-                        // Set the dropped card to the target order
-                        updated_cards[index].4 = order;
-                    } else {
-                        // If no target or target not found, put at end
-                        updated_cards[index].4 = max_order;
-                    }
-                }
-
-                cards.set(updated_cards);
-            },
-        )
+    let on_move = {
+        let columns = columns.clone();
+        Callback::from(move |card_move: KanbanMove| {
+            let mut updated = (*columns).clone();
+            card_move.apply(&mut updated, |(id, _, _)| id);
+            columns.set(updated);
+        })
     };
 
     let render_cards = |column_id: &str| -> Vec<VChild<KanbanCard>> {
-        let mut column_cards: Vec<_> = cards
-            .iter()
-            .filter(|(_, column, _, _, _)| column == column_id)
-            .collect();
-
-        // Sort by order
-        column_cards.sort_by(|(_, _, _, _, order1), (_, _, _, _, order2)| order1.cmp(order2));
-
-        column_cards
+        columns
+            .get(column_id)
             .into_iter()
-            .map(|(id, _, title, content, order)| {
-                let id = id.clone();
-                let title = title.clone();
-                let content = content.clone();
-                let order = *order;
+            .flatten()
+            .enumerate()
+            .map(|(order, (id, title, content))| {
                 html_nested! {
-                    <KanbanCard id={id} title={title} order={order}>
-                        {content}
+                    <KanbanCard id={id.clone()} title={title.clone()} order={order}>
+                        {content.clone()}
                     </KanbanCard>
                 }
             })
-            .collect::<Vec<_>>()
+            .collect()
     };
 
     html! {
         <div class="w-full">
             <div class="mb-4 p-4 bg-green-50 dark:bg-green-900/30 rounded-md">
                 <p class="text-sm text-green-800 dark:text-green-500">
-                    {"Try dragging the cards between columns to see the Kanban board in action!"}
+                    {"Drag the cards between columns, or focus a card and press Space to move it with the arrow keys!"}
                 </p>
             </div>
-            <Kanban>
-                <KanbanColumn id="todo" title="To Do" ondrop={ondrop.clone()}>
+            <Kanban {on_move}>
+                <KanbanColumn id="todo" title="To Do">
                     {for render_cards("todo")}
                 </KanbanColumn>
-                <KanbanColumn id="progress" title="In Progress" ondrop={ondrop.clone()}>
+                <KanbanColumn id="progress" title="In Progress">
                     {for render_cards("progress")}
                 </KanbanColumn>
-                <KanbanColumn id="done" title="Done" ondrop={ondrop.clone()}>
+                <KanbanColumn id="done" title="Done">
                     {for render_cards("done")}
                 </KanbanColumn>
             </Kanban>
@@ -247,106 +173,51 @@ pub fn kanban_documentation() -> Html {
                     <KanbanThemeEditor />
                 }}
                 code={r#"
-// First, define your cards state with ordering
-let cards = use_state(|| vec![
-    (String::from("task1"), String::from("todo"), String::from("Project Setup"), String::from("Configure repository and install dependencies"), 0),
-    (String::from("task2"), String::from("todo"), String::from("Design UI"), String::from("Create wireframes and mockups"), 1),
-    (String::from("task3"), String::from("progress"), String::from("Implementation"), String::from("Start coding the core features"), 0),
-    (String::from("task4"), String::from("progress"), String::from("API Integration"), String::from("Connect to backend services"), 1),
-    (String::from("task5"), String::from("done"), String::from("Requirements"), String::from("Gather project requirements"), 0),
-    (String::from("task6"), String::from("done"), String::from("Planning"), String::from("Create project roadmap"), 1),
-]);
-
-// Create the drop handler to update card positions
-let ondrop = {
-    let cards = cards.clone();
-    Callback::from(move |(card_id, column_id, target_card_id): (String, String, Option<String>)| {
-        let mut updated_cards = (*cards).clone();
-
-        // Find maximum order for the destination column
-        let max_order = updated_cards
-            .iter()
-            .filter(|(_, col, _, _, _)| *col == column_id)
-            .map(|(_, _, _, _, order)| *order)
-            .max()
-            .unwrap_or(0) + 1;
-
-        // Find the card index and process the target position before modifying card
-        let card_index = updated_cards
-            .iter()
-            .position(|(id, _, _, _, _)| *id == card_id);
-
-        // Process target_card_id and get target order if applicable
-        let target_order = if let Some(target_id) = &target_card_id {
-            updated_cards
-                .iter()
-                .position(|(id, col, _, _, _)| id == target_id && *col == column_id)
-                .map(|pos| updated_cards[pos].4)
-        } else {
-            None
-        };
-
-        // Now apply the changes using the card index
-        if let Some(index) = card_index {
-            // Update column
-            updated_cards[index].1 = column_id.clone();
-
-            if let Some(order) = target_order {
-                // Shift all cards with order >= target_order up by 1
-                for c in updated_cards.iter_mut() {
-                    if c.1 == column_id && c.0 != card_id && c.4 >= order {
-                        c.4 += 1;
-                    }
-                }
-
-                // Set the dropped card to the target order
-                updated_cards[index].4 = order;
-            } else {
-                // If no target or target not found, put at end
-                updated_cards[index].4 = max_order;
-            }
-        }
-
-        cards.set(updated_cards);
+// Keep the cards of each column in display order
+let columns = use_state(|| HashMap::from([
+    ("todo".to_string(), vec![
+        ("task1".to_string(), "Project Setup".to_string(), "Configure repository and install dependencies".to_string()),
+        ("task2".to_string(), "Design UI".to_string(), "Create wireframes and mockups".to_string()),
+    ]),
+    ("progress".to_string(), vec![
+        ("task3".to_string(), "Implementation".to_string(), "Start coding the core features".to_string()),
+    ]),
+    ("done".to_string(), vec![]),
+]));
+
+// KanbanMove carries the final position, so applying it is all that is needed
+let on_move = {
+    let columns = columns.clone();
+    Callback::from(move |card_move: KanbanMove| {
+        let mut updated = (*columns).clone();
+        card_move.apply(&mut updated, |(id, _, _)| id);
+        columns.set(updated);
     })
 };
 
-// Helper function to render cards for a column
 let render_cards = |column_id: &str| -> Vec<VChild<KanbanCard>> {
-    let mut column_cards: Vec<_> = cards
-        .iter()
-        .filter(|(_, column, _, _, _)| column == column_id)
-        .collect();
-
-    // Sort by order
-    column_cards.sort_by(|(_, _, _, _, order1), (_, _, _, _, order2)| order1.cmp(order2));
-
-    column_cards
+    columns
+        .get(column_id)
         .into_iter()
-        .map(|(id, _, title, content, order)| {
-            let id = id.clone();
-            let title = title.clone();
-            let content = content.clone();
-            let order = *order;
-            html_nested! {
-                <KanbanCard id={id} title={title} order={order}>
-                    {content}
-                </KanbanCard>
-            }
+        .flatten()
+        .enumerate()
+        .map(|(order, (id, title, content))| html_nested! {
+            <KanbanCard id={id.clone()} title={title.clone()} order={order}>
+                {content.clone()}
+            </KanbanCard>
         })
-        .collect::<Vec<_>>()
+        .collect()
 };
 
-// Render the Kanban board
 html! {
-    <Kanban>
-        <KanbanColumn id="todo" title="To Do" ondrop={ondrop.clone()}>
+    <Kanban {on_move}>
+        <KanbanColumn id="todo" title="To Do">
             {for render_cards("todo")}
         </KanbanColumn>
-        <KanbanColumn id="progress" title="In Progress" ondrop={ondrop.clone()}>
+        <KanbanColumn id="progress" title="In Progress">
             {for render_cards("progress")}
         </KanbanColumn>
-        <KanbanColumn id="done" title="Done" ondrop={ondrop.clone()}>
+        <KanbanColumn id="done" title="Done">
             {for render_cards("done")}
         </KanbanColumn>
     </Kanban>
@@ -354,7 +225,8 @@ html! {
             />
 
             <Features features={vec![
-                "Drag and drop cards between columns",
+                "Drag and drop cards between columns with mouse, pen or touch",
+                "Keyboard moves with screen reader announcements",
                 "Proper card ordering within columns",
                 "Customizable column and card styles",
                 "Visual feedback during drag operations",
@@ -374,6 +246,7 @@ html! {
                     ("children", "Children", "The columns to display on the board."),
                     ("class", "Classes", "Additional CSS classes for the Kanban container."),
                     ("allow_multiple_column_drops", "bool", "If true, allows dropping cards on multiple columns simultaneously. Default is false."),
+                    ("on_move", "Callback<KanbanMove>", "Called once when a card is dropped at a new position. `KanbanMove { card_id, from_column, to_column, index }` gives the final index in the target column; `KanbanMove::apply` updates a `HashMap<String, Vec<C>>` of cards per column."),
                 ]}
             />
 
@@ -387,10 +260,7 @@ html! {
                     ("body_class", "Classes", "Additional CSS classes for the column body."),
                     ("id", "AttrValue", "Unique identifier for the column."),
                     ("title", "AttrValue", "The title to display in the column header."),
-                    ("ondragenter", "Option<Callback<DragEvent>>", "Callback when a draggable item enters the column."),
-                    ("ondragleave", "Option<Callback<DragEvent>>", "Callback when a draggable item leaves the column."),
-                    ("ondragover", "Option<Callback<DragEvent>>", "Callback when a draggable item is over the column."),
-                    ("ondrop", "Option<Callback<(String, String, Option<String>)>>", "Callback when a card is dropped on the column. Receives (card_id, column_id, target_card_id)."),
+                    ("ondrop", "Option<Callback<(String, String, Option<String>)>>", "Callback when a card is dropped on the column. Receives (card_id, column_id, target_card_id), where the target is the card it was placed in front of. Prefer `Kanban::on_move`."),
                 ]}
             />
 
@@ -404,11 +274,6 @@ html! {
                     ("column_id", "Option<AttrValue>", "ID of the column the card belongs to. This is set automatically by the KanbanColumn."),
                     ("title", "Option<AttrValue>", "Optional title to display at the top of the card."),
                     ("order", "Option<usize>", "Ordering position within the column. Cards are sorted by this value."),
-                    ("ondragstart", "Option<Callback<DragEvent>>", "Callback when dragging of the card starts."),
-                    ("ondragend", "Option<Callback<DragEvent>>", "Callback when dragging of the card ends."),
-                    ("ondragover", "Option<Callback<DragEvent>>", "Callback when a draggable item is over the card."),
-                    ("ondragenter", "Option<Callback<DragEvent>>", "Callback when a draggable item enters the card."),
-                    ("ondragleave", "Option<Callback<DragEvent>>", "Callback when a draggable item leaves the card."),
                     ("onclick", "Option<Callback<MouseEvent>>", "Callback when the card is clicked."),
                 ]}
             />
@@ -419,12 +284,12 @@ html! {
                     "Each card must have a unique `id` to enable proper drag and drop functionality.".to_string(),
                     "Each column must have a unique `id` to properly identify where cards are dropped.".to_string(),
                     "Cards are automatically assigned the column_id of their parent column.".to_string(),
-                    "The ondrop callback receives a tuple of (card_id, column_id, target_card_id) to support proper card ordering.".to_string(),
                     "Adding an order property to cards ensures they maintain proper positioning within columns.".to_string(),
-                    "Cards will be reordered when dropped on or between other cards based on the target_card_id.".to_string(),
-                    "The Kanban component uses the HTML5 Drag and Drop API, which provides native support across modern browsers.".to_string(),
-                    "For accessibility, ensure you include proper ARIA attributes and keyboard navigation alternatives.".to_string(),
-                    "The component provides visual feedback during drag operations through CSS classes that can be customized.".to_string(),
+                    "Dragging uses pointer events, so it works with mouse, pen and touch. On touch screens, press and hold a card briefly before dragging; quick swipes still scroll.".to_string(),
+                    "While dragging, a copy of the card follows the pointer and a placeholder shows where it will land.".to_string(),
+                    "Cards are focusable. Press Space to pick one up, the arrow keys to move it within and across columns, Space to drop it and Escape to cancel. Each step is announced through a live region.".to_string(),
+                    "Dropping a card where it started emits nothing.".to_string(),
+                    "Presses on buttons, links and form fields inside a card do not start a drag.".to_string(),
                 ]}
             />
 
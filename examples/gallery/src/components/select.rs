@@ -91,6 +91,16 @@ pub fn select_documentation() -> Html {
                     ("options", "Vec<T>", "A vector of options to display in the dropdown."),
                     ("selected", "Option<T>", "The currently selected option, if any."),
                     ("onchange", "Callback<T>", "A callback function that is called when an option is selected."),
+                    ("groups", "Vec<SelectGroup<T>>", "Options rendered under a group heading, after the ungrouped options."),
+                    ("multiple", "bool", "Allows several options to be selected. Selected options are shown as removable chips."),
+                    ("selected_values", "Vec<T>", "The initially selected options in multi-select mode."),
+                    ("onchange_multiple", "Callback<Vec<T>>", "Called with all selected options whenever the selection changes in multi-select mode."),
+                    ("searchable", "bool", "Shows a search field inside the dropdown that filters options by label."),
+                    ("load_options", "Option<Callback<(String, Callback<Vec<T>>)>>", "Loads options asynchronously. Called with the search text and a callback to deliver the results."),
+                    ("placeholder", "Option<AttrValue>", "Text shown in the trigger when nothing is selected."),
+                    ("loading_text", "AttrValue", "Text shown while options are loading. Defaults to \"Loading...\"."),
+                    ("empty_text", "AttrValue", "Text shown when no options match. Defaults to \"No options\"."),
+                    ("id", "Option<String>", "The id of the select container. Also used as prefix for the listbox and option ids."),
                 ]}
                 template_params={Some(vec![
                     ("T", "The type of the options. Must implement Clone, PartialEq, ToString, and 'static."),
@@ -104,7 +114,9 @@ pub fn select_documentation() -> Html {
                     "The selected option is displayed in the trigger button.".to_string(),
                     "The dropdown list is only rendered when the select is open.".to_string(),
                     "The Select component is generic and can work with various data types, as long as they implement the required traits.".to_string(),
-                    "For accessibility, the component supports keyboard navigation and screen reader announcements.".to_string(),
+                    "Arrow keys move the highlighted option, Enter selects it and Escape closes the dropdown. The highlighted option is exposed through aria-activedescendant.".to_string(),
+                    "In multi-select mode Backspace removes the last selected chip when the search field is empty.".to_string(),
+                    "With load_options, responses to outdated searches are ignored, so results always match the current search text.".to_string(),
                 ]}
             />
 
@@ -117,7 +129,16 @@ pub fn select_documentation() -> Html {
                     ("select_trigger_icon".to_string(), "For the dropdown icon in the trigger button".to_string()),
                     ("select_content_container".to_string(), "For the container of the dropdown content".to_string()),
                     ("select_content_list".to_string(), "For the list of options in the dropdown".to_string()),
+                    ("select_trigger_multiple".to_string(), "For the trigger in multi-select mode, which wraps the chips".to_string()),
+                    ("select_chip".to_string(), "For a selected option chip in multi-select mode".to_string()),
+                    ("select_chip_remove".to_string(), "For the remove button inside a chip".to_string()),
+                    ("select_search_input".to_string(), "For the search field inside the dropdown".to_string()),
+                    ("select_group".to_string(), "For a group of options".to_string()),
+                    ("select_label".to_string(), "For the heading of an option group".to_string()),
                     ("select_item".to_string(), "For individual items in the dropdown list".to_string()),
+                    ("select_item_active".to_string(), "For the option highlighted by keyboard or pointer".to_string()),
+                    ("select_item_selected".to_string(), "For selected options".to_string()),
+                    ("select_message".to_string(), "For the loading and empty state messages".to_string()),
                 ]}
             />
 
@@ -0,0 +1,151 @@
+#[allow(unused_imports)]
+use crate::*;
+use yew::prelude::*;
+
+// Placeholder picture for avatars, so previews don't depend on the network
+#[cfg(feature = "Avatar")]
+const AVATAR_SRC: &str = "data:image/svg+xml;utf8,<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 40 40'><rect width='40' height='40' fill='%23a1a1aa'/><circle cx='20' cy='16' r='7' fill='%23f4f4f5'/><ellipse cx='20' cy='36' rx='13' ry='10' fill='%23f4f4f5'/></svg>";
+
+/// The component named like its feature in every variant, for `ComponentEditor`. Returns `None`
+/// for components without a built-in preview or whose feature is disabled.
+pub(crate) fn component_preview(component: &str) -> Option<Html> {
+    let preview = match component {
+        #[cfg(feature = "Alert")]
+        "Alert" => html! {
+            <div class="flex flex-col gap-2">
+                { for [
+                    (AlertType::Info, "Info"),
+                    (AlertType::Success, "Success"),
+                    (AlertType::Warning, "Warning"),
+                    (AlertType::Error, "Error"),
+                ].into_iter().map(|(alert_type, name)| html! {
+                    <Alert {alert_type}>
+                        <AlertTitle>{name}</AlertTitle>
+                        <AlertDescription>{"Something happened that you should know about."}</AlertDescription>
+                    </Alert>
+                }) }
+            </div>
+        },
+        #[cfg(feature = "Avatar")]
+        "Avatar" => html! {
+            <div class="flex flex-row items-end gap-2">
+                { for [AvatarSize::Small, AvatarSize::Medium, AvatarSize::Large].into_iter().map(|size| html! {
+                    <Avatar src={AVATAR_SRC} alt="Avatar" {size} />
+                }) }
+            </div>
+        },
+        #[cfg(feature = "Badge")]
+        "Badge" => html! {
+            <div class="flex flex-row flex-wrap gap-2">
+                { for [
+                    (BadgeType::Default, "Default"),
+                    (BadgeType::Info, "Info"),
+                    (BadgeType::Success, "Success"),
+                    (BadgeType::Warning, "Warning"),
+                    (BadgeType::Error, "Error"),
+                ].into_iter().map(|(badge_type, label)| html! {
+                    <Badge label={label} {badge_type} />
+                }) }
+            </div>
+        },
+        #[cfg(feature = "Button")]
+        "Button" => html! {
+            <div class="flex flex-col gap-2">
+                <div class="flex flex-row flex-wrap gap-2">
+                    { for [
+                        (ButtonVariant::Primary, "Primary"),
+                        (ButtonVariant::Secondary, "Secondary"),
+                        (ButtonVariant::Success, "Success"),
+                        (ButtonVariant::Warning, "Warning"),
+                        (ButtonVariant::Danger, "Danger"),
+                        (ButtonVariant::Ghost, "Ghost"),
+                        (ButtonVariant::Default, "Default"),
+                    ].into_iter().map(|(variant, label)| html! {
+                        <Button {variant}>{label}</Button>
+                    }) }
+                </div>
+                <div class="flex flex-row flex-wrap items-center gap-2">
+                    { for [
+                        (ButtonSize::Small, "Small"),
+                        (ButtonSize::Medium, "Medium"),
+                        (ButtonSize::Large, "Large"),
+                    ].into_iter().map(|(size, label)| html! {
+                        <Button {size}>{label}</Button>
+                    }) }
+                    <Button disabled=true>{"Disabled"}</Button>
+                </div>
+            </div>
+        },
+        #[cfg(feature = "Card")]
+        "Card" => html! {
+            <Card>
+                <CardHeader>
+                    <CardTitle>{"Card title"}</CardTitle>
+                </CardHeader>
+                <CardContent>{"The content of the card."}</CardContent>
+            </Card>
+        },
+        #[cfg(feature = "Checkbox")]
+        "Checkbox" => html! {
+            <div class="flex flex-row gap-4">
+                <Checkbox id="preview-checkbox-checked" checked=true />
+                <Checkbox id="preview-checkbox-unchecked" />
+                <Checkbox id="preview-checkbox-disabled" disabled=true />
+            </div>
+        },
+        "Input" => html! {
+            <div class="flex flex-col gap-2">
+                <Input id="preview-input" placeholder="Placeholder" />
+                <Input id="preview-input-value" value="Value" />
+                <Input id="preview-input-disabled" placeholder="Disabled" disabled=true />
+            </div>
+        },
+        "Label" => html! {
+            <div class="flex flex-col gap-2">
+                <Label for_id="preview-label">{"Label"}</Label>
+                <Label for_id="preview-label-description" description="With a description">{"Label"}</Label>
+            </div>
+        },
+        #[cfg(feature = "Placeholder")]
+        "Placeholder" => html! {
+            <Placeholder />
+        },
+        #[cfg(feature = "Switch")]
+        "Switch" => html! {
+            <div class="flex flex-row gap-4">
+                <SwitchButton id="preview-switch-checked" checked={Some(true)} />
+                <SwitchButton id="preview-switch-unchecked" checked={Some(false)} />
+                <SwitchButton id="preview-switch-disabled" disabled=true />
+            </div>
+        },
+        #[cfg(feature = "Textarea")]
+        "Textarea" => html! {
+            <div class="flex flex-col gap-2">
+                <Textarea id="preview-textarea" placeholder="Placeholder" />
+                <Textarea id="preview-textarea-disabled" placeholder="Disabled" disabled=true />
+            </div>
+        },
+        #[cfg(feature = "Toggle")]
+        "Toggle" => html! {
+            <div class="flex flex-row gap-4">
+                <Toggle id="preview-toggle-checked" checked=true>{"B"}</Toggle>
+                <Toggle id="preview-toggle-unchecked">{"I"}</Toggle>
+                <Toggle id="preview-toggle-disabled" disabled=true>{"U"}</Toggle>
+            </div>
+        },
+        #[cfg(feature = "Typography")]
+        "Typography" => html! {
+            <div class="flex flex-col gap-2">
+                <H1>{"Heading 1"}</H1>
+                <H2>{"Heading 2"}</H2>
+                <H3>{"Heading 3"}</H3>
+                <H4>{"Heading 4"}</H4>
+                <H5>{"Heading 5"}</H5>
+                <H6>{"Heading 6"}</H6>
+                <Paragraph>{"A paragraph of body text."}</Paragraph>
+            </div>
+        },
+        _ => return None,
+    };
+    Some(preview)
+}
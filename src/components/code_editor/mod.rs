@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 use web_sys::js_sys;
 
+use crate::utils::tw_merge;
 use gloo::events::EventListener;
 use gloo_utils::document;
 use wasm_bindgen::{closure::Closure, JsCast, JsValue};
@@ -329,6 +330,21 @@ impl CodeEditor {
         }
     }
 
+    fn tokenized_line(&self, line: &str, language: &str) -> Html {
+        let tokens = self.tokenize_line(line, language);
+        html! {
+            <div class="line">
+                <div class="line-content">
+                    { for tokens.into_iter().map(|(token_type, text)| {
+                        html! {
+                            <span class={format!("token {}", token_type)}>{ text }</span>
+                        }
+                    })}
+                </div>
+            </div>
+        }
+    }
+
     fn tokenize_line(&self, line: &str, language: &str) -> Vec<(String, String)> {
         let mut tokens = Vec::new();
 
@@ -588,7 +604,7 @@ impl Component for CodeEditor {
         let code = props.code.clone();
         let lines = Self::split_into_lines(&code);
 
-        Self {
+        let mut editor = Self {
             code,
             lines,
             highlighted_html: Html::default(),
@@ -605,7 +621,11 @@ impl Component for CodeEditor {
             cursors: Vec::new(),
             keymap: props.keymap.clone(),
             keymap_enabled: props.enable_keymap,
-        }
+        };
+        // The built-in tokenizer needs no browser, so the code is part of a server render too.
+        // Prism runs once mounted, in `rendered`.
+        editor.highlight_code(ctx, false);
+        editor
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -629,7 +649,7 @@ impl Component for CodeEditor {
                 false // Avoid re-rendering until highlighting is done
             }
             CodeEditorMsg::HighlightCode => {
-                self.highlight_code(ctx);
+                self.highlight_code(ctx, true);
                 true
             }
             CodeEditorMsg::Keydown(event) => {
@@ -799,14 +819,14 @@ impl Component for CodeEditor {
             <>
                 <styles::CodeEditorStyles />
                 <div
-                    class={classes!(
-                        props.class.clone(),
+                    class={tw_merge(classes!(
                         "flex","relative","rounded","overflow-hidden",
                         "border","border-gray-300","dark:border-gray-700",
                         "bg-white","dark:bg-gray-900",
                         "text-gray-900","dark:text-gray-100",
-                        theme_class
-                    )}
+                        theme_class,
+                        props.class.clone()
+                    ))}
                     style={format!("{} {}", container_style, max_height_style)}
                 >
                     // The editor layout is now a flex container with synchronized scroll
@@ -930,7 +950,7 @@ impl Component for CodeEditor {
 
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
         if first_render {
-            self.highlight_code(ctx);
+            self.highlight_code(ctx, true);
             self.setup_listeners(ctx);
 
             // Focus the textarea to ensure the cursor is visible
@@ -975,12 +995,12 @@ impl Component for CodeEditor {
         if props.code != self.code {
             self.code = props.code.clone();
             self.lines = Self::split_into_lines(&self.code);
-            self.highlight_code(ctx);
+            self.highlight_code(ctx, true);
         }
 
         // If language or theme changed, we need to rehighlight
         if old_props.language != props.language || old_props.theme != props.theme {
-            self.highlight_code(ctx);
+            self.highlight_code(ctx, true);
         }
 
         // Update keymap when it changes
@@ -1357,11 +1377,21 @@ impl CodeEditor {
         }
     }
 
-    fn highlight_code(&mut self, ctx: &Context<Self>) {
+    // Highlights with Prism.js if the page has it, else with the built-in tokenizer, which is all
+    // there is outside the browser.
+    fn highlight_code(&mut self, ctx: &Context<Self>, in_browser: bool) {
         let props = ctx.props();
         let language: String = props.language.clone();
         let mut html_lines = Vec::new();
 
+        if !in_browser {
+            for line in &self.lines {
+                html_lines.push(self.tokenized_line(line, &language));
+            }
+            self.highlighted_html = html! { <>{ for html_lines.into_iter() }</> };
+            return;
+        }
+
         // Create a temporary element to assist with syntax highlighting
         let temp_element = document().create_element("pre").unwrap();
         let _ = temp_element.set_class_name(&format!("language-{}", language));
@@ -1407,18 +1437,7 @@ impl CodeEditor {
                 });
             } else {
                 // Fallback to simple highlighting
-                let tokens = self.tokenize_line(&line_clone, &language);
-                html_lines.push(html! {
-                    <div class="line">
-                        <div class="line-content">
-                            { for tokens.into_iter().map(|(token_type, text)| {
-                                html! {
-                                    <span class={format!("token {}", token_type)}>{ text }</span>
-                                }
-                            })}
-                        </div>
-                    </div>
-                });
+                html_lines.push(self.tokenized_line(&line_clone, &language));
             }
         }
 
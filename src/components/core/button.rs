@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -76,7 +77,7 @@ pub fn button(props: &ButtonProps) -> Html {
 
     html! {
         <button
-            class={classes!(&brandguide.button_base, button_class, size_class, props.class.clone())}
+            class={tw_merge(classes!(&brandguide.button_base, button_class, size_class, props.class.clone()))}
             onclick={props.onclick.clone()}
             disabled={props.disabled}
             type={props.kind.clone().unwrap_or_else(|| "button".to_string())}
@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -22,7 +23,7 @@ pub fn col(props: &ColProps) -> Html {
     let brandguide = get_brandguide();
 
     html!(
-        <@{props.tag.clone()} class={classes!(&brandguide.col_container, props.class.clone())}>{props.children.clone()}</@>
+        <@{props.tag.clone()} class={tw_merge(classes!(&brandguide.col_container, props.class.clone()))}>{props.children.clone()}</@>
     )
 }
 
@@ -1,3 +1,4 @@
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -13,7 +14,7 @@ pub struct DividerProps {
 #[function_component(Divider)]
 pub fn divider(props: &DividerProps) -> Html {
     html! {
-        <div class={format!("relative mt-10 {}", props.class)}>
+        <div class={tw_merge(classes!("relative", "mt-10", props.class.clone()))}>
             <div class="absolute inset-0 flex items-center" aria-hidden="true">
                 <hr class="w-full border-t border-gray-200 dark:border-zinc-700" style={props.style.clone()} />
             </div>
@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -19,7 +20,7 @@ pub fn h1(props: &TypographyProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <h1 class={classes!(&brandguide.typography_h1, props.class.clone())}>
+        <h1 class={tw_merge(classes!(&brandguide.typography_h1, props.class.clone()))}>
             { for props.children.iter() }
         </h1>
     }
@@ -32,7 +33,7 @@ pub fn h2(props: &TypographyProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <h2 class={classes!(&brandguide.typography_h2, props.class.clone())}>
+        <h2 class={tw_merge(classes!(&brandguide.typography_h2, props.class.clone()))}>
             { for props.children.iter() }
         </h2>
     }
@@ -45,7 +46,7 @@ pub fn h3(props: &TypographyProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <h3 class={classes!(&brandguide.typography_h3, props.class.clone())}>
+        <h3 class={tw_merge(classes!(&brandguide.typography_h3, props.class.clone()))}>
             { for props.children.iter() }
         </h3>
     }
@@ -58,7 +59,7 @@ pub fn h4(props: &TypographyProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <h4 class={classes!(&brandguide.typography_h4, props.class.clone())}>
+        <h4 class={tw_merge(classes!(&brandguide.typography_h4, props.class.clone()))}>
             { for props.children.iter() }
         </h4>
     }
@@ -71,7 +72,7 @@ pub fn h5(props: &TypographyProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <h5 class={classes!(&brandguide.typography_h5, props.class.clone())}>
+        <h5 class={tw_merge(classes!(&brandguide.typography_h5, props.class.clone()))}>
             { for props.children.iter() }
         </h5>
     }
@@ -84,7 +85,7 @@ pub fn h6(props: &TypographyProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <h6 class={classes!(&brandguide.typography_h6, props.class.clone())}>
+        <h6 class={tw_merge(classes!(&brandguide.typography_h6, props.class.clone()))}>
             { for props.children.iter() }
         </h6>
     }
@@ -97,7 +98,7 @@ pub fn paragraph(props: &TypographyProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <p class={classes!(&brandguide.typography_p, props.class.clone())}>
+        <p class={tw_merge(classes!(&brandguide.typography_p, props.class.clone()))}>
             { for props.children.iter() }
         </p>
     }
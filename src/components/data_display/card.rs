@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -22,7 +23,7 @@ pub fn card(props: &CardProps) -> Html {
     let brandguide = get_brandguide();
     html! {
         <div
-            class={classes!(&brandguide.card_container, props.class.clone())}
+            class={tw_merge(classes!(&brandguide.card_container, props.class.clone()))}
             onclick={props.onclick.clone()}
         >
             { for props.children.iter() }
@@ -45,7 +46,7 @@ pub fn card_header(props: &CardHeaderProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <div class={classes!(&brandguide.card_header, props.class.clone())}>
+        <div class={tw_merge(classes!(&brandguide.card_header, props.class.clone()))}>
             { for props.children.iter() }
         </div>
     }
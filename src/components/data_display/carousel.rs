@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -45,7 +46,7 @@ pub fn carousel(props: &CarouselProps) -> Html {
     }
 
     html! {
-        <div class={classes!(brandguide.carousel_container.clone(), props.class.clone())}>
+        <div class={tw_merge(classes!(brandguide.carousel_container.clone(), props.class.clone()))}>
             <div class={&brandguide.carousel_inner}>
                 { for props.children.iter().enumerate().map(|(index, child)| {
                     let class = if index == *current_index {
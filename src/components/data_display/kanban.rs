@@ -1,7 +1,110 @@
+use crate::components::utils::use_unique_id;
+#[cfg(not(feature = "ThemeProvider"))]
+use crate::config::get_brandguide;
+#[cfg(feature = "ThemeProvider")]
+use crate::config::use_brandguide;
+use crate::utils::tw_merge;
+use gloo::events::{EventListener, EventListenerOptions};
+use gloo::timers::callback::Timeout;
+use gloo_utils::document;
+use std::cell::RefCell;
 use std::collections::HashMap;
-use web_sys::DragEvent;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent, PointerEvent};
 use yew::prelude::*;
 
+// Distance the pointer has to travel before a press turns into a drag.
+const DRAG_THRESHOLD: f64 = 4.0;
+// Touch presses have to be held this long before dragging, so that swipes still scroll.
+const TOUCH_HOLD_MS: u32 = 250;
+
+/// A card moved to a new position, emitted once when it is dropped.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KanbanMove {
+    pub card_id: String,
+    pub from_column: String,
+    pub to_column: String,
+    /// Position in `to_column` after the card has been taken out of `from_column`.
+    pub index: usize,
+}
+
+impl KanbanMove {
+    /// Applies the move to cards kept per column, identified by `id_of`.
+    pub fn apply<C>(&self, columns: &mut HashMap<String, Vec<C>>, id_of: impl Fn(&C) -> &str) {
+        let Some(from) = columns.get_mut(&self.from_column) else {
+            return;
+        };
+        let Some(position) = from.iter().position(|card| id_of(card) == self.card_id) else {
+            return;
+        };
+        let card = from.remove(position);
+        let to = columns.entry(self.to_column.clone()).or_default();
+        let index = self.index.min(to.len());
+        to.insert(index, card);
+    }
+}
+
+// A card being dragged and where it would land.
+#[derive(Clone, PartialEq)]
+struct KanbanDrag {
+    card_id: String,
+    from_column: String,
+    from_index: usize,
+    to_column: String,
+    index: usize,
+    keyboard: bool,
+    height: f64,
+}
+
+impl KanbanDrag {
+    fn to_move(&self) -> KanbanMove {
+        KanbanMove {
+            card_id: self.card_id.clone(),
+            from_column: self.from_column.clone(),
+            to_column: self.to_column.clone(),
+            index: self.index,
+        }
+    }
+}
+
+// A pointer press on a card that has not turned into a drag yet.
+struct PendingPress {
+    card: HtmlElement,
+    pointer_id: i32,
+    start: (f64, f64),
+    ready: bool,
+    _hold: Option<Timeout>,
+}
+
+// A pointer drag in progress, with the floating copy of the card.
+struct PointerDrag {
+    pointer_id: i32,
+    preview: HtmlElement,
+    offset: (f64, f64),
+}
+
+type DropHandler = Callback<(String, String, Option<String>)>;
+
+#[derive(Clone)]
+struct KanbanContext {
+    drag: Option<KanbanDrag>,
+    on_pointer_down: Callback<PointerEvent>,
+    on_card_keydown: Callback<(KeyboardEvent, String)>,
+    drop_handlers: Rc<RefCell<HashMap<String, DropHandler>>>,
+    instructions_id: String,
+}
+
+impl PartialEq for KanbanContext {
+    fn eq(&self, other: &Self) -> bool {
+        self.drag == other.drag
+            && self.on_pointer_down == other.on_pointer_down
+            && self.on_card_keydown == other.on_card_keydown
+            && Rc::ptr_eq(&self.drop_handlers, &other.drop_handlers)
+            && self.instructions_id == other.instructions_id
+    }
+}
+
 // Main Kanban component
 #[derive(Properties, PartialEq)]
 pub struct KanbanProps {
@@ -11,20 +114,519 @@ pub struct KanbanProps {
     pub class: Classes,
     #[prop_or(false)]
     pub allow_multiple_column_drops: bool,
+    /// Called when a card is dropped somewhere other than where it started.
+    #[prop_or_default]
+    pub on_move: Callback<KanbanMove>,
 }
 
 #[function_component(Kanban)]
 pub fn kanban(props: &KanbanProps) -> Html {
-    // Use hardcoded styles since this component may not be in the brandguide yet
-    let kanban_container = "flex overflow-x-auto gap-6 p-6";
+    #[cfg(feature = "ThemeProvider")]
+    let brandguide = use_brandguide();
+    #[cfg(not(feature = "ThemeProvider"))]
+    let brandguide = get_brandguide();
+
+    let container_ref = use_node_ref();
+    let drag = use_state(|| None::<KanbanDrag>);
+    // Mirrors `drag` for the document listeners, which outlive any single render.
+    let drag_ref = use_mut_ref(|| None::<KanbanDrag>);
+    let pending = use_mut_ref(|| None::<PendingPress>);
+    let pointer = use_mut_ref(|| None::<PointerDrag>);
+    let refocus = use_mut_ref(|| None::<(String, String)>);
+    let drop_handlers = use_mut_ref(HashMap::<String, DropHandler>::new);
+    let announcement = use_state(String::new);
+    let base_id = use_unique_id("kanban");
+    let instructions_id = format!("{}-instructions", base_id);
+
+    let set_drag = {
+        let drag = drag.clone();
+        let drag_ref = drag_ref.clone();
+        Callback::from(move |value: Option<KanbanDrag>| {
+            *drag_ref.borrow_mut() = value.clone();
+            drag.set(value);
+        })
+    };
+
+    let finish = {
+        let set_drag = set_drag.clone();
+        let drag_ref = drag_ref.clone();
+        let drop_handlers = drop_handlers.clone();
+        let container_ref = container_ref.clone();
+        let on_move = props.on_move.clone();
+        Callback::from(move |commit: bool| {
+            let Some(current) = drag_ref.borrow().clone() else {
+                return;
+            };
+            set_drag.emit(None);
+            if !commit
+                || (current.to_column == current.from_column && current.index == current.from_index)
+            {
+                return;
+            }
+            if let Some(ondrop) = drop_handlers.borrow().get(&current.to_column) {
+                let target_card_id =
+                    column_element(&container_ref, &current.to_column).and_then(|column| {
+                        card_elements(&column, &current.card_id)
+                            .get(current.index)
+                            .and_then(|card| card.get_attribute("data-card-id"))
+                    });
+                ondrop.emit((
+                    current.card_id.clone(),
+                    current.to_column.clone(),
+                    target_card_id,
+                ));
+            }
+            on_move.emit(current.to_move());
+        })
+    };
+
+    let on_pointer_down = {
+        let pending = pending.clone();
+        let drag_ref = drag_ref.clone();
+        Callback::from(move |event: PointerEvent| {
+            if event.button() != 0 || drag_ref.borrow().is_some() {
+                return;
+            }
+            let Some(card) = event
+                .current_target()
+                .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+            else {
+                return;
+            };
+            // Leave presses on controls inside the card alone.
+            let on_control = event
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|target| target.closest("button, a, input, textarea, select").ok())
+                .flatten()
+                .is_some_and(|control| card.contains(Some(&control)));
+            if on_control {
+                return;
+            }
+            let is_touch = event.pointer_type() == "touch";
+            let hold = is_touch.then(|| {
+                let pending = pending.clone();
+                Timeout::new(TOUCH_HOLD_MS, move || {
+                    if let Some(press) = pending.borrow_mut().as_mut() {
+                        press.ready = true;
+                    }
+                })
+            });
+            *pending.borrow_mut() = Some(PendingPress {
+                card,
+                pointer_id: event.pointer_id(),
+                start: (event.client_x() as f64, event.client_y() as f64),
+                ready: !is_touch,
+                _hold: hold,
+            });
+        })
+    };
+
+    // Pointer dragging is tracked on the document so it keeps working outside the board.
+    {
+        let pending = pending.clone();
+        let pointer = pointer.clone();
+        let drag_ref = drag_ref.clone();
+        let set_drag = set_drag.clone();
+        let finish = finish.clone();
+        let preview_class = brandguide.kanban_drag_preview.to_string();
+        use_effect_with((), move |_| {
+            let onmove = {
+                let pending = pending.clone();
+                let pointer = pointer.clone();
+                let drag_ref = drag_ref.clone();
+                let set_drag = set_drag.clone();
+                EventListener::new(&document(), "pointermove", move |event| {
+                    let event: &PointerEvent = event.unchecked_ref();
+                    let (x, y) = (event.client_x() as f64, event.client_y() as f64);
+                    let press = pending.borrow_mut().take();
+                    if let Some(press) = press {
+                        if press.pointer_id != event.pointer_id() {
+                            *pending.borrow_mut() = Some(press);
+                            return;
+                        }
+                        let moved = (x - press.start.0).hypot(y - press.start.1);
+                        if moved < DRAG_THRESHOLD {
+                            *pending.borrow_mut() = Some(press);
+                            return;
+                        }
+                        if !press.ready {
+                            // A touch that moved before the hold delay is a scroll.
+                            return;
+                        }
+                        if let Some((state, drag)) =
+                            start_pointer_drag(&press, event, &preview_class)
+                        {
+                            *pointer.borrow_mut() = Some(drag);
+                            set_drag.emit(Some(state));
+                        }
+                    }
+                    let pointer = pointer.borrow();
+                    let Some(drag) = pointer.as_ref() else {
+                        return;
+                    };
+                    if drag.pointer_id != event.pointer_id() {
+                        return;
+                    }
+                    let _ = drag.preview.style().set_property(
+                        "transform",
+                        &format!(
+                            "translate({}px, {}px)",
+                            x - drag.offset.0,
+                            y - drag.offset.1
+                        ),
+                    );
+                    let current = drag_ref.borrow().clone();
+                    if let Some(mut current) = current {
+                        if let Some((column, index)) = drop_target(x, y, &current.card_id) {
+                            if column != current.to_column || index != current.index {
+                                current.to_column = column;
+                                current.index = index;
+                                set_drag.emit(Some(current));
+                            }
+                        }
+                    }
+                })
+            };
+            let end = |event_type: &'static str, commit: bool| {
+                let pending = pending.clone();
+                let pointer = pointer.clone();
+                let finish = finish.clone();
+                EventListener::new(&document(), event_type, move |event| {
+                    let event: &PointerEvent = event.unchecked_ref();
+                    pending.borrow_mut().take();
+                    let is_ours = pointer
+                        .borrow()
+                        .as_ref()
+                        .is_some_and(|drag| drag.pointer_id == event.pointer_id());
+                    if is_ours {
+                        if let Some(drag) = pointer.borrow_mut().take() {
+                            drag.preview.remove();
+                        }
+                        finish.emit(commit);
+                    }
+                })
+            };
+            let onup = end("pointerup", true);
+            let oncancel = end("pointercancel", false);
+            // Stop touch dragging from scrolling the page.
+            let ontouchmove = {
+                let pointer = pointer.clone();
+                EventListener::new_with_options(
+                    &document(),
+                    "touchmove",
+                    EventListenerOptions::enable_prevent_default(),
+                    move |event| {
+                        if pointer.borrow().is_some() {
+                            event.prevent_default();
+                        }
+                    },
+                )
+            };
+            move || {
+                if let Some(drag) = pointer.borrow_mut().take() {
+                    drag.preview.remove();
+                }
+                drop((onmove, onup, oncancel, ontouchmove));
+            }
+        });
+    }
+
+    let on_card_keydown = {
+        let drag_ref = drag_ref.clone();
+        let set_drag = set_drag.clone();
+        let finish = finish.clone();
+        let announcement = announcement.clone();
+        let refocus = refocus.clone();
+        let container_ref = container_ref.clone();
+        Callback::from(move |(event, card_id): (KeyboardEvent, String)| {
+            let current = drag_ref.borrow().clone();
+            let columns = column_elements(&container_ref);
+            let column_id =
+                |column: &Element| column.get_attribute("data-column-id").unwrap_or_default();
+            let describe = |drag: &KanbanDrag, verb: &str| {
+                let Some(column) = columns
+                    .iter()
+                    .find(|column| column_id(column) == drag.to_column)
+                else {
+                    return String::new();
+                };
+                format!(
+                    "{} {} position {} of {} in {}.",
+                    card_label(&container_ref, &drag.card_id),
+                    verb,
+                    drag.index + 1,
+                    card_elements(column, &drag.card_id).len() + 1,
+                    column_label(column),
+                )
+            };
+            match (event.key().as_str(), current) {
+                (" ", None) => {
+                    event.prevent_default();
+                    let Some((column, index, height)) = locate_card(&container_ref, &card_id)
+                    else {
+                        return;
+                    };
+                    let column = column_id(&column);
+                    let drag = KanbanDrag {
+                        card_id,
+                        from_column: column.clone(),
+                        from_index: index,
+                        to_column: column,
+                        index,
+                        keyboard: true,
+                        height,
+                    };
+                    announcement.set(format!(
+                        "{} Use the arrow keys to move, Space to drop and Escape to cancel.",
+                        describe(&drag, "picked up at"),
+                    ));
+                    set_drag.emit(Some(drag));
+                }
+                (key, Some(mut drag)) if drag.keyboard && drag.card_id == card_id => {
+                    let position = columns
+                        .iter()
+                        .position(|column| column_id(column) == drag.to_column);
+                    let count = |column: &Element| card_elements(column, &drag.card_id).len();
+                    match key {
+                        "ArrowUp" => drag.index = drag.index.saturating_sub(1),
+                        "ArrowDown" => {
+                            if let Some(column) = position.and_then(|p| columns.get(p)) {
+                                drag.index = (drag.index + 1).min(count(column));
+                            }
+                        }
+                        "ArrowLeft" | "ArrowRight" => {
+                            let next = match (key, position) {
+                                ("ArrowLeft", Some(p)) => p.checked_sub(1),
+                                (_, Some(p)) => Some(p + 1),
+                                _ => None,
+                            };
+                            if let Some(column) = next.and_then(|p| columns.get(p)) {
+                                drag.to_column = column_id(column);
+                                drag.index = drag.index.min(count(column));
+                            }
+                        }
+                        " " | "Enter" => {
+                            event.prevent_default();
+                            announcement.set(describe(&drag, "dropped at"));
+                            *refocus.borrow_mut() =
+                                Some((drag.card_id.clone(), drag.to_column.clone()));
+                            finish.emit(true);
+                            return;
+                        }
+                        "Escape" | "Tab" => {
+                            if key == "Escape" {
+                                event.prevent_default();
+                            }
+                            announcement.set(format!(
+                                "Movement cancelled. {} returned to {}.",
+                                card_label(&container_ref, &drag.card_id),
+                                columns
+                                    .iter()
+                                    .find(|column| column_id(column) == drag.from_column)
+                                    .map(column_label)
+                                    .unwrap_or_default(),
+                            ));
+                            finish.emit(false);
+                            return;
+                        }
+                        _ => return,
+                    }
+                    event.prevent_default();
+                    announcement.set(describe(&drag, "moved to"));
+                    set_drag.emit(Some(drag));
+                }
+                _ => {}
+            }
+        })
+    };
+
+    // Keep keyboard focus on a dropped card once it shows up in its new column.
+    {
+        let refocus = refocus.clone();
+        let container_ref = container_ref.clone();
+        use_effect(move || {
+            let target = refocus.borrow().clone();
+            if let Some((card_id, column)) = target {
+                if let Some((found_in, _, _)) =
+                    locate_card(&container_ref, &card_id).filter(|(found_in, _, _)| {
+                        found_in.get_attribute("data-column-id").as_deref() == Some(&column)
+                    })
+                {
+                    if let Some(card) = card_elements(&found_in, "")
+                        .into_iter()
+                        .find(|card| {
+                            card.get_attribute("data-card-id").as_deref() == Some(&card_id)
+                        })
+                        .and_then(|card| card.dyn_into::<HtmlElement>().ok())
+                    {
+                        let _ = card.focus();
+                    }
+                    refocus.borrow_mut().take();
+                }
+            }
+        });
+    }
+
+    let context = KanbanContext {
+        drag: (*drag).clone(),
+        on_pointer_down,
+        on_card_keydown,
+        drop_handlers,
+        instructions_id: instructions_id.clone(),
+    };
 
     html! {
-        <div class={classes!(kanban_container, props.class.clone())}>
-            { for props.children.iter() }
-        </div>
+        <ContextProvider<KanbanContext> {context}>
+            <div ref={container_ref} class={tw_merge(classes!(&brandguide.kanban_container, props.class.clone()))}>
+                { for props.children.iter() }
+            </div>
+            <div id={instructions_id.clone()} class="sr-only">
+                { "Press Space to pick up a card. Use the arrow keys to move it, Space to drop it and Escape to cancel." }
+            </div>
+            <div class="sr-only" aria-live="assertive" aria-atomic="true">
+                { (*announcement).clone() }
+            </div>
+        </ContextProvider<KanbanContext>>
     }
 }
 
+// Turns a press into a drag, floating a copy of the card under the pointer.
+fn start_pointer_drag(
+    press: &PendingPress,
+    event: &PointerEvent,
+    preview_class: &str,
+) -> Option<(KanbanDrag, PointerDrag)> {
+    let card = &press.card;
+    let card_id = card.get_attribute("data-card-id")?;
+    let column = card.closest("[data-kanban-column]").ok()??;
+    let column_id = column.get_attribute("data-column-id")?;
+    let index = card_elements(&column, "")
+        .iter()
+        .position(|element| element.get_attribute("data-card-id").as_deref() == Some(&card_id))?;
+    let rect = card.get_bounding_client_rect();
+    let preview: HtmlElement = card.clone_node_with_deep(true).ok()?.dyn_into().ok()?;
+    for attribute in ["id", "data-card-id", "tabindex"] {
+        let _ = preview.remove_attribute(attribute);
+    }
+    let _ = preview.set_attribute("aria-hidden", "true");
+    preview.set_class_name(&format!("{} {}", preview.class_name(), preview_class));
+    let style = preview.style();
+    for (property, value) in [
+        ("position", "fixed".to_string()),
+        ("left", "0".to_string()),
+        ("top", "0".to_string()),
+        ("width", format!("{}px", rect.width())),
+        ("margin", "0".to_string()),
+        ("pointer-events", "none".to_string()),
+        ("z-index", "9999".to_string()),
+        (
+            "transform",
+            format!("translate({}px, {}px)", rect.left(), rect.top()),
+        ),
+    ] {
+        let _ = style.set_property(property, &value);
+    }
+    document().body()?.append_child(&preview).ok()?;
+    let offset = (
+        event.client_x() as f64 - rect.left(),
+        event.client_y() as f64 - rect.top(),
+    );
+    Some((
+        KanbanDrag {
+            card_id,
+            from_column: column_id.clone(),
+            from_index: index,
+            to_column: column_id,
+            index,
+            keyboard: false,
+            height: rect.height(),
+        },
+        PointerDrag {
+            pointer_id: press.pointer_id,
+            preview,
+            offset,
+        },
+    ))
+}
+
+// Column and position under the pointer, ignoring the dragged card.
+fn drop_target(x: f64, y: f64, card_id: &str) -> Option<(String, usize)> {
+    let column = document()
+        .element_from_point(x as f32, y as f32)?
+        .closest("[data-kanban-column]")
+        .ok()??;
+    let index = card_elements(&column, card_id)
+        .iter()
+        .take_while(|card| {
+            let rect = card.get_bounding_client_rect();
+            rect.top() + rect.height() / 2.0 < y
+        })
+        .count();
+    Some((column.get_attribute("data-column-id")?, index))
+}
+
+fn column_elements(container_ref: &NodeRef) -> Vec<Element> {
+    let Some(container) = container_ref.cast::<Element>() else {
+        return Vec::new();
+    };
+    let Ok(nodes) = container.query_selector_all("[data-kanban-column]") else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.get(i))
+        .filter_map(|node| node.dyn_into::<Element>().ok())
+        .collect()
+}
+
+fn column_element(container_ref: &NodeRef, column_id: &str) -> Option<Element> {
+    column_elements(container_ref)
+        .into_iter()
+        .find(|column| column.get_attribute("data-column-id").as_deref() == Some(column_id))
+}
+
+// Cards of a column in display order, leaving out `except`.
+fn card_elements(column: &Element, except: &str) -> Vec<Element> {
+    let Ok(nodes) = column.query_selector_all("[data-card-id]") else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.get(i))
+        .filter_map(|node| node.dyn_into::<Element>().ok())
+        .filter(|card| card.get_attribute("data-card-id").as_deref() != Some(except))
+        .collect()
+}
+
+// Column, index and height of a card.
+fn locate_card(container_ref: &NodeRef, card_id: &str) -> Option<(Element, usize, f64)> {
+    column_elements(container_ref)
+        .into_iter()
+        .find_map(|column| {
+            let cards = card_elements(&column, "");
+            let index = cards
+                .iter()
+                .position(|card| card.get_attribute("data-card-id").as_deref() == Some(card_id))?;
+            let height = cards[index].get_bounding_client_rect().height();
+            Some((column, index, height))
+        })
+}
+
+fn card_label(container_ref: &NodeRef, card_id: &str) -> String {
+    locate_card(container_ref, card_id)
+        .and_then(|(column, index, _)| card_elements(&column, "").get(index).cloned())
+        .and_then(|card| card.get_attribute("data-card-title"))
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| card_id.to_string())
+}
+
+fn column_label(column: &Element) -> String {
+    column
+        .get_attribute("data-column-title")
+        .filter(|title| !title.is_empty())
+        .or_else(|| column.get_attribute("data-column-id"))
+        .unwrap_or_default()
+}
+
 // Kanban Column component
 #[derive(Properties, PartialEq)]
 pub struct KanbanColumnProps {
@@ -40,142 +642,102 @@ pub struct KanbanColumnProps {
     pub id: AttrValue,
     #[prop_or_default]
     pub title: AttrValue,
-    #[prop_or_default]
-    pub ondragenter: Option<Callback<DragEvent>>,
-    #[prop_or_default]
-    pub ondragleave: Option<Callback<DragEvent>>,
-    #[prop_or_default]
-    pub ondragover: Option<Callback<DragEvent>>,
+    /// Called with `(card_id, column_id, target_card_id)` when a card is dropped here.
+    /// `target_card_id` is the card the dropped one was placed in front of.
     #[prop_or_default]
     pub ondrop: Option<Callback<(String, String, Option<String>)>>,
 }
 
 #[function_component(KanbanColumn)]
 pub fn kanban_column(props: &KanbanColumnProps) -> Html {
-    // Use hardcoded styles since these components may not be in the brandguide yet
-    let kanban_column = "flex flex-col min-w-[300px] border rounded-md bg-white dark:bg-zinc-900 border-zinc-200 dark:border-zinc-700 shadow-sm";
-    let kanban_column_header = "p-4 font-semibold border-b border-zinc-200 dark:border-zinc-700";
-    let kanban_column_content = "p-3 flex-1 flex flex-col gap-3 min-h-[100px] overflow-y-auto";
-    let kanban_column_over = "border-2 border-blue-500 dark:border-blue-400";
+    #[cfg(feature = "ThemeProvider")]
+    let brandguide = use_brandguide();
+    #[cfg(not(feature = "ThemeProvider"))]
+    let brandguide = get_brandguide();
 
-    let is_over = use_state(|| false);
+    let context = use_context::<KanbanContext>();
     let column_id = props.id.clone();
 
-    // Global drag state to properly track highlight state
-    let drag_counter = use_state(|| 0_u32);
-
-    let ondragenter = {
-        let is_over = is_over.clone();
-        let drag_counter = drag_counter.clone();
-        let user_callback = props.ondragenter.clone();
-        Callback::from(move |e: DragEvent| {
-            e.prevent_default();
-            e.stop_propagation();
-
-            // Increment counter for nested elements
-            drag_counter.set(*drag_counter + 1);
-            is_over.set(true);
-
-            if let Some(callback) = &user_callback {
-                callback.emit(e);
-            }
-        })
-    };
-
-    let ondragleave = {
-        let is_over = is_over.clone();
-        let drag_counter = drag_counter.clone();
-        let user_callback = props.ondragleave.clone();
-        Callback::from(move |e: DragEvent| {
-            e.prevent_default();
-            e.stop_propagation();
-
-            // Decrement counter and only remove highlight when we've left all nested elements
-            let new_count = (*drag_counter).saturating_sub(1);
-            drag_counter.set(new_count);
-
-            if new_count == 0 {
-                is_over.set(false);
-            }
-
-            if let Some(callback) = &user_callback {
-                callback.emit(e);
-            }
-        })
-    };
-
-    let ondragover = {
-        let user_callback = props.ondragover.clone();
-        Callback::from(move |e: DragEvent| {
-            e.prevent_default();
-            e.stop_propagation();
-            if let Some(callback) = &user_callback {
-                callback.emit(e);
-            }
-        })
-    };
-
-    let ondrop = {
-        let is_over = is_over.clone();
-        let drag_counter = drag_counter.clone();
-        let column_id = column_id.clone();
-        let user_callback = props.ondrop.clone();
-
-        Callback::from(move |e: DragEvent| {
-            e.prevent_default();
-            e.stop_propagation();
-            is_over.set(false);
-            drag_counter.set(0);
-
-            if let Some(data_transfer) = e.data_transfer() {
-                if let Ok(card_id) = data_transfer.get_data("text/plain") {
-                    // Get position data (target card id) if available
-                    let target_card_id = if let Ok(target) = data_transfer.get_data("target-card") {
-                        if !target.is_empty() && target != card_id {
-                            Some(target)
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    };
-
-                    if let Some(callback) = &user_callback {
-                        callback.emit((card_id, column_id.to_string(), target_card_id));
+    {
+        let drop_handlers = context
+            .as_ref()
+            .map(|context| context.drop_handlers.clone());
+        use_effect_with(
+            (column_id.clone(), props.ondrop.clone()),
+            move |(column_id, ondrop)| {
+                let column_id = column_id.to_string();
+                if let (Some(drop_handlers), Some(ondrop)) = (&drop_handlers, ondrop) {
+                    drop_handlers
+                        .borrow_mut()
+                        .insert(column_id.clone(), ondrop.clone());
+                }
+                move || {
+                    if let Some(drop_handlers) = drop_handlers {
+                        drop_handlers.borrow_mut().remove(&column_id);
                     }
                 }
-            }
-        })
-    };
+            },
+        );
+    }
 
-    let over_class = if *is_over { kanban_column_over } else { "" };
+    let drag = context.as_ref().and_then(|context| context.drag.clone());
+    let target = drag
+        .as_ref()
+        .filter(|drag| drag.to_column == column_id.as_str());
+    let over_class = target.is_some().then_some(&brandguide.kanban_column_over);
+    let placeholder = |height: f64| {
+        html! {
+            <div
+                key="kanban-placeholder"
+                class={&brandguide.kanban_card_placeholder}
+                style={format!("height: {}px", height)}
+                aria-hidden="true"
+            />
+        }
+    };
 
     // Sort children by order if provided
     let mut sorted_children: Vec<_> = props.children.iter().collect();
     sorted_children.sort_by_key(|child| child.props.order);
 
+    // Lay out the cards, leaving a gap where the dragged card would land.
+    let mut cards = Vec::new();
+    let mut position = 0;
+    for child in sorted_children {
+        let is_dragged = drag
+            .as_ref()
+            .is_some_and(|drag| drag.card_id == child.props.id.as_str());
+        if !is_dragged {
+            if let Some(target) = target.filter(|target| target.index == position) {
+                cards.push(placeholder(target.height));
+            }
+            position += 1;
+        }
+        let mut props = (*child.props).clone();
+        props.column_id = Some(column_id.clone());
+        let key = props.id.to_string();
+        cards.push(html! {
+            <KanbanCard {key} ..props>
+                { for child.props.children.iter() }
+            </KanbanCard>
+        });
+    }
+    if let Some(target) = target.filter(|target| target.index >= position) {
+        cards.push(placeholder(target.height));
+    }
+
     html! {
         <div
-            class={classes!(kanban_column, over_class, props.class.clone())}
-            ondragenter={ondragenter}
-            ondragleave={ondragleave}
-            ondragover={ondragover}
-            ondrop={ondrop}
+            class={tw_merge(classes!(&brandguide.kanban_column, over_class, props.class.clone()))}
+            data-kanban-column="true"
             data-column-id={props.id.clone()}
+            data-column-title={props.title.clone()}
         >
-            <div class={classes!(kanban_column_header, props.header_class.clone())}>
+            <div class={classes!(&brandguide.kanban_column_header, props.header_class.clone())}>
                 <div class="font-medium text-lg">{props.title.clone()}</div>
             </div>
-            <div class={classes!(kanban_column_content, props.body_class.clone())}>
-                { for sorted_children.into_iter().map(|child| {
-                    let mut props = (*child.props).clone();
-                    props.column_id = Some(column_id.clone());
-                    html! {
-                        <KanbanCard ..props>
-                            { for child.props.children.iter() }
-                        </KanbanCard>
-                    }
-                })}
+            <div class={classes!(&brandguide.kanban_column_body, props.body_class.clone())}>
+                { for cards }
             </div>
         </div>
     }
@@ -195,16 +757,6 @@ pub struct KanbanCardProps {
     #[prop_or_default]
     pub title: Option<AttrValue>,
     #[prop_or_default]
-    pub ondragstart: Option<Callback<DragEvent>>,
-    #[prop_or_default]
-    pub ondragend: Option<Callback<DragEvent>>,
-    #[prop_or_default]
-    pub ondragover: Option<Callback<DragEvent>>,
-    #[prop_or_default]
-    pub ondragenter: Option<Callback<DragEvent>>,
-    #[prop_or_default]
-    pub ondragleave: Option<Callback<DragEvent>>,
-    #[prop_or_default]
     pub onclick: Option<Callback<MouseEvent>>,
     #[prop_or_default]
     pub order: Option<usize>,
@@ -212,174 +764,61 @@ pub struct KanbanCardProps {
 
 #[function_component(KanbanCard)]
 pub fn kanban_card(props: &KanbanCardProps) -> Html {
-    // Use hardcoded styles since these components may not be in the brandguide yet
-    let kanban_card = "border border-zinc-200 dark:border-zinc-700 rounded-md bg-white dark:bg-zinc-800 p-4 cursor-grab active:cursor-grabbing shadow-sm";
-    let kanban_card_title = "font-medium pb-2";
-    let kanban_card_content = "text-sm text-zinc-600 dark:text-zinc-400";
-    let kanban_card_dragging = "opacity-50 shadow-md";
-    let kanban_card_drag_target = "border-t-2 border-blue-500 dark:border-blue-400";
-    let kanban_drag_handle = "cursor-move text-zinc-400 hover:text-zinc-600 dark:text-zinc-500 dark:hover:text-zinc-300 transition-colors";
-
-    let is_dragging = use_state(|| false);
-    let is_drag_over = use_state(|| false);
-    let drag_counter = use_state(|| 0_u32);
-    let card_id = props.id.clone();
-
-    let ondragstart = {
-        let is_dragging = is_dragging.clone();
-        let card_id = card_id.clone();
-        let column_id = props.column_id.clone();
-        let order = props.order.clone();
-        let user_callback = props.ondragstart.clone();
-
-        Callback::from(move |e: DragEvent| {
-            if let Some(data_transfer) = e.data_transfer() {
-                // Set the card ID as the drag data
-                let _ = data_transfer.set_data("text/plain", &card_id);
-
-                // Store original column ID if needed for reordering logic
-                if let Some(col_id) = &column_id {
-                    let _ = data_transfer.set_data("source-column", col_id);
-                }
-
-                // Store the order if available for proper positioning
-                if let Some(order_val) = order {
-                    let _ = data_transfer.set_data("order", &order_val.to_string());
-                }
-
-                // Clear any target card from previous operations
-                let _ = data_transfer.set_data("target-card", "");
-            }
-
-            is_dragging.set(true);
-
-            if let Some(callback) = &user_callback {
-                callback.emit(e);
-            }
-        })
-    };
-
-    let ondragend = {
-        let is_dragging = is_dragging.clone();
-        let user_callback = props.ondragend.clone();
-
-        Callback::from(move |e: DragEvent| {
-            is_dragging.set(false);
-            if let Some(callback) = &user_callback {
-                callback.emit(e);
-            }
-        })
-    };
-
-    let ondragenter = {
-        let is_drag_over = is_drag_over.clone();
-        let drag_counter = drag_counter.clone();
-        let user_callback = props.ondragenter.clone();
-        let card_id = card_id.clone();
-
-        Callback::from(move |e: DragEvent| {
-            e.prevent_default();
-            e.stop_propagation();
-
-            // Check if not dragging self
-            if let Some(data_transfer) = e.data_transfer() {
-                if let Ok(dragged_id) = data_transfer.get_data("text/plain") {
-                    if dragged_id == card_id {
-                        return;
-                    }
-                }
-            }
-
-            drag_counter.set(*drag_counter + 1);
-            is_drag_over.set(true);
-
-            if let Some(callback) = &user_callback {
-                callback.emit(e);
+    #[cfg(feature = "ThemeProvider")]
+    let brandguide = use_brandguide();
+    #[cfg(not(feature = "ThemeProvider"))]
+    let brandguide = get_brandguide();
+
+    let context = use_context::<KanbanContext>();
+    let drag = context
+        .as_ref()
+        .and_then(|context| context.drag.as_ref())
+        .filter(|drag| drag.card_id == props.id.as_str());
+    let drag_class = drag.map(|drag| {
+        if drag.keyboard {
+            &brandguide.kanban_card_grabbed
+        } else {
+            &brandguide.kanban_card_dragging
+        }
+    });
+
+    let onpointerdown = context
+        .as_ref()
+        .map(|context| context.on_pointer_down.clone());
+    let onkeydown = context.as_ref().map(|context| {
+        let on_card_keydown = context.on_card_keydown.clone();
+        let card_id = props.id.to_string();
+        Callback::from(move |event: KeyboardEvent| {
+            // Keys pressed in controls inside the card are not for the card.
+            if event.target() == event.current_target() {
+                on_card_keydown.emit((event, card_id.clone()));
             }
         })
-    };
-
-    let ondragleave = {
-        let is_drag_over = is_drag_over.clone();
-        let drag_counter = drag_counter.clone();
-        let user_callback = props.ondragleave.clone();
-
-        Callback::from(move |e: DragEvent| {
-            e.prevent_default();
-            e.stop_propagation();
-
-            let new_count = (*drag_counter).saturating_sub(1);
-            drag_counter.set(new_count);
-
-            if new_count == 0 {
-                is_drag_over.set(false);
-            }
-
-            if let Some(callback) = &user_callback {
-                callback.emit(e);
-            }
-        })
-    };
-
-    let ondragover = {
-        let card_id = card_id.clone();
-        let user_callback = props.ondragover.clone();
-
-        Callback::from(move |e: DragEvent| {
-            e.prevent_default();
-            e.stop_propagation();
-
-            // Don't allow dropping on self
-            if let Some(data_transfer) = e.data_transfer() {
-                if let Ok(dragged_id) = data_transfer.get_data("text/plain") {
-                    if dragged_id != card_id {
-                        // Mark which card we're hovering over for positional dropping
-                        let _ = data_transfer.set_data("target-card", &card_id);
-                    }
-                }
-            }
-
-            if let Some(callback) = &user_callback {
-                callback.emit(e);
-            }
-        })
-    };
-
-    let drag_class = if *is_dragging {
-        kanban_card_dragging
-    } else {
-        ""
-    };
-
-    let drag_over_class = if *is_drag_over {
-        kanban_card_drag_target
-    } else {
-        ""
-    };
+    });
 
     html! {
         <div
-            class={classes!(kanban_card, drag_class, drag_over_class, props.class.clone())}
-            draggable="true"
-            ondragstart={ondragstart}
-            ondragend={ondragend}
-            ondragenter={ondragenter}
-            ondragleave={ondragleave}
-            ondragover={ondragover}
+            class={tw_merge(classes!(&brandguide.kanban_card, drag_class, props.class.clone()))}
+            tabindex={context.is_some().then_some("0")}
+            aria-roledescription={context.is_some().then_some("draggable card")}
+            aria-describedby={context.as_ref().map(|context| context.instructions_id.clone())}
+            {onpointerdown}
+            {onkeydown}
             onclick={props.onclick.clone()}
             data-card-id={props.id.clone()}
+            data-card-title={props.title.clone()}
             data-column-id={props.column_id.clone()}
             data-order={props.order.map(|o| o.to_string())}
         >
-            <span class={kanban_drag_handle}>
+            <span class={&brandguide.kanban_drag_handle}>
                 {"≡"}
             </span>
             if let Some(title) = &props.title {
-                <div class={kanban_card_title}>
+                <div class={&brandguide.kanban_card_title}>
                     {title.clone()}
                 </div>
             }
-            <div class={kanban_card_content}>
+            <div class={&brandguide.kanban_card_content}>
                 { for props.children.iter() }
             </div>
         </div>
@@ -21,7 +21,7 @@ pub use avatar::{Avatar, AvatarSize};
 #[cfg(feature = "Card")]
 pub use card::{Card, CardContent, CardHeader, CardTitle};
 #[cfg(feature = "Kanban")]
-pub use kanban::{Kanban, KanbanColumn, KanbanCard};
+pub use kanban::{Kanban, KanbanCard, KanbanColumn, KanbanMove};
 #[cfg(feature = "Table")]
 pub use table::{Table, TableBody, TableCell, TableFooter, TableHead, TableRow};
 #[cfg(feature = "Carousel")]
@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -20,7 +21,7 @@ pub fn placeholder(props: &PlaceholderProps) -> Html {
     let brandguide = get_brandguide();
 
     html! {
-        <div class={classes!(&brandguide.placeholder_container, props.class.clone())}>
+        <div class={tw_merge(classes!(&brandguide.placeholder_container, props.class.clone()))}>
             <svg class={classes!(&brandguide.placeholder_svg)} fill="none">
             <defs>
                 <pattern id="dash" width="10" height="10" patternTransform="rotate(45 0 0)" patternUnits="userSpaceOnUse">
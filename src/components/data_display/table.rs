@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -19,7 +20,7 @@ pub fn table(props: &TableProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <div class={format!("{} {}", brandguide.table_container, props.class)}>
+        <div class={tw_merge(classes!(&brandguide.table_container, props.class.clone()))}>
             <table class={&brandguide.table}>
                 { for props.children.iter() }
             </table>
@@ -42,7 +43,7 @@ pub fn table_head(props: &TableHeadProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <thead class={format!("{} {}", brandguide.table_head, props.class)}>
+        <thead class={tw_merge(classes!(&brandguide.table_head, props.class.clone()))}>
             { for props.children.iter().map(|child| {
                 html! {
                     <TableRow
@@ -79,7 +80,7 @@ pub fn table_row(props: &TableRowProps) -> Html {
         &brandguide.table_row
     };
     html! {
-        <tr class={format!("{} {}", class, props.class)}>
+        <tr class={tw_merge(classes!(class, props.class.clone()))}>
             { for props.children.iter() }
         </tr>
     }
@@ -107,7 +108,7 @@ pub fn table_cell(props: &TableCellProps) -> Html {
     let brandguide = get_brandguide();
     html! {
         <td
-            class={format!("{} {}", brandguide.table_cell, props.class)}
+            class={tw_merge(classes!(&brandguide.table_cell, props.class.clone()))}
             colspan={props.colspan.map(|c| c.to_string())}
             rowspan={props.rowspan.map(|r| r.to_string())}
             onclick={props.onclick.clone()}
@@ -132,7 +133,7 @@ pub fn table_body(props: &TableBodyProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <tbody class={format!("{} {}", brandguide.table_body, props.class)}>
+        <tbody class={tw_merge(classes!(&brandguide.table_body, props.class.clone()))}>
            { for props.children.iter() }
            /*
             { for props.children.iter().map(|child| {
@@ -165,7 +166,7 @@ pub fn table_footer(props: &TableFooterProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <tfoot class={format!("{} {}", brandguide.table_footer, props.class)}>
+        <tfoot class={tw_merge(classes!(&brandguide.table_footer, props.class.clone()))}>
             { for props.children.iter() }
         </tfoot>
     }
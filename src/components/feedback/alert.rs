@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -46,7 +47,7 @@ pub fn alert_title(props: &AlertTitleProps) -> Html {
     let brandguide = get_brandguide();
 
     html! {
-        <div class={classes!(brandguide.alert_title.clone(), props.class.clone())}>
+        <div class={tw_merge(classes!(brandguide.alert_title.clone(), props.class.clone()))}>
             { for props.children.iter() }
         </div>
     }
@@ -68,7 +69,7 @@ pub fn alert_description(props: &AlertDescriptionProps) -> Html {
     let brandguide = get_brandguide();
 
     html! {
-        <div class={classes!(brandguide.alert_description.clone(), props.class.clone())}>
+        <div class={tw_merge(classes!(brandguide.alert_description.clone(), props.class.clone()))}>
             { for props.children.iter() }
         </div>
     }
@@ -88,7 +89,7 @@ pub fn alert(props: &AlertProps) -> Html {
     };
 
     html! {
-        <div class={classes!(format!("{} {}", brandguide.alert_base, alert_class), props.class.clone())}>
+        <div class={tw_merge(classes!(format!("{} {}", brandguide.alert_base, alert_class), props.class.clone()))}>
             { if let Some(icon) = &props.icon { html! { <span>{icon.clone()}</span> } } else { html! {} } }
             { for props.children.iter() }
         </div>
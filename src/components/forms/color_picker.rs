@@ -23,8 +23,9 @@ pub struct ColorPicker {
     active_pointer: Option<i32>,
     current_color: String,
     indicator_position: (i32, i32),
-    move_closure: Closure<dyn FnMut(PointerEvent)>,
-    up_closure: Closure<dyn FnMut(PointerEvent)>,
+    // Created on the first drag, so rendering on the server doesn't need wasm-bindgen
+    move_closure: Option<Closure<dyn FnMut(PointerEvent)>>,
+    up_closure: Option<Closure<dyn FnMut(PointerEvent)>>,
 }
 
 pub enum Msg {
@@ -41,25 +42,14 @@ impl Component for ColorPicker {
     type Properties = ColorPickerProps;
 
     fn create(ctx: &Context<Self>) -> Self {
-        let link = ctx.link().clone();
         Self {
             canvas_ref: NodeRef::default(),
             context: None,
             active_pointer: None,
             current_color: ctx.props().value.clone(),
             indicator_position: (0, 0),
-            move_closure: {
-                let link = link.clone();
-                Closure::wrap(Box::new(move |event: web_sys::PointerEvent| {
-                    link.send_message(Msg::PointerMove(event));
-                }) as Box<dyn FnMut(_)>)
-            },
-            up_closure: {
-                let link = link.clone();
-                Closure::wrap(Box::new(move |event: web_sys::PointerEvent| {
-                    link.send_message(Msg::PointerUp(event));
-                }) as Box<dyn FnMut(_)>)
-            },
+            move_closure: None,
+            up_closure: None,
         }
     }
 
@@ -71,16 +61,28 @@ impl Component for ColorPicker {
                     self.active_pointer = Some(event.pointer_id());
 
                     let window = web_sys::window().expect("no global `window` exists");
+                    let move_closure = self.move_closure.get_or_insert_with(|| {
+                        let link = ctx.link().clone();
+                        Closure::wrap(Box::new(move |event: web_sys::PointerEvent| {
+                            link.send_message(Msg::PointerMove(event));
+                        }) as Box<dyn FnMut(_)>)
+                    });
                     window
                         .add_event_listener_with_callback(
                             "pointermove",
-                            self.move_closure.as_ref().unchecked_ref(),
+                            move_closure.as_ref().unchecked_ref(),
                         )
                         .unwrap();
+                    let up_closure = self.up_closure.get_or_insert_with(|| {
+                        let link = ctx.link().clone();
+                        Closure::wrap(Box::new(move |event: web_sys::PointerEvent| {
+                            link.send_message(Msg::PointerUp(event));
+                        }) as Box<dyn FnMut(_)>)
+                    });
                     window
                         .add_event_listener_with_callback(
                             "pointerup",
-                            self.up_closure.as_ref().unchecked_ref(),
+                            up_closure.as_ref().unchecked_ref(),
                         )
                         .unwrap();
                 }
@@ -101,18 +103,22 @@ impl Component for ColorPicker {
                     self.active_pointer = None;
 
                     let window = web_sys::window().expect("no global `window` exists");
-                    window
-                        .remove_event_listener_with_callback(
-                            "pointermove",
-                            self.move_closure.as_ref().unchecked_ref(),
-                        )
-                        .unwrap();
-                    window
-                        .remove_event_listener_with_callback(
-                            "pointerup",
-                            self.up_closure.as_ref().unchecked_ref(),
-                        )
-                        .unwrap();
+                    if let Some(move_closure) = &self.move_closure {
+                        window
+                            .remove_event_listener_with_callback(
+                                "pointermove",
+                                move_closure.as_ref().unchecked_ref(),
+                            )
+                            .unwrap();
+                    }
+                    if let Some(up_closure) = &self.up_closure {
+                        window
+                            .remove_event_listener_with_callback(
+                                "pointerup",
+                                up_closure.as_ref().unchecked_ref(),
+                            )
+                            .unwrap();
+                    }
                 }
                 false
             }
@@ -42,6 +42,14 @@ pub enum Msg {
 
 impl Drop for ColorPickerHue {
     fn drop(&mut self) {
+        // Nothing is attached before the first render in the browser, e.g. on the server
+        if self.move_closure.is_none()
+            && self.up_closure.is_none()
+            && self.resize_observer.is_none()
+            && self.resize_callback.is_none()
+        {
+            return;
+        }
         let window = web_sys::window().expect("no global `window` exists");
         if let Some(move_closure) = &self.move_closure {
             window
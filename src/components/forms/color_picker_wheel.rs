@@ -46,6 +46,10 @@ pub enum Msg {
 
 impl Drop for ColorPickerWheel {
     fn drop(&mut self) {
+        // Nothing is attached before the first render in the browser, e.g. on the server
+        if self.resize_observer.is_none() && self.resize_callback.is_none() {
+            return;
+        }
         let window = web_sys::window().expect("no global `window` exists");
 
         if let Some(resize_observer) = self.resize_observer.take() {
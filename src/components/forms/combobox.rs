@@ -1,98 +1,491 @@
+use crate::components::utils::use_unique_id;
 #[cfg(not(feature = "ThemeProvider"))]
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
 use crate::config::ClassesStr;
+use crate::utils::{fold_case, fuzzy_match, highlight};
+use gloo::timers::callback::Timeout;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
+/// A plain value/label pair for comboboxes over string data.
+#[derive(Clone, PartialEq)]
+pub struct ComboboxOption {
+    pub value: String,
+    pub label: String,
+}
+
+impl std::fmt::Display for ComboboxOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+impl From<(String, String)> for ComboboxOption {
+    fn from((value, label): (String, String)) -> Self {
+        ComboboxOption { value, label }
+    }
+}
+
+/// Decides whether an option label matches the typed text and which characters to highlight.
+#[derive(Clone, PartialEq, Default)]
+pub enum ComboboxMatcher {
+    #[default]
+    Substring,
+    Prefix,
+    /// Matches when the typed characters appear in order, not necessarily adjacent, highlighting
+    /// the best match found by `fuzzy_match`.
+    Fuzzy,
+    /// Receives `(query, label)` and returns the matched character indices, or `None`.
+    Custom(Callback<(String, String), Option<Vec<usize>>>),
+}
+
+impl ComboboxMatcher {
+    /// Returns the indices of the matched characters in `label`, or `None` if it does not match.
+    pub fn matches(&self, query: &str, label: &str) -> Option<Vec<usize>> {
+        if query.is_empty() {
+            return Some(Vec::new());
+        }
+        let folded = |text: &str| text.chars().map(fold_case).collect::<Vec<char>>();
+        match self {
+            ComboboxMatcher::Substring => {
+                let (query, label) = (folded(query), folded(label));
+                label
+                    .windows(query.len())
+                    .position(|window| window == query.as_slice())
+                    .map(|start| (start..start + query.len()).collect())
+            }
+            ComboboxMatcher::Prefix => {
+                let (query, label) = (folded(query), folded(label));
+                label
+                    .starts_with(&query)
+                    .then(|| (0..query.len()).collect())
+            }
+            ComboboxMatcher::Fuzzy => fuzzy_match(query, label).map(|(_, indices)| indices),
+            ComboboxMatcher::Custom(matcher) => {
+                matcher.emit((query.to_string(), label.to_string()))
+            }
+        }
+    }
+}
+
 #[derive(Properties, PartialEq)]
-pub struct ComboboxProps {
+pub struct ComboboxProps<T: Clone + PartialEq + ToString + 'static> {
     #[prop_or_default]
     pub id: String,
     #[prop_or_default]
-    pub options: Vec<(String, String)>, // (value, label)
+    pub options: Vec<T>,
+    /// Makes the combobox controlled when set, with `Some(None)` showing no selection. The
+    /// selection only changes when this prop changes. `value={selected}` with an `Option<T>`
+    /// sets it.
+    #[prop_or_default]
+    pub value: Option<Option<T>>,
+    #[prop_or_default]
+    pub default_value: Option<T>,
+    #[prop_or_default]
+    pub on_select: Callback<T>,
+    /// Label accessor. Defaults to `ToString`.
+    #[prop_or_default]
+    pub get_label: Option<Callback<T, String>>,
+    /// Value accessor used for keys and the hidden form field. Defaults to the label.
     #[prop_or_default]
-    pub on_select: Callback<String>,
+    pub get_value: Option<Callback<T, String>>,
+    /// Name of a hidden input carrying the selected value, for use in forms.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    #[prop_or_default]
+    pub matcher: ComboboxMatcher,
+    /// Offers to create a new entry from the typed text when no option label equals it.
+    #[prop_or_default]
+    pub allow_create: bool,
+    #[prop_or_default]
+    pub on_create: Callback<String>,
+    /// Called with the typed text and a callback that receives the suggestions.
+    /// When set, `options` is ignored and the loader is expected to do the filtering.
+    #[prop_or_default]
+    pub load_options: Option<Callback<(String, Callback<Vec<T>>)>>,
+    #[prop_or(250)]
+    pub debounce_ms: u32,
+    #[prop_or(AttrValue::from("Select option..."))]
+    pub placeholder: AttrValue,
+    #[prop_or(AttrValue::from("Loading..."))]
+    pub loading_text: AttrValue,
+    #[prop_or(AttrValue::from("No results"))]
+    pub empty_text: AttrValue,
     #[prop_or_default]
     pub disabled: bool,
 }
 
+enum Entry<T> {
+    Option(T, Vec<usize>),
+    Create(String),
+}
+
 #[function_component(Combobox)]
-pub fn combobox(props: &ComboboxProps) -> Html {
+pub fn combobox<T: Clone + PartialEq + ToString + 'static>(props: &ComboboxProps<T>) -> Html {
     #[cfg(feature = "ThemeProvider")]
     let brandguide = use_brandguide();
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     let open = use_state(|| false);
-    let value = use_state(|| "".to_string());
+    let value = use_state(|| {
+        props
+            .value
+            .clone()
+            .unwrap_or_else(|| props.default_value.clone())
+    });
+    // The text being typed. `None` while the input shows the selected label.
+    let query = use_state(|| None::<String>);
+    let active = use_state(|| None::<usize>);
+    let loaded = use_state(Vec::<T>::new);
+    let loading = use_state(|| false);
+    let request = use_mut_ref(|| 0usize);
+    let debounce = use_mut_ref(|| None::<Timeout>);
+    let base_id = use_unique_id("combobox");
+    let container_ref = use_node_ref();
+    let input_ref = use_node_ref();
 
-    let on_select = {
-        let value = value.clone();
+    use_effect_with(
+        (value.clone(), props.value.clone()),
+        |(value, prop_value)| {
+            if let Some(prop_value) = prop_value {
+                if **value != *prop_value {
+                    value.set(prop_value.clone());
+                }
+            }
+        },
+    );
+
+    // Debounce suggestion requests while typing. Responses to superseded requests are dropped.
+    {
+        let load_options = props.load_options.clone();
+        let debounce_ms = props.debounce_ms;
+        let loaded = loaded.clone();
+        let loading = loading.clone();
+        let text = (*query).clone().unwrap_or_default();
+        use_effect_with((*open, text), move |(open, text)| {
+            debounce.borrow_mut().take();
+            if let (true, Some(load_options)) = (*open, load_options) {
+                let text = text.clone();
+                *debounce.borrow_mut() = Some(Timeout::new(debounce_ms, move || {
+                    let id = {
+                        let mut request = request.borrow_mut();
+                        *request += 1;
+                        *request
+                    };
+                    loading.set(true);
+                    load_options.emit((
+                        text,
+                        Callback::from(move |options: Vec<T>| {
+                            if *request.borrow() == id {
+                                loaded.set(options);
+                                loading.set(false);
+                            }
+                        }),
+                    ));
+                }));
+            }
+            || ()
+        });
+    }
+
+    let label_of = {
+        let get_label = props.get_label.clone();
+        move |option: &T| match &get_label {
+            Some(get_label) => get_label.emit(option.clone()),
+            None => option.to_string(),
+        }
+    };
+    let value_of = {
+        let get_value = props.get_value.clone();
+        let label_of = label_of.clone();
+        move |option: &T| match &get_value {
+            Some(get_value) => get_value.emit(option.clone()),
+            None => label_of(option),
+        }
+    };
+
+    let text = (*query).clone().unwrap_or_default();
+    let mut entries: Vec<Entry<T>> = if props.load_options.is_some() {
+        loaded
+            .iter()
+            .map(|option| {
+                let matched = props
+                    .matcher
+                    .matches(&text, &label_of(option))
+                    .unwrap_or_default();
+                Entry::Option(option.clone(), matched)
+            })
+            .collect()
+    } else {
+        props
+            .options
+            .iter()
+            .filter_map(|option| {
+                props
+                    .matcher
+                    .matches(&text, &label_of(option))
+                    .map(|matched| Entry::Option(option.clone(), matched))
+            })
+            .collect()
+    };
+    let trimmed = text.trim();
+    let folded = |text: &str| text.chars().map(fold_case).collect::<String>();
+    if props.allow_create
+        && !trimmed.is_empty()
+        && !entries.iter().any(|entry| {
+            matches!(entry, Entry::Option(option, _) if folded(&label_of(option)) == folded(trimmed))
+        })
+    {
+        entries.push(Entry::Create(trimmed.to_string()));
+    }
+
+    let close = {
         let open = open.clone();
-        let on_select = props.on_select.clone();
-        Callback::from(move |selected_value: String| {
-            value.set(selected_value.clone());
+        let query = query.clone();
+        let active = active.clone();
+        Callback::from(move |_| {
             open.set(false);
-            on_select.emit(selected_value);
+            query.set(None);
+            active.set(None);
+        })
+    };
+
+    let choose = {
+        let value = value.clone();
+        let on_select = props.on_select.clone();
+        let on_create = props.on_create.clone();
+        let controlled = props.value.is_some();
+        let close = close.clone();
+        Callback::from(move |entry: Entry<T>| {
+            match entry {
+                Entry::Option(option, _) => {
+                    if !controlled {
+                        value.set(Some(option.clone()));
+                    }
+                    on_select.emit(option);
+                }
+                Entry::Create(text) => on_create.emit(text),
+            }
+            close.emit(());
         })
     };
 
-    let selected_label = props
-        .options
-        .iter()
-        .find(|(val, _)| val == value.as_str())
-        .map(|(_, label)| label.clone())
-        .unwrap_or_else(|| "Select option...".to_string());
+    let take_entry = {
+        let entries: Vec<Option<T>> = entries
+            .iter()
+            .map(|entry| match entry {
+                Entry::Option(option, _) => Some(option.clone()),
+                Entry::Create(_) => None,
+            })
+            .collect();
+        let created = match entries.last() {
+            Some(None) => Some(trimmed.to_string()),
+            _ => None,
+        };
+        move |index: usize| match entries.get(index) {
+            Some(Some(option)) => Some(Entry::Option(option.clone(), Vec::new())),
+            Some(None) => created.clone().map(Entry::Create),
+            None => None,
+        }
+    };
+
+    let oninput = {
+        let open = open.clone();
+        let query = query.clone();
+        let active = active.clone();
+        Callback::from(move |event: InputEvent| {
+            let input: HtmlInputElement = event.target_unchecked_into();
+            query.set(Some(input.value()));
+            active.set(Some(0));
+            open.set(true);
+        })
+    };
+
+    let onkeydown = {
+        let open = open.clone();
+        let active = active.clone();
+        let close = close.clone();
+        let choose = choose.clone();
+        let take_entry = take_entry.clone();
+        let count = entries.len();
+        Callback::from(move |event: KeyboardEvent| {
+            let last = count.checked_sub(1);
+            match event.key().as_str() {
+                "ArrowDown" => {
+                    event.prevent_default();
+                    if !*open {
+                        open.set(true);
+                        active.set(last.map(|_| 0));
+                    } else {
+                        active
+                            .set(last.map(|last| active.map_or(0, |index| (index + 1).min(last))));
+                    }
+                }
+                "ArrowUp" => {
+                    event.prevent_default();
+                    active
+                        .set(last.map(|last| active.map_or(last, |index| index.saturating_sub(1))));
+                }
+                "Enter" if *open => {
+                    event.prevent_default();
+                    if let Some(entry) = active.and_then(&take_entry) {
+                        choose.emit(entry);
+                    }
+                }
+                "Escape" if *open => {
+                    event.prevent_default();
+                    close.emit(());
+                }
+                "Tab" => close.emit(()),
+                _ => {}
+            }
+        })
+    };
 
     let toggle_open = {
         let open = open.clone();
-        Callback::from(move |_| open.set(!*open))
+        let close = close.clone();
+        let input_ref = input_ref.clone();
+        let disabled = props.disabled;
+        Callback::from(move |_: MouseEvent| {
+            if disabled {
+                return;
+            }
+            if *open {
+                close.emit(());
+            } else {
+                open.set(true);
+                if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                    let _ = input.focus();
+                }
+            }
+        })
+    };
+
+    let onfocusout = {
+        let container_ref = container_ref.clone();
+        let close = close.clone();
+        let is_open = *open;
+        let is_editing = query.is_some();
+        Callback::from(move |event: FocusEvent| {
+            let inside = event
+                .related_target()
+                .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                .zip(container_ref.cast::<web_sys::Element>())
+                .map(|(target, container)| container.contains(Some(&target)))
+                .unwrap_or(false);
+            if !inside && (is_open || is_editing) {
+                close.emit(());
+            }
+        })
     };
 
+    let listbox_id = format!("{}-listbox", base_id);
+    let option_id = |index: usize| format!("{}-option-{}", base_id, index);
+    let input_value = match &*query {
+        Some(text) => text.clone(),
+        None => value.as_ref().map(&label_of).unwrap_or_default(),
+    };
+    let active_descendant = active
+        .filter(|index| *open && *index < entries.len())
+        .map(option_id);
+
     html! {
-        <div>
-            <button
-                id={props.id.clone()}
+        <div ref={container_ref} class="relative inline-block" {onfocusout}>
+            <div
                 class={classes!(
                     &brandguide.combobox_button,
                     if *open { brandguide.combobox_button_open.clone() } else { ClassesStr::empty() },
                     if props.disabled { brandguide.combobox_button_disabled.clone() } else { ClassesStr::empty() },
                 )}
-                role="combobox"
-                aria-expanded={open.to_string()}
-                onclick={toggle_open}
-                disabled={props.disabled}
             >
-                { selected_label }
-                <svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="lucide lucide-chevrons-up-down ml-2 h-4 w-4 shrink-0 opacity-50">
+                <input
+                    ref={input_ref}
+                    id={props.id.clone()}
+                    type="text"
+                    class={&brandguide.combobox_input}
+                    role="combobox"
+                    autocomplete="off"
+                    aria-autocomplete="list"
+                    aria-expanded={open.to_string()}
+                    aria-controls={listbox_id.clone()}
+                    aria-activedescendant={active_descendant}
+                    placeholder={props.placeholder.clone()}
+                    value={input_value}
+                    disabled={props.disabled}
+                    {oninput}
+                    {onkeydown}
+                />
+                <svg onclick={toggle_open} onmousedown={|e: MouseEvent| e.prevent_default()} xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="lucide lucide-chevrons-up-down ml-2 h-4 w-4 shrink-0 opacity-50 cursor-pointer" aria-hidden="true">
                     <path d="m7 15 5 5 5-5"></path>
                     <path d="m7 9 5-5 5 5"></path>
                 </svg>
-            </button>
-            {
-                if *open {
-                    html! {
-                        <div class={&brandguide.combobox_list}>
-                            { for props.options.iter().map(|(val, label)| {
-                                let on_select = on_select.clone();
-                                let val = val.clone();
-                                html! {
-                                    <div
-                                        class={classes!(
-                                            &brandguide.combobox_item,
-                                            if *value == val { brandguide.combobox_item_selected.clone() } else { ClassesStr::empty() },
-                                        )}
-                                        onclick={Callback::from(move |_| on_select.emit(val.clone()))}
-                                    >
-                                        { label }
-                                    </div>
-                                }
-                            }) }
-                        </div>
+            </div>
+            if let Some(name) = props.name.clone() {
+                <input type="hidden" {name} value={value.as_ref().map(&value_of).unwrap_or_default()} />
+            }
+            if *open {
+                <div class={&brandguide.combobox_list}>
+                    if *loading {
+                        <div class={&brandguide.combobox_message} role="status">{ props.loading_text.clone() }</div>
+                    } else if entries.is_empty() {
+                        <div class={&brandguide.combobox_message} role="status">{ props.empty_text.clone() }</div>
                     }
-                } else {
-                    html! {}
-                }
+                    <ul id={listbox_id} role="listbox">
+                        { for entries.into_iter().enumerate().map(|(index, entry)| {
+                            let is_selected = matches!(&entry, Entry::Option(option, _) if value.as_ref() == Some(option));
+                            let (key, content) = match &entry {
+                                Entry::Option(option, matched) => (
+                                    value_of(option),
+                                    highlight(&label_of(option), matched, classes!(&brandguide.combobox_match)),
+                                ),
+                                Entry::Create(text) => (
+                                    format!("create:{}", text),
+                                    html! { { format!("Create \"{}\"", text) } },
+                                ),
+                            };
+                            let onclick = {
+                                let choose = choose.clone();
+                                let take_entry = take_entry.clone();
+                                Callback::from(move |_: MouseEvent| {
+                                    if let Some(entry) = take_entry(index) {
+                                        choose.emit(entry);
+                                    }
+                                })
+                            };
+                            // Keep focus in the input so the blur handler does not close the list first.
+                            let onmousedown = Callback::from(|event: MouseEvent| event.prevent_default());
+                            let onmousemove = {
+                                let active = active.clone();
+                                Callback::from(move |_: MouseEvent| {
+                                    if *active != Some(index) {
+                                        active.set(Some(index));
+                                    }
+                                })
+                            };
+                            html! {
+                                <li
+                                    {key}
+                                    id={option_id(index)}
+                                    role="option"
+                                    aria-selected={is_selected.to_string()}
+                                    class={classes!(
+                                        if is_selected { &brandguide.combobox_item_selected } else { &brandguide.combobox_item },
+                                        (*active == Some(index)).then_some(&brandguide.combobox_item_active),
+                                    )}
+                                    {onclick}
+                                    {onmousedown}
+                                    {onmousemove}
+                                >
+                                    { content }
+                                </li>
+                            }
+                        }) }
+                    </ul>
+                </div>
             }
         </div>
     }
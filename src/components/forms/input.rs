@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
@@ -75,7 +76,7 @@ pub fn input(props: &InputProps) -> Html {
     html! {
         <input
             type={props.kind.clone()}
-            class={classes!(&brandguide.input_base, props.class.clone())}
+            class={tw_merge(classes!(&brandguide.input_base, props.class.clone()))}
             value={props.value.clone()}
             oninput={ontext}
             onchange={onchange}
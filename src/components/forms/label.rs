@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -25,7 +26,7 @@ pub fn label(props: &LabelProps) -> Html {
     html! {
         <>
             <label
-                class={classes!(&brandguide.label_base, props.class.clone())}
+                class={tw_merge(classes!(&brandguide.label_base, props.class.clone()))}
                 for={props.for_id.clone()}
             >
             {
@@ -38,7 +38,7 @@ pub use input::Input;
 #[cfg(feature = "Textarea")]
 pub use textarea::Textarea;
 #[cfg(feature = "Select")]
-pub use select::{Select, SelectOption};
+pub use select::{Select, SelectGroup, SelectOption};
 #[cfg(feature = "Checkbox")]
 pub use checkbox::Checkbox;
 #[cfg(feature = "Switch")]
@@ -50,7 +50,7 @@ pub use label::Label;
 #[cfg(feature = "TagInput")]
 pub use tag_input::TagInput;
 #[cfg(feature = "Combobox")]
-pub use combobox::Combobox;
+pub use combobox::{Combobox, ComboboxMatcher, ComboboxOption};
 #[cfg(feature = "Selectable")]
 pub use selectable::{Selectable, SelectableArea, SelectableIndicator};
 #[cfg(feature = "ColorPicker")]
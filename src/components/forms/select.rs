@@ -1,8 +1,12 @@
+use crate::components::utils::use_unique_id;
 #[cfg(not(feature = "ThemeProvider"))]
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use gloo_utils::document;
 use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
 #[derive(Clone, PartialEq)]
@@ -17,9 +21,17 @@ impl ToString for SelectOption {
     }
 }
 
+/// A labelled group of options, rendered under a `select_label` heading.
+#[derive(Clone, PartialEq)]
+pub struct SelectGroup<T: Clone + PartialEq + ToString + 'static> {
+    pub label: String,
+    pub options: Vec<T>,
+}
+
 #[derive(Clone, PartialEq)]
 pub struct SelectState<T: Clone + PartialEq + ToString + 'static> {
     pub selected: Option<T>,
+    pub selected_values: Vec<T>,
     pub is_open: bool,
     pub toggle: Callback<()>,
     pub on_select: Callback<T>,
@@ -27,11 +39,34 @@ pub struct SelectState<T: Clone + PartialEq + ToString + 'static> {
 
 #[derive(Properties, PartialEq)]
 pub struct SelectProps<T: Clone + PartialEq + ToString + 'static> {
+    #[prop_or_default]
     pub options: Vec<T>,
     #[prop_or_default]
+    pub groups: Vec<SelectGroup<T>>,
+    #[prop_or_default]
     pub selected: Option<T>,
     #[prop_or_default]
     pub onchange: Callback<T>,
+    /// Allows several values to be chosen. They are shown as chips and emitted through `onchange_multiple`.
+    #[prop_or_default]
+    pub multiple: bool,
+    #[prop_or_default]
+    pub selected_values: Vec<T>,
+    #[prop_or_default]
+    pub onchange_multiple: Callback<Vec<T>>,
+    /// Shows a search field inside the dropdown that filters the options by label.
+    #[prop_or_default]
+    pub searchable: bool,
+    /// Called with the current search text and a callback that receives the matching options.
+    /// When set, `options` and `groups` are ignored and filtering is left to the loader.
+    #[prop_or_default]
+    pub load_options: Option<Callback<(String, Callback<Vec<T>>)>>,
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    #[prop_or(AttrValue::from("Loading..."))]
+    pub loading_text: AttrValue,
+    #[prop_or(AttrValue::from("No options"))]
+    pub empty_text: AttrValue,
     #[prop_or_default]
     pub id: Option<String>,
 }
@@ -44,74 +79,495 @@ pub fn select<T: Clone + PartialEq + ToString + 'static>(props: &SelectProps<T>)
     let brandguide = get_brandguide();
     let is_open = use_state(|| false);
     let selected = use_state(|| props.selected.clone());
+    let selected_values = use_state(|| props.selected_values.clone());
+    let query = use_state(String::new);
+    let active = use_state(|| None::<usize>);
+    let loaded = use_state(Vec::<T>::new);
+    let loading = use_state(|| false);
+    let request = use_mut_ref(|| 0usize);
+    let generated_id = use_unique_id("select");
+    let base_id = props.id.clone().unwrap_or(generated_id);
+    let container_ref = use_node_ref();
+    let trigger_ref = use_node_ref();
+    let search_ref = use_node_ref();
+    let list_ref = use_node_ref();
 
-    let toggle = {
+    // Ask the loader for fresh options whenever the dropdown opens or the search changes.
+    // Responses to superseded requests are dropped.
+    {
+        let load_options = props.load_options.clone();
+        let loaded = loaded.clone();
+        let loading = loading.clone();
+        use_effect_with((*is_open, (*query).clone()), move |(is_open, query)| {
+            if let (true, Some(load_options)) = (*is_open, load_options) {
+                let id = {
+                    let mut request = request.borrow_mut();
+                    *request += 1;
+                    *request
+                };
+                loading.set(true);
+                load_options.emit((
+                    query.clone(),
+                    Callback::from(move |options: Vec<T>| {
+                        if *request.borrow() == id {
+                            loaded.set(options);
+                            loading.set(false);
+                        }
+                    }),
+                ));
+            }
+            || ()
+        });
+    }
+
+    {
+        let search_ref = search_ref.clone();
+        use_effect_with(*is_open, move |is_open| {
+            if *is_open {
+                if let Some(input) = search_ref.cast::<HtmlInputElement>() {
+                    let _ = input.focus();
+                }
+            }
+            || ()
+        });
+    }
+
+    // Keep the highlighted option visible inside the scrollable list.
+    {
+        let list_ref = list_ref.clone();
+        let base_id = base_id.clone();
+        use_effect_with(*active, move |active| {
+            if let (Some(index), Some(list)) = (active, list_ref.cast::<web_sys::Element>()) {
+                if let Some(option) = document().get_element_by_id(&option_id(&base_id, *index)) {
+                    let list_rect = list.get_bounding_client_rect();
+                    let option_rect = option.get_bounding_client_rect();
+                    if option_rect.top() < list_rect.top() {
+                        list.set_scroll_top(
+                            list.scroll_top() - (list_rect.top() - option_rect.top()).ceil() as i32,
+                        );
+                    } else if option_rect.bottom() > list_rect.bottom() {
+                        list.set_scroll_top(
+                            list.scroll_top()
+                                + (option_rect.bottom() - list_rect.bottom()).ceil() as i32,
+                        );
+                    }
+                }
+            }
+            || ()
+        });
+    }
+
+    let sections: Vec<(Option<String>, Vec<T>)> = if props.load_options.is_some() {
+        vec![(None, (*loaded).clone())]
+    } else {
+        let needle = query.to_lowercase();
+        let matches =
+            |option: &&T| needle.is_empty() || option.to_string().to_lowercase().contains(&needle);
+        std::iter::once((
+            None,
+            props.options.iter().filter(matches).cloned().collect(),
+        ))
+        .chain(props.groups.iter().map(|group| {
+            (
+                Some(group.label.clone()),
+                group.options.iter().filter(matches).cloned().collect(),
+            )
+        }))
+        .filter(|(_, options): &(Option<String>, Vec<T>)| !options.is_empty())
+        .collect()
+    };
+    let visible: Rc<Vec<T>> = Rc::new(
+        sections
+            .iter()
+            .flat_map(|(_, options)| options.iter().cloned())
+            .collect(),
+    );
+
+    let focus_trigger = {
+        let trigger_ref = trigger_ref.clone();
+        move || {
+            if let Some(trigger) = trigger_ref.cast::<web_sys::HtmlElement>() {
+                let _ = trigger.focus();
+            }
+        }
+    };
+
+    let open = {
         let is_open = is_open.clone();
+        let query = query.clone();
+        let active = active.clone();
+        let visible = visible.clone();
+        let selected = selected.clone();
+        let selected_values = selected_values.clone();
+        let multiple = props.multiple;
+        Callback::from(move |_| {
+            let current = if multiple {
+                selected_values.first().cloned()
+            } else {
+                (*selected).clone()
+            };
+            query.set(String::new());
+            active.set(
+                current
+                    .and_then(|value| visible.iter().position(|option| *option == value))
+                    .or_else(|| (!visible.is_empty()).then_some(0)),
+            );
+            is_open.set(true);
+        })
+    };
+
+    let close = {
+        let is_open = is_open.clone();
+        let query = query.clone();
+        let active = active.clone();
+        Callback::from(move |_| {
+            is_open.set(false);
+            query.set(String::new());
+            active.set(None);
+        })
+    };
+
+    let toggle = {
+        let open = open.clone();
+        let close = close.clone();
+        let is_open = *is_open;
         Callback::from(move |_| {
-            is_open.set(!*is_open);
+            if is_open {
+                close.emit(())
+            } else {
+                open.emit(())
+            }
         })
     };
 
     let on_select = {
         let selected = selected.clone();
+        let selected_values = selected_values.clone();
         let onchange = props.onchange.clone();
+        let onchange_multiple = props.onchange_multiple.clone();
+        let multiple = props.multiple;
+        let close = close.clone();
+        let focus_trigger = focus_trigger.clone();
+        Callback::from(move |value: T| {
+            if multiple {
+                let mut values = (*selected_values).clone();
+                match values.iter().position(|existing| *existing == value) {
+                    Some(index) => {
+                        values.remove(index);
+                    }
+                    None => values.push(value),
+                }
+                selected_values.set(values.clone());
+                onchange_multiple.emit(values);
+            } else {
+                selected.set(Some(value.clone()));
+                onchange.emit(value);
+                close.emit(());
+                focus_trigger();
+            }
+        })
+    };
+
+    let remove_value = {
+        let selected_values = selected_values.clone();
+        let onchange_multiple = props.onchange_multiple.clone();
         Callback::from(move |value: T| {
-            selected.set(Some(value.clone()));
-            onchange.emit(value);
+            let values: Vec<T> = selected_values
+                .iter()
+                .filter(|existing| **existing != value)
+                .cloned()
+                .collect();
+            selected_values.set(values.clone());
+            onchange_multiple.emit(values);
         })
     };
 
     let state = Rc::new(SelectState {
         selected: (*selected).clone(),
+        selected_values: (*selected_values).clone(),
         is_open: *is_open,
         toggle,
         on_select,
     });
 
-    let selected_label = props
-        .options
-        .iter()
-        .find(|value| Some(*value) == selected.as_ref())
-        .map(|value| value.to_string())
-        .unwrap_or_default();
+    let onkeydown = {
+        let state = state.clone();
+        let open = open.clone();
+        let close = close.clone();
+        let active = active.clone();
+        let visible = visible.clone();
+        let remove_value = remove_value.clone();
+        let query_is_empty = query.is_empty();
+        let searchable = props.searchable;
+        let multiple = props.multiple;
+        Callback::from(move |event: KeyboardEvent| {
+            let key = event.key();
+            if multiple && key == "Backspace" && query_is_empty {
+                if let Some(last) = state.selected_values.last() {
+                    remove_value.emit(last.clone());
+                }
+                return;
+            }
+            if !state.is_open {
+                if matches!(key.as_str(), "ArrowDown" | "ArrowUp" | "Enter" | " ") {
+                    event.prevent_default();
+                    open.emit(());
+                }
+                return;
+            }
+            let last = visible.len().checked_sub(1);
+            match key.as_str() {
+                "ArrowDown" => {
+                    event.prevent_default();
+                    active.set(last.map(|last| active.map_or(0, |index| (index + 1).min(last))));
+                }
+                "ArrowUp" => {
+                    event.prevent_default();
+                    active
+                        .set(last.map(|last| active.map_or(last, |index| index.saturating_sub(1))));
+                }
+                "Home" if !searchable => {
+                    event.prevent_default();
+                    active.set(last.map(|_| 0));
+                }
+                "End" if !searchable => {
+                    event.prevent_default();
+                    active.set(last);
+                }
+                "Enter" => {
+                    event.prevent_default();
+                    if let Some(value) = active.and_then(|index| visible.get(index)) {
+                        state.on_select.emit(value.clone());
+                    }
+                }
+                " " if !searchable => {
+                    event.prevent_default();
+                    if let Some(value) = active.and_then(|index| visible.get(index)) {
+                        state.on_select.emit(value.clone());
+                    }
+                }
+                "Escape" => {
+                    event.prevent_default();
+                    close.emit(());
+                    focus_trigger();
+                }
+                "Tab" => close.emit(()),
+                _ => {}
+            }
+        })
+    };
+
+    // Close when focus leaves the component, as `Popover` does.
+    let onfocusout = {
+        let container_ref = container_ref.clone();
+        let close = close.clone();
+        let is_open = *is_open;
+        Callback::from(move |event: FocusEvent| {
+            if !is_open {
+                return;
+            }
+            let inside = event
+                .related_target()
+                .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                .zip(container_ref.cast::<web_sys::Element>())
+                .map(|(target, container)| container.contains(Some(&target)))
+                .unwrap_or(false);
+            if !inside {
+                close.emit(());
+            }
+        })
+    };
+
+    let oninput = {
+        let query = query.clone();
+        let active = active.clone();
+        Callback::from(move |event: InputEvent| {
+            let input: HtmlInputElement = event.target_unchecked_into();
+            query.set(input.value());
+            active.set(Some(0));
+        })
+    };
+
+    let listbox_id = format!("{}-listbox", base_id);
+    let active_descendant = active
+        .filter(|index| *is_open && *index < visible.len())
+        .map(|index| option_id(&base_id, index));
+    let placeholder = props.placeholder.clone().unwrap_or_default();
+
+    let trigger_content = if props.multiple {
+        if selected_values.is_empty() {
+            html! { <span class={&brandguide.select_trigger_placeholder}>{ placeholder }</span> }
+        } else {
+            html! {
+                { for selected_values.iter().map(|value| {
+                    let label = value.to_string();
+                    let onclick = {
+                        let remove_value = remove_value.clone();
+                        let value = value.clone();
+                        Callback::from(move |event: MouseEvent| {
+                            event.stop_propagation();
+                            remove_value.emit(value.clone());
+                        })
+                    };
+                    html! {
+                        <span class={&brandguide.select_chip}>
+                            { label.clone() }
+                            <span
+                                role="button"
+                                aria-label={format!("Remove {}", label)}
+                                class={&brandguide.select_chip_remove}
+                                {onclick}
+                            >
+                                { "×" }
+                            </span>
+                        </span>
+                    }
+                }) }
+            }
+        }
+    } else {
+        let selected_label = selected
+            .as_ref()
+            .map(|value| value.to_string())
+            .unwrap_or_else(|| placeholder.to_string());
+        html! { <span class={&brandguide.select_trigger_placeholder}>{ selected_label }</span> }
+    };
+
+    let chevron = html! {
+        <svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class={&brandguide.select_trigger_icon} aria-hidden="true">
+            <path d="m6 9 6 6 6-6"></path>
+        </svg>
+    };
+
+    let trigger_onclick = {
+        let toggle = state.toggle.clone();
+        move |_| toggle.emit(())
+    };
+    let trigger_descendant = active_descendant.clone().filter(|_| !props.searchable);
+
+    let mut index = 0;
+    let options_html = sections.iter().enumerate().map(|(section, (label, options))| {
+        let items = options.iter().map(|value| {
+            let item_index = index;
+            index += 1;
+            let is_selected = if props.multiple {
+                selected_values.contains(value)
+            } else {
+                selected.as_ref() == Some(value)
+            };
+            let onclick = {
+                let value = value.clone();
+                let on_select = state.on_select.clone();
+                Callback::from(move |_| on_select.emit(value.clone()))
+            };
+            let onmousemove = {
+                let active = active.clone();
+                Callback::from(move |_: MouseEvent| {
+                    if *active != Some(item_index) {
+                        active.set(Some(item_index));
+                    }
+                })
+            };
+            html! {
+                <li
+                    id={option_id(&base_id, item_index)}
+                    role="option"
+                    aria-selected={is_selected.to_string()}
+                    class={classes!(
+                        &brandguide.select_item,
+                        (*active == Some(item_index)).then_some(&brandguide.select_item_active),
+                        is_selected.then_some(&brandguide.select_item_selected),
+                    )}
+                    // Keeps focus where it is, so the list doesn't close before the click
+                    onmousedown={|e: MouseEvent| e.prevent_default()}
+                    {onclick}
+                    {onmousemove}
+                >
+                    { value.to_string() }
+                </li>
+            }
+        }).collect::<Html>();
+
+        match label {
+            Some(label) => {
+                let label_id = format!("{}-group-{}", base_id, section);
+                html! {
+                    <li role="group" aria-labelledby={label_id.clone()} class={&brandguide.select_group}>
+                        <div id={label_id} class={&brandguide.select_label}>{ label }</div>
+                        <ul role="none">{ items }</ul>
+                    </li>
+                }
+            }
+            None => items,
+        }
+    }).collect::<Html>();
 
     html! {
-        <div class={&brandguide.select_container} id={props.id.clone()}>
-            <button
-                type="button"
-                class={&brandguide.select_trigger}
-                onclick={{
-                    let toggle = state.toggle.clone();
-                    move |_| toggle.emit(())
-                }}
-            >
-                <span class={&brandguide.select_trigger_placeholder}>{ selected_label }</span>
-                <svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class={&brandguide.select_trigger_icon} aria-hidden="true">
-                    <path d="m6 9 6 6 6-6"></path>
-                </svg>
-            </button>
+        <div
+            ref={container_ref}
+            class={&brandguide.select_container}
+            id={props.id.clone()}
+            {onkeydown}
+            {onfocusout}
+        >
+            if props.multiple {
+                <div
+                    ref={trigger_ref}
+                    role="combobox"
+                    tabindex="0"
+                    aria-haspopup="listbox"
+                    aria-expanded={is_open.to_string()}
+                    aria-controls={listbox_id.clone()}
+                    aria-activedescendant={trigger_descendant}
+                    class={&brandguide.select_trigger_multiple}
+                    onclick={trigger_onclick}
+                >
+                    { trigger_content }
+                    { chevron }
+                </div>
+            } else {
+                <button
+                    ref={trigger_ref}
+                    type="button"
+                    role="combobox"
+                    aria-haspopup="listbox"
+                    aria-expanded={is_open.to_string()}
+                    aria-controls={listbox_id.clone()}
+                    aria-activedescendant={trigger_descendant}
+                    class={&brandguide.select_trigger}
+                    onclick={trigger_onclick}
+                >
+                    { trigger_content }
+                    { chevron }
+                </button>
+            }
             if *is_open {
                 <div class={&brandguide.select_content_container}>
-                    <ul class={&brandguide.select_content_list}>
-                        {for props.options.iter().map(|value| {
-                            let on_click = {
-                                let value = value.clone();
-                                let on_select = state.on_select.clone();
-                                let toggle = state.toggle.clone();
-                                Callback::from(move |_| {
-                                    on_select.emit(value.clone());
-                                    toggle.emit(());
-                                })
-                            };
-                            html! {
-                                <li
-                                    class={&brandguide.select_item}
-                                    onclick={on_click}
-                                >
-                                    { value.to_string() }
-                                </li>
-                            }
-                        })}
+                    if props.searchable {
+                        <input
+                            ref={search_ref}
+                            type="text"
+                            class={&brandguide.select_search_input}
+                            value={(*query).clone()}
+                            aria-autocomplete="list"
+                            aria-controls={listbox_id.clone()}
+                            aria-activedescendant={active_descendant}
+                            {oninput}
+                        />
+                    }
+                    if *loading {
+                        <div class={&brandguide.select_message} role="status">{ props.loading_text.clone() }</div>
+                    } else if visible.is_empty() {
+                        <div class={&brandguide.select_message} role="status">{ props.empty_text.clone() }</div>
+                    }
+                    <ul
+                        ref={list_ref}
+                        id={listbox_id}
+                        role="listbox"
+                        aria-multiselectable={props.multiple.then_some("true")}
+                        class={&brandguide.select_content_list}
+                    >
+                        { options_html }
                     </ul>
                 </div>
             }
@@ -119,13 +575,6 @@ pub fn select<T: Clone + PartialEq + ToString + 'static>(props: &SelectProps<T>)
     }
 }
 
-// New component names to add to BRANDGUIDE:
-// select_container: "relative inline-block text-left"
-// select_trigger: "flex h-10 items-center justify-between rounded-md border border-gray-300 bg-white px-3 py-2 text-sm ring-offset-white focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50 w-[180px]"
-// select_trigger_placeholder: "pointer-events-none"
-// select_trigger_icon: "lucide lucide-chevron-down h-4 w-4 opacity-50"
-// select_content_container: "absolute mt-1 w-full rounded-md bg-white shadow-lg z-10"
-// select_content_list: "max-h-60 rounded-md py-1 text-base ring-1 ring-black ring-opacity-5 overflow-auto focus:outline-none sm:text-sm"
-// select_group: "text-gray-900"
-// select_label: "px-4 py-2 text-sm text-gray-700"
-// select_item: "text-gray-900 cursor-pointer select-none relative py-2 pl-3 pr-9 hover:bg-gray-100"
+fn option_id(base_id: &str, index: usize) -> String {
+    format!("{}-option-{}", base_id, index)
+}
@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use std::rc::Rc;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
@@ -99,7 +100,7 @@ pub fn selectable_indicator(props: &SelectableIndicatorProps) -> Html {
                 "position: fixed; top: {}px; left: {}px; width: {}px; height: {}px; z-index: 1000; pointer-events: none;",
                 area.top, area.left, area.width, area.height
             );
-            html! { <div style={style} class={classes!(&brandguide.selectable_indicator, props.class.clone())} /> }
+            html! { <div style={style} class={tw_merge(classes!(&brandguide.selectable_indicator, props.class.clone()))} /> }
         }
         None => {
             html! {}
@@ -301,12 +302,13 @@ pub fn selectable(props: &SelectableProps) -> Html {
         });
     }
 
-    let mut classes = props.class.clone();
-    classes.extend(classes!(brandguide.selectable_hover.clone()));
+    let mut classes = classes!(brandguide.selectable_hover.clone());
     if selected {
         classes.extend(classes!(brandguide.selectable_selected.clone()));
     }
     classes.extend(classes!(brandguide.selectable_cursor.clone()));
+    classes.extend(props.class.clone());
+    let classes = tw_merge(classes);
 
     html! {
         <@{props.tag.clone()} class={classes} style={props.style.clone()} {onclick} {onmouseenter} {onmouseleave} ref={node_ref}  key="selectable">
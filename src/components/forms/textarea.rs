@@ -3,6 +3,7 @@ use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
 use crate::config::ClassesStr;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 //use web_sys::InputData;
@@ -40,7 +41,7 @@ pub fn textarea(props: &TextareaProps) -> Html {
 
     html! {
         <textarea
-            class={classes!(&brandguide.textarea_base, disabled_class, props.class.clone())}
+            class={tw_merge(classes!(&brandguide.textarea_base, disabled_class, props.class.clone()))}
             value={props.value.clone()}
             oninput={props.oninput.clone()}
             placeholder={props.placeholder.clone()}
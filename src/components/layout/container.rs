@@ -3,6 +3,7 @@ use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
 use crate::config::ClassesStr;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(PartialEq)]
@@ -69,6 +70,6 @@ pub fn container(props: &ContainerProps) -> Html {
     let container_class = classes!(padding_x, padding_y, expanding, variant);
 
     html!(
-        <@{props.tag.clone()} class={classes!(container_class, props.class.clone())} style={props.style.clone()}>{props.children.clone()}</@>
+        <@{props.tag.clone()} class={tw_merge(classes!(container_class, props.class.clone()))} style={props.style.clone()}>{props.children.clone()}</@>
     )
 }
@@ -4,6 +4,7 @@ use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
 use crate::config::ClassesStr;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -30,7 +31,7 @@ pub fn main_content(props: &ContentProps) -> Html {
     let brandguide = get_brandguide();
 
     let base_container = html! {
-        <Container key="main" tag="main" variant={ContainerVariant::None} expanding={props.expanding} padding_x={props.padding_x} padding_y={props.padding_y} class={classes!(props.class.clone(), if props.aside.is_some() { brandguide.content_with_aside.clone() } else { ClassesStr::empty() })}>
+        <Container key="main" tag="main" variant={ContainerVariant::None} expanding={props.expanding} padding_x={props.padding_x} padding_y={props.padding_y} class={tw_merge(classes!(if props.aside.is_some() { brandguide.content_with_aside.clone() } else { ClassesStr::empty() }, props.class.clone()))}>
             {props.children.clone()}
         </Container>
     };
@@ -1,5 +1,12 @@
-use crate::components::utils::media_query::use_media_query;
-use crate::components::layout::layout_context::{LayoutContext, LayoutState, SidebarPosition};
+use crate::components::layout::layout_context::{
+    restore_actions, LayoutAction, LayoutContext, LayoutState, SidebarPosition, StoredLayout,
+};
+use crate::components::utils::breakpoint::{use_breakpoint, Breakpoint};
+use crate::components::utils::browser::RENDER_READS_BROWSER;
+use gloo::events::{EventListener, EventListenerOptions};
+use gloo::storage::{LocalStorage, Storage};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
 use yew::context::ContextProvider;
 use yew::prelude::*;
 
@@ -53,7 +60,7 @@ pub fn layout(props: &LayoutProps) -> Html {
         String::new()
     };
 
-    let is_large_screen = use_media_query("(min-width: 1024px)");
+    let is_large_screen = use_breakpoint() >= Some(Breakpoint::Lg);
 
     let direction = match props.direction {
         LayoutDirection::None => classes!("overflow-y-auto"),
@@ -83,8 +90,80 @@ pub fn layout(props: &LayoutProps) -> Html {
 
 #[function_component(LayoutProvider)]
 pub fn layout_provider(props: &LayoutProviderProps) -> Html {
+    let stored_layout = {
+        let storage_key = props.storage_key.clone();
+        move || {
+            storage_key
+                .as_ref()
+                .and_then(|key| LocalStorage::get::<StoredLayout>(key.as_str()).ok())
+        }
+    };
     let initial_state = props.initial_state.clone();
-    let layout_context = use_reducer(|| initial_state);
+    let layout_context = {
+        let stored_layout = stored_layout.clone();
+        use_reducer(move || {
+            // Without a browser to ask, the stored layout is restored once mounted
+            let stored = if RENDER_READS_BROWSER {
+                stored_layout()
+            } else {
+                None
+            };
+            let state = restore_actions(stored)
+                .into_iter()
+                .fold(Rc::new(initial_state), Reducible::reduce);
+            Rc::unwrap_or_clone(state)
+        })
+    };
+    {
+        let layout_context = layout_context.clone();
+        use_effect_with((), move |_| {
+            if !RENDER_READS_BROWSER {
+                for action in restore_actions(stored_layout()) {
+                    layout_context.dispatch(action);
+                }
+            }
+        });
+    }
+    {
+        let storage_key = props.storage_key.clone();
+        use_effect_with(
+            (storage_key, layout_context.stored()),
+            |(storage_key, stored)| {
+                if let Some(key) = storage_key {
+                    let _ = LocalStorage::set(key.as_str(), stored);
+                }
+            },
+        );
+    }
+    {
+        let layout_context = layout_context.clone();
+        let folded = layout_context.sidebar_folded;
+        use_effect_with((props.shortcuts, folded), move |(shortcuts, folded)| {
+            let folded = *folded;
+            let listener = shortcuts.then(|| {
+                let options = EventListenerOptions::enable_prevent_default();
+                EventListener::new_with_options(
+                    &gloo::utils::window(),
+                    "keydown",
+                    options,
+                    move |event| {
+                        let Some(event) = event.dyn_ref::<web_sys::KeyboardEvent>() else {
+                            return;
+                        };
+                        let toggle = (event.ctrl_key() || event.meta_key())
+                            && event.key().eq_ignore_ascii_case("b")
+                            && !event.default_prevented()
+                            && !is_editable(event.target());
+                        if toggle {
+                            event.prevent_default();
+                            layout_context.dispatch(LayoutAction::SetSidebarFolded(!folded));
+                        }
+                    },
+                )
+            });
+            move || drop(listener)
+        });
+    }
 
     html! {
         <ContextProvider<LayoutContext> context={layout_context}>
@@ -93,10 +172,27 @@ pub fn layout_provider(props: &LayoutProviderProps) -> Html {
     }
 }
 
+// Whether keys pressed in `target` are typed into it, so shortcuts leave them alone
+fn is_editable(target: Option<web_sys::EventTarget>) -> bool {
+    let Some(element) = target.and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+    else {
+        return false;
+    };
+    element.is_content_editable()
+        || matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+}
+
 #[derive(Properties, PartialEq)]
 pub struct LayoutProviderProps {
     #[prop_or_default]
     pub initial_state: LayoutState,
     #[prop_or_default]
     pub children: Children,
-}
\ No newline at end of file
+    /// `localStorage` key the sidebar's width, fold and side are kept under, or `None` to not
+    /// persist them.
+    #[prop_or_default]
+    pub storage_key: Option<AttrValue>,
+    /// Whether Ctrl+B (⌘B on macOS) folds and unfolds the sidebar.
+    #[prop_or_default]
+    pub shortcuts: bool,
+}
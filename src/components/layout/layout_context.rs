@@ -1,8 +1,10 @@
 use crate::components::layout::multicol_sidebar::SidebarColumn;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 use yew::prelude::*;
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SidebarPosition {
     Left,
     Right,
@@ -15,6 +17,14 @@ pub struct LayoutState {
     pub folded_menu_size: i32,
     pub standard_menu_size: i32,
     pub base_size: i32,
+    /// Narrowest and widest the sidebar can be dragged to.
+    pub min_menu_size: i32,
+    pub max_menu_size: i32,
+    /// Dragging the sidebar narrower than this folds it, and a folded one wider unfolds it.
+    pub fold_threshold: i32,
+    /// Whether `standard_menu_size` was set by dragging or restoring rather than derived from the
+    /// number of sidebar columns.
+    pub sidebar_resized: bool,
     pub show_topbar: bool,
     pub show_footer: bool,
     pub sidebar_folded: bool,
@@ -36,6 +46,10 @@ impl Default for LayoutState {
             folded_menu_size: 72,
             standard_menu_size: 288,
             base_size: 288,
+            min_menu_size: 200,
+            max_menu_size: 640,
+            fold_threshold: 136,
+            sidebar_resized: false,
             show_topbar: true,
             show_footer: true,
             sidebar_folded: false,
@@ -48,6 +62,8 @@ impl Default for LayoutState {
 pub enum LayoutAction {
     SetMobileMenuOpen(bool),
     SetSizeMenuSize(i32), // Corrected type from String to i32
+    /// Sets the width of the sidebar unless it was resized, for sidebars sizing to their content.
+    SetDefaultMenuSize(i32),
     SetShowTopbar(bool),
     SetShowFooter(bool),
     SetSidebarFolded(bool),
@@ -61,7 +77,16 @@ impl Reducible for LayoutState {
         let mut state = (*self).clone();
         match action {
             LayoutAction::SetMobileMenuOpen(value) => state.mobile_menu_open = value,
-            LayoutAction::SetSizeMenuSize(value) => state.standard_menu_size = value,
+            LayoutAction::SetSizeMenuSize(value) => {
+                state.standard_menu_size = value;
+                state.sidebar_resized = true;
+            }
+            LayoutAction::SetDefaultMenuSize(value) => {
+                if state.sidebar_resized {
+                    return self;
+                }
+                state.standard_menu_size = value;
+            }
             LayoutAction::SetShowTopbar(value) => state.show_topbar = value,
             LayoutAction::SetShowFooter(value) => state.show_footer = value,
             LayoutAction::SetSidebarFolded(value) => state.sidebar_folded = value,
@@ -74,3 +99,109 @@ impl Reducible for LayoutState {
 }
 
 pub type LayoutContext = UseReducerHandle<LayoutState>;
+
+// What `LayoutProvider` keeps in `localStorage`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct StoredLayout {
+    /// The width the sidebar was resized to, if it was
+    pub width: Option<i32>,
+    pub folded: bool,
+    pub position: SidebarPosition,
+}
+
+impl LayoutState {
+    pub(crate) fn stored(&self) -> StoredLayout {
+        StoredLayout {
+            width: self.sidebar_resized.then_some(self.standard_menu_size),
+            folded: self.sidebar_folded,
+            position: self.sidebar_position.clone(),
+        }
+    }
+}
+
+// The actions bringing a layout back to a stored one, if any
+pub(crate) fn restore_actions(stored: Option<StoredLayout>) -> Vec<LayoutAction> {
+    let Some(stored) = stored else {
+        return Vec::new();
+    };
+    let mut actions = vec![LayoutAction::SetSidebarFolded(stored.folded)];
+    if let Some(width) = stored.width {
+        actions.push(LayoutAction::SetSizeMenuSize(width));
+    }
+    actions.push(LayoutAction::SetSidebarPosition(stored.position));
+    actions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(state: LayoutState, actions: Vec<LayoutAction>) -> LayoutState {
+        let state = actions.into_iter().fold(Rc::new(state), Reducible::reduce);
+        Rc::unwrap_or_clone(state)
+    }
+
+    #[test]
+    fn resizing_keeps_the_width_over_defaults() {
+        let state = apply(
+            LayoutState::default(),
+            vec![LayoutAction::SetDefaultMenuSize(576)],
+        );
+        assert_eq!(state.standard_menu_size, 576);
+        assert!(!state.sidebar_resized);
+
+        let state = apply(
+            state,
+            vec![
+                LayoutAction::SetSizeMenuSize(320),
+                LayoutAction::SetDefaultMenuSize(576),
+            ],
+        );
+        assert_eq!(state.standard_menu_size, 320);
+        assert!(state.sidebar_resized);
+    }
+
+    #[test]
+    fn stored_layout_restores() {
+        let state = apply(
+            LayoutState::default(),
+            vec![
+                LayoutAction::SetSizeMenuSize(360),
+                LayoutAction::SetSidebarFolded(true),
+                LayoutAction::SetSidebarPosition(SidebarPosition::Right),
+            ],
+        );
+        let stored = state.stored();
+        assert_eq!(
+            stored,
+            StoredLayout {
+                width: Some(360),
+                folded: true,
+                position: SidebarPosition::Right,
+            }
+        );
+
+        let restored = apply(
+            LayoutState::default(),
+            restore_actions(Some(stored.clone())),
+        );
+        assert_eq!(restored.stored(), stored);
+        assert_eq!(restored.standard_menu_size, 360);
+    }
+
+    #[test]
+    fn unresized_width_is_not_restored() {
+        let stored = LayoutState::default().stored();
+        assert_eq!(stored.width, None);
+
+        let restored = apply(LayoutState::default(), restore_actions(Some(stored)));
+        assert!(!restored.sidebar_resized);
+        let restored = apply(restored, vec![LayoutAction::SetDefaultMenuSize(576)]);
+        assert_eq!(restored.standard_menu_size, 576);
+    }
+
+    #[test]
+    fn nothing_stored_restores_nothing() {
+        assert!(restore_actions(None).is_empty());
+    }
+}
@@ -20,13 +20,15 @@ pub mod content;
 pub mod page_header;
 #[cfg(feature = "Resizable")]
 pub mod resizable;
+#[cfg(feature = "PanelGroup")]
+pub mod panel_group;
 
 #[cfg(feature = "Layout")]
 pub use layout::{use_layout, Layout, LayoutDirection, LayoutProvider};
 #[cfg(feature = "Layout")]
 pub use layout_context::{LayoutAction, LayoutContext, LayoutState, SidebarPosition};
 #[cfg(feature = "MulticolSidebar")]
-pub use multicol_sidebar::{MultiColumnSidebar, MultiColumnSidebarProps, SidebarColumn, SidebarColumnProps};
+pub use multicol_sidebar::{MultiColumnSidebar, MultiColumnSidebarProps, SidebarColumn, SidebarColumnProps, SidebarResizeHandle};
 #[cfg(feature = "PageContent")]
 pub use page_content::PageContent;
 #[cfg(feature = "Sidebar")]
@@ -40,4 +42,6 @@ pub use content::MainContent;
 #[cfg(feature = "PageHeader")]
 pub use page_header::PageHeader;
 #[cfg(feature = "Resizable")]
-pub use resizable::Resizable;
\ No newline at end of file
+pub use resizable::Resizable;
+#[cfg(feature = "PanelGroup")]
+pub use panel_group::{Panel, PanelDirection, PanelGroup};
\ No newline at end of file
@@ -1,4 +1,9 @@
 use crate::components::layout::layout_context::{LayoutAction, LayoutContext, SidebarPosition};
+#[cfg(not(feature = "ThemeProvider"))]
+use crate::config::get_brandguide;
+#[cfg(feature = "ThemeProvider")]
+use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*; // Import LayoutContext and SidebarPosition
 
 #[derive(Properties, PartialEq)]
@@ -59,12 +64,161 @@ pub fn sidebar_column(props: &SidebarColumnProps) -> Html {
     }
 }
 
+// Width a keyboard press resizes the sidebar by
+const RESIZE_STEP: i32 = 16;
+
+#[derive(Properties, PartialEq)]
+pub struct SidebarResizeHandleProps {
+    #[prop_or_default]
+    pub class: Classes,
+    /// Name read by screen readers
+    #[prop_or(AttrValue::from("Resize sidebar"))]
+    pub aria_label: AttrValue,
+}
+
+/// Drag handle on the inner edge of the sidebar. Dragging resizes the sidebar between the
+/// `min_menu_size` and `max_menu_size` of the layout and folds it below `fold_threshold`. With
+/// focus, the arrow keys resize it, Home and End make it narrowest and widest, and Enter folds or
+/// unfolds it.
+#[function_component(SidebarResizeHandle)]
+pub fn sidebar_resize_handle(props: &SidebarResizeHandleProps) -> Html {
+    #[cfg(feature = "ThemeProvider")]
+    let brandguide = use_brandguide();
+    #[cfg(not(feature = "ThemeProvider"))]
+    let brandguide = get_brandguide();
+    let layout_context = use_context::<LayoutContext>().expect("LayoutContext not found");
+    // Pointer position and sidebar width where the drag started
+    let drag_start = use_mut_ref(|| None::<(i32, i32)>);
+    let folded = layout_context.sidebar_folded;
+    let (min, max) = (
+        layout_context.min_menu_size,
+        layout_context
+            .max_menu_size
+            .max(layout_context.min_menu_size),
+    );
+    let width = if folded {
+        layout_context.folded_menu_size
+    } else {
+        layout_context.standard_menu_size
+    };
+    // Whether moving right widens the sidebar
+    let (direction, edge_class) = match layout_context.sidebar_position {
+        SidebarPosition::Left => (1, &brandguide.multicol_sidebar_resize_handle_left),
+        SidebarPosition::Right => (-1, &brandguide.multicol_sidebar_resize_handle_right),
+    };
+
+    let resize = {
+        let layout_context = layout_context.clone();
+        move |width: i32| {
+            let fold = width < layout_context.fold_threshold;
+            if fold != layout_context.sidebar_folded {
+                layout_context.dispatch(LayoutAction::SetSidebarFolded(fold));
+            }
+            if !fold {
+                layout_context.dispatch(LayoutAction::SetSizeMenuSize(width.clamp(min, max)));
+            }
+        }
+    };
+
+    let onpointerdown = {
+        let drag_start = drag_start.clone();
+        Callback::from(move |e: PointerEvent| {
+            if e.button() != 0 {
+                return;
+            }
+            // Keeps the drag from selecting text
+            e.prevent_default();
+            if let Some(element) = e.target_dyn_into::<web_sys::Element>() {
+                let _ = element.set_pointer_capture(e.pointer_id());
+            }
+            *drag_start.borrow_mut() = Some((e.client_x(), width));
+        })
+    };
+    let onpointermove = {
+        let drag_start = drag_start.clone();
+        let resize = resize.clone();
+        Callback::from(move |e: PointerEvent| {
+            let Some((x, width)) = *drag_start.borrow() else {
+                return;
+            };
+            resize(width + direction * (e.client_x() - x));
+        })
+    };
+    let onpointerup = {
+        let drag_start = drag_start.clone();
+        Callback::from(move |e: PointerEvent| {
+            if drag_start.borrow_mut().take().is_some() {
+                if let Some(element) = e.target_dyn_into::<web_sys::Element>() {
+                    let _ = element.release_pointer_capture(e.pointer_id());
+                }
+            }
+        })
+    };
+
+    let onkeydown = {
+        let layout_context = layout_context.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let wider = match e.key().as_str() {
+                "ArrowRight" => direction > 0,
+                "ArrowLeft" => direction < 0,
+                "Home" => {
+                    resize(min);
+                    e.prevent_default();
+                    return;
+                }
+                "End" => {
+                    resize(max);
+                    e.prevent_default();
+                    return;
+                }
+                "Enter" => {
+                    layout_context.dispatch(LayoutAction::SetSidebarFolded(!folded));
+                    e.prevent_default();
+                    return;
+                }
+                _ => return,
+            };
+            e.prevent_default();
+            if folded {
+                if wider {
+                    layout_context.dispatch(LayoutAction::SetSidebarFolded(false));
+                }
+            } else if wider {
+                resize(width + RESIZE_STEP);
+            } else {
+                resize((width - RESIZE_STEP).max(min));
+            }
+        })
+    };
+
+    html! {
+        <div
+            role="separator"
+            aria-orientation="vertical"
+            aria-label={props.aria_label.clone()}
+            aria-valuenow={width.to_string()}
+            aria-valuemin={min.to_string()}
+            aria-valuemax={max.to_string()}
+            tabindex="0"
+            class={tw_merge(classes!(&brandguide.multicol_sidebar_resize_handle, edge_class, props.class.clone()))}
+            {onpointerdown}
+            {onpointermove}
+            onpointerup={onpointerup.clone()}
+            onpointercancel={onpointerup}
+            {onkeydown}
+        />
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct MultiColumnSidebarProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub curtain_content: Html,
+    /// Whether the sidebar has a `SidebarResizeHandle` on its inner edge.
+    #[prop_or_default]
+    pub resizable: bool,
 }
 
 #[function_component(MultiColumnSidebar)]
@@ -72,6 +226,7 @@ pub fn multi_column_sidebar(props: &MultiColumnSidebarProps) -> Html {
     let MultiColumnSidebarProps {
         children,
         curtain_content,
+        resizable,
     } = props;
     let layout_context = use_context::<LayoutContext>().expect("LayoutContext not found");
     let folded = layout_context.sidebar_folded;
@@ -85,7 +240,7 @@ pub fn multi_column_sidebar(props: &MultiColumnSidebarProps) -> Html {
         let full_size = layout_context.base_size * (count as i32);
         let layout_context = layout_context.clone();
         use_effect_with((full_size,), move |(full_size,)| {
-            layout_context.dispatch(LayoutAction::SetSizeMenuSize(*full_size));
+            layout_context.dispatch(LayoutAction::SetDefaultMenuSize(*full_size));
         });
     }
 
@@ -137,6 +292,9 @@ pub fn multi_column_sidebar(props: &MultiColumnSidebarProps) -> Html {
             if let Some(extra_sidebar) = &layout_context.extra_sidebar {
                 {extra_sidebar.clone()}
             }
+            if *resizable {
+                <SidebarResizeHandle />
+            }
          </div>
        </>
     }
@@ -0,0 +1,488 @@
+use crate::components::utils::drag_point::DragPoint;
+#[cfg(not(feature = "ThemeProvider"))]
+use crate::config::get_brandguide;
+#[cfg(feature = "ThemeProvider")]
+use crate::config::use_brandguide;
+use crate::utils::tw_merge;
+use gloo::storage::{LocalStorage, Storage};
+use std::rc::Rc;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+// Share of the group a keyboard press moves a gutter by, in percent
+const RESIZE_STEP: f64 = 5.;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PanelDirection {
+    /// Panels side by side, with vertical gutters between them
+    #[default]
+    Horizontal,
+    /// Panels stacked, with horizontal gutters between them
+    Vertical,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PanelProps {
+    /// Set on the panel's element, and the name its size is stored under.
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    /// Share of the group in percent, or an equal share of what the other panels leave.
+    #[prop_or_default]
+    pub default_size: Option<f64>,
+    #[prop_or(0.)]
+    pub min_size: f64,
+    #[prop_or(100.)]
+    pub max_size: f64,
+    /// Whether dragging the panel below half its `min_size` collapses it to `collapsed_size`.
+    #[prop_or_default]
+    pub collapsible: bool,
+    #[prop_or(0.)]
+    pub collapsed_size: f64,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// A panel of a `PanelGroup`, which sizes and renders it.
+#[function_component(Panel)]
+pub fn panel(props: &PanelProps) -> Html {
+    html! { <>{ props.children.clone() }</> }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PanelGroupProps {
+    #[prop_or_default]
+    pub direction: PanelDirection,
+    /// `localStorage` key the panel sizes are kept under, by panel id, or `None` to not persist
+    /// them.
+    #[prop_or_default]
+    pub storage_key: Option<AttrValue>,
+    /// Called with the sizes of the panels in percent whenever they are resized.
+    #[prop_or_default]
+    pub onresize: Callback<Vec<f64>>,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub children: ChildrenWithProps<Panel>,
+}
+
+/// Divides its space between its `Panel`s, side by side or stacked, with a gutter between each
+/// two that drags to resize them. Gutters are focusable separators: the arrow keys move them,
+/// Home and End move them as far as the panels allow, and Enter collapses or expands the
+/// collapsible panel next to them.
+#[function_component(PanelGroup)]
+pub fn panel_group(props: &PanelGroupProps) -> Html {
+    #[cfg(feature = "ThemeProvider")]
+    let brandguide = use_brandguide();
+    #[cfg(not(feature = "ThemeProvider"))]
+    let brandguide = get_brandguide();
+
+    let panels: Vec<Rc<PanelProps>> = props
+        .children
+        .iter()
+        .map(|child| child.props.clone())
+        .collect();
+    let keys: Vec<String> = panels
+        .iter()
+        .enumerate()
+        .map(|(index, panel)| {
+            panel
+                .id
+                .as_ref()
+                .map_or_else(|| index.to_string(), |id| id.to_string())
+        })
+        .collect();
+    let group_ref = use_node_ref();
+    // Gutter, pointer position and sizes where the drag started
+    let drag_start = use_mut_ref(|| None::<(usize, f64, Vec<f64>)>);
+    let sizes = use_state_eq(Vec::<f64>::new);
+
+    // The stored sizes are picked up once mounted, so a server render shows the default ones
+    {
+        let sizes = sizes.clone();
+        let storage_key = props.storage_key.clone();
+        let defaults = default_sizes(&panels);
+        use_effect_with(keys.clone(), move |keys| {
+            let stored = storage_key
+                .as_ref()
+                .and_then(|key| stored_sizes(key.as_str(), keys));
+            sizes.set(stored.unwrap_or(defaults));
+        });
+    }
+    let current = if sizes.len() == panels.len() {
+        (*sizes).clone()
+    } else {
+        default_sizes(&panels)
+    };
+
+    let set_sizes = {
+        let sizes = sizes.clone();
+        let keys = keys.clone();
+        let storage_key = props.storage_key.clone();
+        let onresize = props.onresize.clone();
+        move |new_sizes: Vec<f64>| {
+            if let Some(key) = &storage_key {
+                let stored: Vec<(String, f64)> = keys
+                    .iter()
+                    .cloned()
+                    .zip(new_sizes.iter().copied())
+                    .collect();
+                let _ = LocalStorage::set(key.as_str(), stored);
+            }
+            onresize.emit(new_sizes.clone());
+            sizes.set(new_sizes);
+        }
+    };
+
+    let horizontal = props.direction == PanelDirection::Horizontal;
+    let pointer_position = move |e: &PointerEvent| {
+        if horizontal {
+            e.client_x() as f64
+        } else {
+            e.client_y() as f64
+        }
+    };
+    let (direction_class, gutter_class, handle_class, orientation) = if horizontal {
+        (
+            &brandguide.panel_group_horizontal,
+            &brandguide.panel_group_gutter_horizontal,
+            &brandguide.panel_group_handle_horizontal,
+            "vertical",
+        )
+    } else {
+        (
+            &brandguide.panel_group_vertical,
+            &brandguide.panel_group_gutter_vertical,
+            &brandguide.panel_group_handle_vertical,
+            "horizontal",
+        )
+    };
+
+    let gutter = |index: usize| {
+        let onstart = {
+            let drag_start = drag_start.clone();
+            let current = current.clone();
+            Callback::from(move |e: PointerEvent| {
+                *drag_start.borrow_mut() = Some((index, pointer_position(&e), current.clone()));
+            })
+        };
+        let onmove = {
+            let drag_start = drag_start.clone();
+            let group_ref = group_ref.clone();
+            let panels = panels.clone();
+            let set_sizes = set_sizes.clone();
+            Callback::from(move |e: PointerEvent| {
+                let Some((gutter, start, start_sizes)) = drag_start.borrow().clone() else {
+                    return;
+                };
+                let Some(group) = group_ref.cast::<HtmlElement>() else {
+                    return;
+                };
+                let rect = group.get_bounding_client_rect();
+                let length = if horizontal {
+                    rect.width()
+                } else {
+                    rect.height()
+                };
+                if length > 0. {
+                    let delta = (pointer_position(&e) - start) / length * 100.;
+                    set_sizes(resize(&start_sizes, &panels, gutter, delta));
+                }
+            })
+        };
+        let onstop = {
+            let drag_start = drag_start.clone();
+            Callback::from(move |_| {
+                drag_start.borrow_mut().take();
+            })
+        };
+        let onkeydown = {
+            let current = current.clone();
+            let panels = panels.clone();
+            let set_sizes = set_sizes.clone();
+            Callback::from(move |e: KeyboardEvent| {
+                let delta = match (e.key().as_str(), horizontal) {
+                    ("ArrowLeft", true) | ("ArrowUp", false) => -RESIZE_STEP,
+                    ("ArrowRight", true) | ("ArrowDown", false) => RESIZE_STEP,
+                    ("Home", _) => -100.,
+                    ("End", _) => 100.,
+                    ("Enter", _) => toggle_delta(&current, &panels, index),
+                    _ => return,
+                };
+                e.prevent_default();
+                set_sizes(resize(&current, &panels, index, delta));
+            })
+        };
+        let before = &panels[index];
+        html! {
+            <div
+                role="separator"
+                aria-orientation={orientation}
+                aria-valuenow={format!("{:.0}", current[index])}
+                aria-valuemin={format!("{:.0}", before.min_size)}
+                aria-valuemax={format!("{:.0}", before.max_size)}
+                aria-controls={before.id.clone()}
+                tabindex="0"
+                class={classes!(&brandguide.panel_group_gutter, gutter_class)}
+                {onkeydown}
+            >
+                <DragPoint class={classes!(handle_class)} {onstart} {onstop} {onmove} />
+            </div>
+        }
+    };
+
+    html! {
+        <div
+            ref={group_ref.clone()}
+            class={tw_merge(classes!(&brandguide.panel_group_container, direction_class, props.class.clone()))}
+        >
+            { for panels.iter().enumerate().map(|(index, panel)| html! {
+                <>
+                    if index > 0 {
+                        { gutter(index - 1) }
+                    }
+                    <div
+                        id={panel.id.clone()}
+                        class={tw_merge(classes!(&brandguide.panel_group_panel, panel.class.clone()))}
+                        style={format!("flex: {} 1 0%;", current[index])}
+                    >
+                        { panel.children.clone() }
+                    </div>
+                </>
+            }) }
+        </div>
+    }
+}
+
+// Shares given by the panels, the rest split equally between the others, scaled to 100
+fn default_sizes(panels: &[Rc<PanelProps>]) -> Vec<f64> {
+    let given: f64 = panels.iter().filter_map(|panel| panel.default_size).sum();
+    let flexible = panels
+        .iter()
+        .filter(|panel| panel.default_size.is_none())
+        .count();
+    let share = if flexible > 0 {
+        ((100. - given) / flexible as f64).max(0.)
+    } else {
+        0.
+    };
+    normalize(
+        panels
+            .iter()
+            .map(|panel| panel.default_size.unwrap_or(share))
+            .collect(),
+    )
+}
+
+fn normalize(sizes: Vec<f64>) -> Vec<f64> {
+    let total: f64 = sizes.iter().sum();
+    if total <= 0. {
+        let share = 100. / sizes.len().max(1) as f64;
+        return vec![share; sizes.len()];
+    }
+    sizes.into_iter().map(|size| size * 100. / total).collect()
+}
+
+// The stored sizes, if there is one for every panel
+fn stored_sizes(storage_key: &str, keys: &[String]) -> Option<Vec<f64>> {
+    let stored = LocalStorage::get::<Vec<(String, f64)>>(storage_key).ok()?;
+    let sizes = keys
+        .iter()
+        .map(|key| {
+            stored
+                .iter()
+                .find(|(stored_key, _)| stored_key == key)
+                .map(|(_, size)| *size)
+        })
+        .collect::<Option<Vec<f64>>>()?;
+    Some(normalize(sizes))
+}
+
+// Moves the gutter after panel `gutter` by `delta` percent, trading size between the panels on
+// either side of it within their limits. A collapsible panel dragged below half its minimum
+// collapses.
+fn resize(sizes: &[f64], panels: &[Rc<PanelProps>], gutter: usize, delta: f64) -> Vec<f64> {
+    let (before, after) = (&panels[gutter], &panels[gutter + 1]);
+    let total = sizes[gutter] + sizes[gutter + 1];
+    let wanted = sizes[gutter] + delta;
+    let size = if before.collapsible && collapses(before, wanted) {
+        before.collapsed_size
+    } else if after.collapsible && collapses(after, total - wanted) {
+        total - after.collapsed_size
+    } else {
+        let low = before.min_size.max(total - after.max_size);
+        let high = before.max_size.min(total - after.min_size);
+        wanted.min(high).max(low)
+    };
+    let size = size.clamp(0., total);
+    let mut sizes = sizes.to_vec();
+    sizes[gutter] = size;
+    sizes[gutter + 1] = total - size;
+    sizes
+}
+
+fn collapses(panel: &PanelProps, size: f64) -> bool {
+    size < panel.min_size / 2. || size <= panel.collapsed_size
+}
+
+// The move of gutter `gutter` collapsing or expanding the collapsible panel before it, else the
+// one after it
+fn toggle_delta(sizes: &[f64], panels: &[Rc<PanelProps>], gutter: usize) -> f64 {
+    let expanded = |panel: &PanelProps| {
+        panel
+            .default_size
+            .unwrap_or(100. / panels.len() as f64)
+            .max(panel.min_size)
+    };
+    let collapsed =
+        |panel: &PanelProps, size: f64| (size - panel.collapsed_size).abs() < f64::EPSILON;
+    let (before, after) = (&panels[gutter], &panels[gutter + 1]);
+    if before.collapsible {
+        if collapsed(before, sizes[gutter]) {
+            expanded(before) - sizes[gutter]
+        } else {
+            before.collapsed_size - sizes[gutter]
+        }
+    } else if after.collapsible {
+        if collapsed(after, sizes[gutter + 1]) {
+            sizes[gutter + 1] - expanded(after)
+        } else {
+            sizes[gutter + 1] - after.collapsed_size
+        }
+    } else {
+        0.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn panel(default_size: Option<f64>, min_size: f64, max_size: f64) -> Rc<PanelProps> {
+        Rc::new(PanelProps {
+            id: None,
+            default_size,
+            min_size,
+            max_size,
+            collapsible: false,
+            collapsed_size: 0.,
+            class: Classes::new(),
+            children: Children::default(),
+        })
+    }
+
+    fn collapsible(min_size: f64, collapsed_size: f64) -> Rc<PanelProps> {
+        Rc::new(PanelProps {
+            collapsible: true,
+            collapsed_size,
+            ..Rc::into_inner(panel(None, min_size, 100.)).unwrap()
+        })
+    }
+
+    #[track_caller]
+    fn assert_sizes(actual: Vec<f64>, expected: &[f64]) {
+        assert_eq!(
+            actual.len(),
+            expected.len(),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+        for (actual_size, expected_size) in actual.iter().zip(expected) {
+            assert!(
+                (actual_size - expected_size).abs() < 1e-9,
+                "{:?} != {:?}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn default_sizes_share_what_is_left() {
+        let panels = [
+            panel(Some(40.), 0., 100.),
+            panel(None, 0., 100.),
+            panel(None, 0., 100.),
+        ];
+        assert_sizes(default_sizes(&panels), &[40., 30., 30.]);
+
+        let panels = [panel(Some(30.), 0., 100.), panel(Some(10.), 0., 100.)];
+        assert_sizes(default_sizes(&panels), &[75., 25.]);
+
+        let panels = [
+            panel(Some(80.), 0., 100.),
+            panel(Some(40.), 0., 100.),
+            panel(None, 0., 100.),
+        ];
+        assert_sizes(default_sizes(&panels), &[200. / 3., 100. / 3., 0.]);
+    }
+
+    #[test]
+    fn normalize_scales_to_100() {
+        assert_sizes(normalize(vec![1., 3.]), &[25., 75.]);
+        assert_sizes(normalize(vec![60., 60.]), &[50., 50.]);
+        assert_sizes(normalize(vec![0., 0., 0., 0.]), &[25., 25., 25., 25.]);
+        assert_sizes(normalize(Vec::new()), &[]);
+    }
+
+    #[test]
+    fn resize_clamps_to_the_limits() {
+        let panels = [panel(None, 20., 60.), panel(None, 30., 100.)];
+        assert_sizes(resize(&[50., 50.], &panels, 0, 5.), &[55., 45.]);
+        assert_sizes(resize(&[50., 50.], &panels, 0, -100.), &[20., 80.]);
+        assert_sizes(resize(&[50., 50.], &panels, 0, 100.), &[60., 40.]);
+
+        let panels = [panel(None, 0., 100.), panel(None, 30., 100.)];
+        assert_sizes(resize(&[50., 50.], &panels, 0, 40.), &[70., 30.]);
+    }
+
+    #[test]
+    fn resize_only_moves_its_gutter() {
+        let panels = [
+            panel(None, 0., 100.),
+            panel(None, 10., 100.),
+            panel(None, 0., 100.),
+        ];
+        assert_sizes(resize(&[20., 30., 50.], &panels, 1, -10.), &[20., 20., 60.]);
+        assert_sizes(
+            resize(&[20., 30., 50.], &panels, 1, -100.),
+            &[20., 10., 70.],
+        );
+    }
+
+    #[test]
+    fn resize_collapses_below_half_the_minimum() {
+        let panels = [collapsible(20., 0.), panel(None, 0., 100.)];
+        assert_sizes(resize(&[30., 70.], &panels, 0, -15.), &[20., 80.]);
+        assert_sizes(resize(&[30., 70.], &panels, 0, -25.), &[0., 100.]);
+
+        let panels = [panel(None, 0., 100.), collapsible(20., 4.)];
+        assert_sizes(resize(&[70., 30.], &panels, 0, 25.), &[96., 4.]);
+    }
+
+    #[test]
+    fn collapse_threshold() {
+        let panel = collapsible(20., 4.);
+        assert!(collapses(&panel, 9.));
+        assert!(!collapses(&panel, 10.));
+        assert!(collapses(&collapsible(0., 4.), 4.));
+    }
+
+    #[test]
+    fn toggle_collapses_and_expands() {
+        let panels = [collapsible(20., 0.), panel(None, 0., 100.)];
+        let delta = toggle_delta(&[40., 60.], &panels, 0);
+        assert_sizes(resize(&[40., 60.], &panels, 0, delta), &[0., 100.]);
+        let delta = toggle_delta(&[0., 100.], &panels, 0);
+        assert_sizes(resize(&[0., 100.], &panels, 0, delta), &[50., 50.]);
+
+        let panels = [panel(None, 0., 100.), collapsible(10., 0.)];
+        let delta = toggle_delta(&[70., 30.], &panels, 0);
+        assert_sizes(resize(&[70., 30.], &panels, 0, delta), &[100., 0.]);
+
+        let panels = [panel(None, 0., 100.), panel(None, 0., 100.)];
+        assert_eq!(toggle_delta(&[50., 50.], &panels, 0), 0.);
+    }
+}
@@ -4,8 +4,6 @@ use crate::config::{get_brandguide, BrandGuideType};
 #[cfg(feature = "ThemeProvider")]
 use crate::config::{use_brandguide, BrandGuideType};
 use std::rc::Rc;
-use wasm_bindgen::closure::Closure;
-use wasm_bindgen::JsCast;
 use web_sys::HtmlDivElement;
 use yew::events::PointerEvent;
 use yew::prelude::*;
@@ -64,24 +62,6 @@ pub struct ResizableInner {
     mouse_position: (i32, i32),
     coordinates: Option<(f64, f64, f64, f64)>,
     scale: f64,
-
-    move_closure: Closure<dyn FnMut(PointerEvent)>,
-}
-
-impl Drop for ResizableInner {
-    fn drop(&mut self) {
-        let container = self.container_ref.cast::<HtmlDivElement>();
-        if let Some(container) = container {
-            if let Some(window) = web_sys::window() {
-                window
-                    .remove_event_listener_with_callback(
-                        "pointermove",
-                        self.move_closure.as_ref().unchecked_ref(),
-                    )
-                    .unwrap();
-            }
-        }
-    }
 }
 
 pub enum Msg {
@@ -94,7 +74,7 @@ impl Component for ResizableInner {
     type Message = Msg;
     type Properties = ResizableInnerProps;
 
-    fn create(ctx: &Context<Self>) -> Self {
+    fn create(_ctx: &Context<Self>) -> Self {
         Self {
             container_ref: NodeRef::default(),
             div_ref: NodeRef::default(),
@@ -103,13 +83,6 @@ impl Component for ResizableInner {
             mouse_position: (0, 0),
             coordinates: None,
             scale: 1.,
-            move_closure: {
-                let link = ctx.link().clone();
-
-                Closure::wrap(Box::new(move |event: web_sys::PointerEvent| {
-                    link.send_message(Msg::PointerMove(event));
-                }) as Box<dyn FnMut(_)>)
-            },
         }
     }
 
@@ -129,15 +102,6 @@ impl Component for ResizableInner {
                 let y = event.client_y() - rect.y() as i32;
                 self.mode = mode.clone();
                 self.mouse_position = (x, y);
-
-                let window = web_sys::window().expect("no global `window` exists");
-
-                window
-                    .add_event_listener_with_callback(
-                        "pointermove",
-                        self.move_closure.as_ref().unchecked_ref(),
-                    )
-                    .unwrap();
             }
 
             Msg::PointerMove(event) => {
@@ -190,14 +154,6 @@ impl Component for ResizableInner {
             }
             Msg::PointerMoveEnd => {
                 self.mode = Mode::View;
-
-                let window = web_sys::window().expect("no global `window` exists");
-                window
-                    .remove_event_listener_with_callback(
-                        "pointermove",
-                        self.move_closure.as_ref().unchecked_ref(),
-                    )
-                    .unwrap();
             }
         }
 
@@ -233,41 +189,49 @@ impl Component for ResizableInner {
                         class={classes!(if props.north_west { &brandguide.resizable_handle_visible } else { &brandguide.resizable_handle_hidden }, &brandguide.resizable_handle_nw)}
                         onstart={ctx.link().callback(|e| Msg::PointerMoveStart(Mode::ResizeTopLeft, e))}
                         onstop={ctx.link().callback(|_| Msg::PointerMoveEnd )}
+                        onmove={ctx.link().callback(Msg::PointerMove)}
                     />
                     <DragPoint
                         class={classes!(if props.north_east { &brandguide.resizable_handle_visible } else { &brandguide.resizable_handle_hidden }, &brandguide.resizable_handle_ne)}
                         onstart={ctx.link().callback(|e| Msg::PointerMoveStart(Mode::ResizeTopRight, e))}
                         onstop={ctx.link().callback(|_| Msg::PointerMoveEnd)}
+                        onmove={ctx.link().callback(Msg::PointerMove)}
                     />
                     <DragPoint
                         class={classes!(if props.south_west { &brandguide.resizable_handle_visible } else { &brandguide.resizable_handle_hidden }, &brandguide.resizable_handle_sw)}
                         onstart={ctx.link().callback(|e| Msg::PointerMoveStart(Mode::ResizeBottomLeft, e))}
                         onstop={ctx.link().callback(|_| Msg::PointerMoveEnd)}
+                        onmove={ctx.link().callback(Msg::PointerMove)}
                     />
                     <DragPoint
                         class={classes!(if props.south_east { &brandguide.resizable_handle_visible } else { &brandguide.resizable_handle_hidden }, &brandguide.resizable_handle_se)}
                         onstart={ctx.link().callback(|e| Msg::PointerMoveStart(Mode::ResizeBottomRight,e ))}
                         onstop={ctx.link().callback(|_| Msg::PointerMoveEnd)}
+                        onmove={ctx.link().callback(Msg::PointerMove)}
                     />
                     <DragPoint
                         class={classes!(if props.north { &brandguide.resizable_handle_visible } else { &brandguide.resizable_handle_hidden }, &brandguide.resizable_handle_n)}
                         onstart={ctx.link().callback(|e| Msg::PointerMoveStart(Mode::ResizeTop,e))}
                         onstop={ctx.link().callback(|_| Msg::PointerMoveEnd)}
+                        onmove={ctx.link().callback(Msg::PointerMove)}
                     />
                     <DragPoint
                         class={classes!(if props.south { &brandguide.resizable_handle_visible } else { &brandguide.resizable_handle_hidden }, &brandguide.resizable_handle_s)}
                         onstart={ctx.link().callback(|e| Msg::PointerMoveStart(Mode::ResizeBottom,e))}
                         onstop={ctx.link().callback(|_| Msg::PointerMoveEnd)}
+                        onmove={ctx.link().callback(Msg::PointerMove)}
                     />
                     <DragPoint
                         class={classes!(if props.west { &brandguide.resizable_handle_visible } else { &brandguide.resizable_handle_hidden }, &brandguide.resizable_handle_w)}
                         onstart={ctx.link().callback(|e| Msg::PointerMoveStart(Mode::ResizeLeft,e))}
                         onstop={ctx.link().callback(|_| Msg::PointerMoveEnd)}
+                        onmove={ctx.link().callback(Msg::PointerMove)}
                     />
                     <DragPoint
                         class={classes!(if props.east { &brandguide.resizable_handle_visible } else { &brandguide.resizable_handle_hidden }, &brandguide.resizable_handle_e)}
                         onstart={ctx.link().callback(|e| Msg::PointerMoveStart(Mode::ResizeRight,e))}
                         onstop={ctx.link().callback(|_| Msg::PointerMoveEnd)}
+                        onmove={ctx.link().callback(Msg::PointerMove)}
                     />
                 </div>
             </div>
@@ -1,5 +1,6 @@
+use crate::components::layout::layout_context::{LayoutAction, LayoutContext};
 use crate::components::layout::multicol_sidebar::{MultiColumnSidebar, SidebarColumn};
-use crate::components::layout::layout_context::{LayoutContext, LayoutAction};
+use crate::utils::tw_merge;
 use gloo_console as console;
 use yew::prelude::*; // Import LayoutContext and SidebarPosition
 use yew_router::prelude::use_location;
@@ -235,12 +236,15 @@ pub struct SidebarProps {
     pub header: Option<Html>,
     #[prop_or_default]
     pub footer: Option<Html>,
+    /// Whether the sidebar can be resized by dragging its inner edge.
+    #[prop_or_default]
+    pub resizable: bool,
 }
 
 #[function_component(Sidebar)]
 pub fn sidebar(props: &SidebarProps) -> Html {
     html! {
-      <MultiColumnSidebar curtain_content={props.curtain_content.clone()}>
+      <MultiColumnSidebar curtain_content={props.curtain_content.clone()} resizable={props.resizable}>
         <SidebarColumn header={props.header.clone()} footer={props.footer.clone()}>
           {props.children.clone()}
         </SidebarColumn>
@@ -254,6 +258,9 @@ pub struct MobileMenuButtonProps {
     pub children: Children,
     #[prop_or_default]
     pub class: Classes,
+    /// Name read by screen readers, since the button usually only shows an icon
+    #[prop_or(AttrValue::from("Open menu"))]
+    pub aria_label: AttrValue,
 }
 
 #[function_component(MobileMenuButton)]
@@ -268,7 +275,13 @@ pub fn mobile_menu_button(props: &MobileMenuButtonProps) -> Html {
     };
     // TODO: Use Button instead
     html! {
-        <button class={classes!("lg:hidden", props.class.clone())} onclick={open_mobile_menu}>
+        <button
+            type="button"
+            class={tw_merge(classes!("lg:hidden", props.class.clone()))}
+            aria-label={props.aria_label.clone()}
+            aria-expanded={mobile_menu_open.to_string()}
+            onclick={open_mobile_menu}
+        >
             {props.children.clone()}
         </button>
     }
@@ -295,7 +308,7 @@ pub fn sidebar_header(props: &SidebarHeaderProps) -> Html {
         "dark:bg-zinc-900"
     );
 
-    let combined_classes = classes!(default_classes, props.class.clone());
+    let combined_classes = tw_merge(classes!(default_classes, props.class.clone()));
 
     html! {
         <div
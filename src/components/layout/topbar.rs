@@ -1,3 +1,4 @@
+use crate::utils::tw_merge;
 use yew::prelude::*;
 // <div class="sticky top-0 z-40 flex h-16 shrink-0 items-center gap-x-4 border-b border-gray-200 bg-white px-4 shadow-sm sm:gap-x-6 sm:px-6 lg:px-8">
 
@@ -27,7 +28,7 @@ pub fn topbar(props: &TopbarProps) -> Html {
     };
 
     html!(
-      <div class={classes!(props.class.clone(), style, "shrink-0", "top-0","z-10","flex","h-16","border-b","border-zinc-200","dark:border-zinc-800","bg-white","dark:bg-zinc-900")}>
+      <div class={tw_merge(classes!(style, "shrink-0", "top-0","z-10","flex","h-16","border-b","border-zinc-200","dark:border-zinc-800","bg-white","dark:bg-zinc-900", props.class.clone()))}>
         {for props.children.iter()}
       </div>
     )
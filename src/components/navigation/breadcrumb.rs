@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 use yew_router::prelude::{use_navigator, Link};
 use yew_router::Routable;
@@ -40,11 +41,11 @@ pub fn BreadcrumbRouteItem<R: Routable + 'static>(props: &BreadcrumbRouteItemPro
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
 
-    let combined_class = classes!(
+    let combined_class = tw_merge(classes!(
         "transition-colors",
         "hover:text-foreground",
         props.class.clone()
-    );
+    ));
 
     html! {
         <li class={&brandguide.breadcrumb_item}>
@@ -62,12 +63,16 @@ pub fn breadcrumb_item(props: &BreadcrumbItemProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
 
-    let link_class = classes!(
+    let link_class = tw_merge(classes!(
         "transition-colors",
         "hover:text-foreground",
         props.class.clone()
-    );
-    let span_class = classes!("font-normal", "text-foreground", props.class.clone());
+    ));
+    let span_class = tw_merge(classes!(
+        "font-normal",
+        "text-foreground",
+        props.class.clone()
+    ));
 
     html! {
         <li class={&brandguide.breadcrumb_item}>
@@ -94,7 +99,7 @@ pub fn breadcrumb(props: &BreadcrumbProps) -> Html {
         </svg>
     });
 
-    let nav_class = classes!(&brandguide.breadcrumb_nav, props.class.clone());
+    let nav_class = tw_merge(classes!(&brandguide.breadcrumb_nav, props.class.clone()));
 
     html! {
         <nav aria-label="breadcrumb" class={nav_class}>
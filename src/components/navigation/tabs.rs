@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use std::rc::Rc;
 use yew::function_component;
 use yew::html;
@@ -54,7 +55,7 @@ pub fn tabs(props: &TabsProps) -> Html {
 
     html! {
         <ContextProvider<Rc<TabsState>> context={state}>
-            <div class={classes!(props.class.clone(), &brandguide.tabs_container)}>
+            <div class={tw_merge(classes!(&brandguide.tabs_container, props.class.clone()))}>
                 { for props.children.iter() }
             </div>
         </ContextProvider<Rc<TabsState>>>
@@ -80,7 +81,11 @@ pub fn tabs_list(props: &TabsListProps) -> Html {
         TabsDirection::Column => &brandguide.tabs_list_column,
     };
     html! {
-        <div class={classes!(props.class.clone(), class, &brandguide.tabs_list)}>
+        <div
+            role="tablist"
+            aria-orientation={(state.direction == TabsDirection::Column).then_some("vertical")}
+            class={tw_merge(classes!(class, &brandguide.tabs_list, props.class.clone()))}
+        >
             { for props.children.iter() }
         </div>
     }
@@ -114,12 +119,13 @@ pub fn tabs_trigger(props: &TabsTriggerProps) -> Html {
         <button
             type="button"
             role="tab"
+            aria-selected={is_active.to_string()}
             onclick={onclick}
-            class={classes!(
-                props.class.clone(),
+            class={tw_merge(classes!(
                 if is_active { &brandguide.tabs_trigger_active } else { &brandguide.tabs_trigger_inactive },
-                &brandguide.tabs_trigger
-            )}
+                &brandguide.tabs_trigger,
+                props.class.clone()
+            ))}
         >
             { for props.children.iter() }
         </button>
@@ -147,7 +153,7 @@ pub fn tabs_content(props: &TabsContentProps) -> Html {
     }
 
     html! {
-        <div class={classes!(props.class.clone(), &brandguide.tabs_content)}>
+        <div role="tabpanel" class={tw_merge(classes!(&brandguide.tabs_content, props.class.clone()))}>
             { for props.children.iter() }
         </div>
     }
@@ -3,6 +3,7 @@ use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::{use_brandguide, use_set_brandguide};
 use crate::config::{BrandGuideType, ClassesStr};
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -28,7 +29,7 @@ pub fn accordion(props: &AccordionProps) -> Html {
     };
 
     html! {
-        <div class={classes!(&brandguide.accordion_container, props.class.clone())}>
+        <div class={tw_merge(classes!(&brandguide.accordion_container, props.class.clone()))}>
             <div class={classes!(&brandguide.accordion_header)} {onclick}>
                 <h2 class={&brandguide.accordion_title}>{ &props.title }</h2>
             </div>
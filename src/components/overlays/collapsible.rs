@@ -2,6 +2,7 @@
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::tw_merge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -35,7 +36,7 @@ pub fn collapsible(props: &CollapsibleProps) -> Html {
     };
 
     html! {
-        <div class={classes!(&brandguide.collapsible_container, props.class.clone())}>
+        <div class={tw_merge(classes!(&brandguide.collapsible_container, props.class.clone()))}>
             { for props.children.iter() }
         </div>
     }
@@ -82,7 +83,7 @@ pub fn collapsible_content(props: &CollapsibleContentProps) -> Html {
     let brandguide = get_brandguide();
     html! {
         if props.is_open {
-            <div class={classes!(&brandguide.collapsible_content, props.class.clone())}>
+            <div class={tw_merge(classes!(&brandguide.collapsible_content, props.class.clone()))}>
                 { for props.children.iter() }
             </div>
         }
@@ -104,7 +105,7 @@ pub fn collapsible_header(props: &CollapsibleHeaderProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <div class={classes!(&brandguide.collapsible_header, props.class.clone())}>
+        <div class={tw_merge(classes!(&brandguide.collapsible_header, props.class.clone()))}>
             { for props.children.iter() }
         </div>
     }
@@ -125,7 +126,7 @@ pub fn collapsible_title(props: &CollapsibleTitleProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <h4 class={classes!(&brandguide.collapsible_title, props.class.clone())}>
+        <h4 class={tw_merge(classes!(&brandguide.collapsible_title, props.class.clone()))}>
             { for props.children.iter() }
         </h4>
     }
@@ -146,7 +147,7 @@ pub fn collapsible_item(props: &CollapsibleItemProps) -> Html {
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
     html! {
-        <div class={classes!(&brandguide.collapsible_item, props.class.clone())}>
+        <div class={tw_merge(classes!(&brandguide.collapsible_item, props.class.clone()))}>
             { for props.children.iter() }
         </div>
     }
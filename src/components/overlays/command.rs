@@ -1,23 +1,119 @@
+use crate::components::utils::use_unique_id;
 #[cfg(not(feature = "ThemeProvider"))]
 use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
+use crate::utils::{fuzzy_match, highlight, tw_merge};
+use gloo::events::EventListener;
+use gloo_utils::document;
 use wasm_bindgen::JsCast;
-use web_sys::KeyboardEvent;
+use web_sys::{HtmlElement, HtmlInputElement, KeyboardEvent};
 use yew::prelude::*;
 
+/// An entry of the command palette.
+#[derive(Clone, PartialEq)]
+pub struct CommandItem<T: Clone + PartialEq> {
+    pub value: T,
+    pub label: String,
+    /// Comma-separated search terms matched in addition to the label.
+    pub keywords: String,
+    pub icon: Option<Html>,
+    /// Heading the item is listed under.
+    pub group: Option<String>,
+    /// Pinned items are listed first while the search is empty.
+    pub pinned: bool,
+    /// Choosing an item with children opens them as a nested page instead of selecting it.
+    pub children: Vec<CommandItem<T>>,
+}
+
+impl<T: Clone + PartialEq> CommandItem<T> {
+    pub fn new(value: T, label: impl Into<String>) -> Self {
+        CommandItem {
+            value,
+            label: label.into(),
+            keywords: String::new(),
+            icon: None,
+            group: None,
+            pinned: false,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn keywords(mut self, keywords: impl Into<String>) -> Self {
+        self.keywords = keywords.into();
+        self
+    }
+
+    pub fn icon(mut self, icon: Html) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    pub fn pinned(mut self) -> Self {
+        self.pinned = true;
+        self
+    }
+
+    pub fn children(mut self, children: Vec<CommandItem<T>>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl<T: Clone + PartialEq> From<(T, String, String, Option<Html>)> for CommandItem<T> {
+    fn from((value, keywords, label, icon): (T, String, String, Option<Html>)) -> Self {
+        CommandItem {
+            icon,
+            keywords,
+            ..CommandItem::new(value, label)
+        }
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct CommandProps<T: Clone + PartialEq + 'static> {
     #[prop_or_default]
     pub placeholder: String,
     #[prop_or_default]
     pub options: Vec<(T, String, String, Option<Html>)>, // (value, keywords, label, icon)
+    /// Listed after `options`. Use these for groups, pinned items and nested pages.
+    #[prop_or_default]
+    pub items: Vec<CommandItem<T>>,
     #[prop_or_default]
     pub on_select: Callback<T>,
+    /// Recently selected values, most recent first.
+    #[prop_or_default]
+    pub recent: Vec<T>,
+    /// How many selections are remembered as recent. `0` turns the recent list off.
+    #[prop_or(5)]
+    pub max_recent: usize,
+    #[prop_or_default]
+    pub on_recent_change: Callback<Vec<T>>,
+    #[prop_or(AttrValue::from("Pinned"))]
+    pub pinned_heading: AttrValue,
+    #[prop_or(AttrValue::from("Recent"))]
+    pub recent_heading: AttrValue,
+    #[prop_or(AttrValue::from("No results available"))]
+    pub empty_text: AttrValue,
+    /// Always show the list instead of opening it when the input is clicked.
+    #[prop_or_default]
+    pub expanded: bool,
+    #[prop_or_default]
+    pub autofocus: bool,
     #[prop_or_default]
     pub class: Classes,
 }
 
+struct Section<T: Clone + PartialEq> {
+    heading: Option<String>,
+    items: Vec<(CommandItem<T>, Vec<usize>)>,
+}
+
 #[function_component(Command)]
 pub fn command<T: Clone + PartialEq + 'static>(props: &CommandProps<T>) -> Html
 where
@@ -29,19 +125,111 @@ where
     let brandguide = get_brandguide();
     let is_open = use_state(|| false);
     let value = use_state(|| String::new());
-    let filtered_options = use_state(|| props.options.clone());
+    // Items whose children are shown, outermost first.
+    let pages = use_state(Vec::<CommandItem<T>>::new);
+    let recent = use_state(|| props.recent.clone());
     let div_ref = use_node_ref();
     let input_ref = use_node_ref();
+    let list_ref = use_node_ref();
     let selected_index = use_state(|| 0);
+    let base_id = use_unique_id("command");
+    let show_list = props.expanded || *is_open;
+
+    {
+        let recent = recent.clone();
+        use_effect_with(props.recent.clone(), move |values| {
+            recent.set(values.clone());
+        });
+    }
+
+    {
+        let input_ref = input_ref.clone();
+        use_effect_with(props.autofocus, move |autofocus| {
+            if *autofocus {
+                if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                    let _ = input.focus();
+                }
+            }
+        });
+    }
+
+    let root: Vec<CommandItem<T>> = props
+        .options
+        .iter()
+        .cloned()
+        .map(CommandItem::from)
+        .chain(props.items.iter().cloned())
+        .collect();
+    let current = pages.last().map(|page| &page.children).unwrap_or(&root);
+    let sections = build_sections(
+        current,
+        value.trim(),
+        if props.max_recent > 0 { &recent } else { &[] },
+        &props.pinned_heading,
+        &props.recent_heading,
+    );
+    let flat: Vec<CommandItem<T>> = sections
+        .iter()
+        .flat_map(|section| section.items.iter().map(|(item, _)| item.clone()))
+        .collect();
+    let active = (!flat.is_empty()).then(|| (*selected_index).min(flat.len() - 1));
+
+    // Keep the highlighted item visible inside the scrollable list.
+    {
+        let list_ref = list_ref.clone();
+        let base_id = base_id.clone();
+        use_effect_with((active, show_list), move |(active, _)| {
+            if let (Some(index), Some(list)) = (active, list_ref.cast::<web_sys::Element>()) {
+                if let Some(item) = document().get_element_by_id(&item_id(&base_id, *index)) {
+                    let list_rect = list.get_bounding_client_rect();
+                    let item_rect = item.get_bounding_client_rect();
+                    if item_rect.top() < list_rect.top() {
+                        list.set_scroll_top(
+                            list.scroll_top() - (list_rect.top() - item_rect.top()).ceil() as i32,
+                        );
+                    } else if item_rect.bottom() > list_rect.bottom() {
+                        list.set_scroll_top(
+                            list.scroll_top()
+                                + (item_rect.bottom() - list_rect.bottom()).ceil() as i32,
+                        );
+                    }
+                }
+            }
+        });
+    }
 
     let on_select = {
         let value = value.clone();
         let is_open = is_open.clone();
+        let pages = pages.clone();
+        let recent = recent.clone();
+        let selected_index = selected_index.clone();
+        let input_ref = input_ref.clone();
         let on_select = props.on_select.clone();
-        Callback::from(move |selected_value: T| {
+        let on_recent_change = props.on_recent_change.clone();
+        let max_recent = props.max_recent;
+        Callback::from(move |item: CommandItem<T>| {
             value.set(String::new());
+            selected_index.set(0);
+            if !item.children.is_empty() {
+                let mut stack = (*pages).clone();
+                stack.push(item);
+                pages.set(stack);
+                if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                    let _ = input.focus();
+                }
+                return;
+            }
             is_open.set(false);
-            on_select.emit(selected_value);
+            pages.set(Vec::new());
+            if max_recent > 0 {
+                let mut values = vec![item.value.clone()];
+                values.extend(recent.iter().filter(|v| **v != item.value).cloned());
+                values.truncate(max_recent);
+                recent.set(values.clone());
+                on_recent_change.emit(values);
+            }
+            on_select.emit(item.value);
         })
     };
 
@@ -79,73 +267,83 @@ where
     let oninput = {
         let is_open = is_open.clone();
         let value = value.clone();
-        let filtered_options = filtered_options.clone();
-        let options = props.options.clone();
         let selected_index = selected_index.clone();
         Callback::from(move |e: InputEvent| {
             let input = e.target_unchecked_into::<web_sys::HtmlInputElement>();
             if !*is_open {
                 is_open.set(true);
             }
-            let new_value = input.value().to_lowercase();
-            value.set(new_value.clone());
-            let new_filtered = options
-                .iter()
-                .filter(|(_, keywords, label, _)| {
-                    keywords.to_lowercase().contains(&new_value)
-                        || label.to_lowercase().contains(&new_value)
-                })
-                .cloned()
-                .collect::<Vec<_>>();
-            filtered_options.set(new_filtered);
+            value.set(input.value());
             selected_index.set(0);
         })
     };
 
     let onkeydown = {
         let is_open = is_open.clone();
-        let filtered_options = filtered_options.clone();
+        let value = value.clone();
+        let pages = pages.clone();
         let selected_index = selected_index.clone();
         let on_select = on_select.clone();
-        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
-            "Escape" => {
-                is_open.set(false);
-                e.prevent_default();
-            }
-            "Enter" => {
-                if *is_open {
-                    if let Some((val, _, _, _)) = filtered_options.get(*selected_index) {
-                        on_select.emit(val.clone());
+        let flat = flat.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let go_back = || {
+                let mut stack = (*pages).clone();
+                stack.pop();
+                pages.set(stack);
+                selected_index.set(0);
+            };
+            match e.key().as_str() {
+                "Escape" => {
+                    e.prevent_default();
+                    if !pages.is_empty() {
+                        // Leave enclosing dialogs open while stepping back.
+                        e.stop_propagation();
+                        value.set(String::new());
+                        go_back();
+                    } else {
+                        is_open.set(false);
                     }
+                }
+                "Backspace" if value.is_empty() && !pages.is_empty() => {
                     e.prevent_default();
+                    go_back();
                 }
-            }
-            "ArrowDown" => {
-                if *is_open {
-                    selected_index.set((*selected_index + 1) % filtered_options.len());
+                "Enter" if show_list => {
+                    if let Some(item) = active.and_then(|index| flat.get(index)) {
+                        on_select.emit(item.clone());
+                    }
                     e.prevent_default();
                 }
-            }
-            "ArrowUp" => {
-                if *is_open {
-                    selected_index.set(
-                        (*selected_index as usize + filtered_options.len() - 1)
-                            % filtered_options.len(),
-                    );
+                "ArrowDown" if show_list => {
+                    if let Some(index) = active {
+                        selected_index.set((index + 1) % flat.len());
+                    }
+                    e.prevent_default();
+                }
+                "ArrowUp" if show_list => {
+                    if let Some(index) = active {
+                        selected_index.set((index + flat.len() - 1) % flat.len());
+                    }
                     e.prevent_default();
                 }
+                _ => {}
             }
-            _ => {}
         })
     };
 
+    let listbox_id = format!("{}-listbox", base_id);
+    let mut position = 0;
+
     html! {
-        <div ref={div_ref} class={classes!(&brandguide.command_container, props.class.clone())} tabindex="0" onfocusout={close}>
+        <div ref={div_ref} class={tw_merge(classes!(&brandguide.command_container, props.class.clone()))} tabindex="0" onfocusout={close}>
             <div class={classes!(&brandguide.command_input_wrapper)}>
                 <svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class={classes!(&brandguide.command_icon)}>
                     <circle cx="11" cy="11" r="8"></circle>
                     <path d="m21 21-4.3-4.3"></path>
                 </svg>
+                { for pages.iter().map(|page| html! {
+                    <span class={classes!(&brandguide.command_breadcrumb)}>{ &page.label }</span>
+                }) }
                 <input
                     ref={input_ref}
                     class={classes!(&brandguide.command_input)}
@@ -153,52 +351,350 @@ where
                     autocomplete="off"
                     autocorrect="off"
                     spellcheck="false"
+                    role="combobox"
+                    aria-autocomplete="list"
+                    aria-expanded={show_list.to_string()}
+                    aria-controls={listbox_id.clone()}
+                    aria-activedescendant={active.filter(|_| show_list).map(|index| item_id(&base_id, index))}
                     onclick={toggle.clone()}
                     oninput={oninput}
                     onkeydown={onkeydown}
                     value={(*value).clone()}
                 />
             </div>
-            {
-                if *is_open {
-                    html! {
-                        <div class={classes!(&brandguide.command_list)} role="listbox">
-                            {
-                                if filtered_options.is_empty() {
+            if show_list {
+                <div ref={list_ref} id={listbox_id} class={classes!(&brandguide.command_list)} role="listbox">
+                    if flat.is_empty() {
+                        <div class={classes!(&brandguide.command_empty)} role="status">
+                            { props.empty_text.clone() }
+                        </div>
+                    }
+                    { for sections.into_iter().map(|section| {
+                        let heading_id = section
+                            .heading
+                            .as_ref()
+                            .map(|_| format!("{}-group-{}", base_id, position));
+                        html! {
+                            <div role="group" aria-labelledby={heading_id.clone()}>
+                                if let Some(heading) = &section.heading {
+                                    <div id={heading_id} class={classes!(&brandguide.command_group_heading)}>
+                                        { heading }
+                                    </div>
+                                }
+                                { for section.items.into_iter().map(|(item, matched)| {
+                                    let index = position;
+                                    position += 1;
+                                    let is_selected = active == Some(index);
+                                    let label = highlight(&item.label, &matched, classes!(&brandguide.command_match));
+                                    let icon = item.icon.clone();
+                                    let onclick = {
+                                        let on_select = on_select.clone();
+                                        Callback::from(move |_| on_select.emit(item.clone()))
+                                    };
+                                    let onmousemove = {
+                                        let selected_index = selected_index.clone();
+                                        Callback::from(move |_: MouseEvent| {
+                                            if *selected_index != index {
+                                                selected_index.set(index);
+                                            }
+                                        })
+                                    };
                                     html! {
-                                        <div class={classes!(&brandguide.command_item)}>
-                                            { "No results available" }
+                                        <div
+                                            id={item_id(&base_id, index)}
+                                            class={classes!(if is_selected { &brandguide.command_selected_item } else { &brandguide.command_item })}
+                                            {onclick}
+                                            {onmousemove}
+                                            role="option"
+                                            aria-selected={is_selected.to_string()}
+                                        >
+                                            if let Some(icon) = icon {
+                                                <span class={classes!(&brandguide.command_item_icon)}>
+                                                    {icon}
+                                                </span>
+                                            }
+                                            <span>{ label }</span>
                                         </div>
                                     }
-                                } else {
-                                    filtered_options.iter().enumerate().map(|(index, (val, _, label, icon))| {
-                                        let on_select = on_select.clone();
-                                        let val = val.clone();
-                                        let is_selected = index == *selected_index;
-                                        html! {
-                                            <div
-                                                class={classes!(if is_selected { &brandguide.command_selected_item } else { &brandguide.command_item })}
-                                                onclick={Callback::from(move |_| on_select.emit(val.clone()))}
-                                                role="option"
-                                                aria-selected={is_selected.to_string()}
-                                            >
-                                                if let Some(icon) = icon {
-                                                    <span class={classes!(&brandguide.command_item_icon)}>
-                                                        {icon.clone()}
-                                                    </span>
-                                                }
-                                                <span>{ label }</span>
-                                            </div>
-                                        }
-                                    }).collect::<Html>()
-                                }
-                            }
-                        </div>
-                    }
-                } else {
-                    html! {}
+                                }) }
+                            </div>
+                        }
+                    }) }
+                </div>
+            }
+        </div>
+    }
+}
+
+fn item_id(base_id: &str, index: usize) -> String {
+    format!("{}-item-{}", base_id, index)
+}
+
+// With an empty query, lists pinned and recent items first and everything else by group.
+// Otherwise lists matching items by score, with groups ordered by their best match.
+fn build_sections<T: Clone + PartialEq>(
+    items: &[CommandItem<T>],
+    query: &str,
+    recent: &[T],
+    pinned_heading: &str,
+    recent_heading: &str,
+) -> Vec<Section<T>> {
+    let mut sections = Vec::new();
+    let mut rest = Vec::new();
+    if query.is_empty() {
+        let pinned: Vec<_> = items
+            .iter()
+            .filter(|item| item.pinned)
+            .map(|item| (item.clone(), Vec::new()))
+            .collect();
+        let recent: Vec<_> = recent
+            .iter()
+            .filter_map(|value| {
+                items
+                    .iter()
+                    .find(|item| item.value == *value && !item.pinned && item.children.is_empty())
+            })
+            .map(|item| (item.clone(), Vec::new()))
+            .collect();
+        rest.extend(
+            items
+                .iter()
+                .filter(|item| {
+                    !item.pinned && !recent.iter().any(|(shown, _)| shown.value == item.value)
+                })
+                .map(|item| (item.clone(), Vec::new())),
+        );
+        for (heading, items) in [(pinned_heading, pinned), (recent_heading, recent)] {
+            if !items.is_empty() {
+                sections.push(Section {
+                    heading: Some(heading.to_string()),
+                    items,
+                });
+            }
+        }
+    } else {
+        let mut scored: Vec<_> = items
+            .iter()
+            .filter_map(|item| {
+                score_item(query, item).map(|(score, matched)| (score, item, matched))
+            })
+            .collect();
+        scored.sort_by_key(|(score, _, _)| std::cmp::Reverse(*score));
+        rest.extend(
+            scored
+                .into_iter()
+                .map(|(_, item, matched)| (item.clone(), matched)),
+        );
+    }
+    let grouped_from = sections.len();
+    for (item, matched) in rest {
+        match sections[grouped_from..]
+            .iter_mut()
+            .find(|section| section.heading == item.group)
+        {
+            Some(section) => section.items.push((item, matched)),
+            None => sections.push(Section {
+                heading: item.group.clone(),
+                items: vec![(item, matched)],
+            }),
+        }
+    }
+    sections
+}
+
+// Scores the label and each keyword, preferring the label. Only label matches are highlighted.
+fn score_item<T: Clone + PartialEq>(
+    query: &str,
+    item: &CommandItem<T>,
+) -> Option<(i32, Vec<usize>)> {
+    let label = fuzzy_match(query, &item.label);
+    let keyword = item
+        .keywords
+        .split(',')
+        .filter_map(|keyword| fuzzy_match(query, keyword.trim()))
+        .map(|(score, _)| score - 2)
+        .max();
+    match (label, keyword) {
+        (Some((score, _)), Some(keyword)) if keyword > score => Some((keyword, Vec::new())),
+        (Some(label), _) => Some(label),
+        (None, keyword) => keyword.map(|score| (score, Vec::new())),
+    }
+}
+
+/// Open state of the command palette, provided by `CommandProvider`.
+#[derive(Clone, PartialEq)]
+pub struct CommandContext {
+    pub is_open: bool,
+    pub open: Callback<()>,
+    pub close: Callback<()>,
+    pub toggle: Callback<()>,
+}
+
+#[hook]
+pub fn use_command_palette() -> CommandContext {
+    use_context::<CommandContext>().expect("no CommandProvider found")
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CommandProviderProps {
+    pub children: Children,
+    /// Key that opens the palette together with Ctrl, or Cmd on macOS.
+    #[prop_or(AttrValue::from("k"))]
+    pub shortcut_key: AttrValue,
+}
+
+/// Opens the `CommandDialog` beneath it on Ctrl+K or Cmd+K.
+#[function_component(CommandProvider)]
+pub fn command_provider(props: &CommandProviderProps) -> Html {
+    let is_open = use_state(|| false);
+    // Element focused before the palette opened, refocused when it closes.
+    let restore_focus = use_mut_ref(|| None::<HtmlElement>);
+
+    let open = {
+        let is_open = is_open.clone();
+        let restore_focus = restore_focus.clone();
+        Callback::from(move |_| {
+            if !*is_open {
+                *restore_focus.borrow_mut() = document()
+                    .active_element()
+                    .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+                is_open.set(true);
+            }
+        })
+    };
+
+    let close = {
+        let is_open = is_open.clone();
+        Callback::from(move |_| {
+            if *is_open {
+                is_open.set(false);
+                if let Some(element) = restore_focus.borrow_mut().take() {
+                    let _ = element.focus();
                 }
             }
+        })
+    };
+
+    let toggle = {
+        let is_open = *is_open;
+        let open = open.clone();
+        let close = close.clone();
+        Callback::from(move |_| {
+            if is_open {
+                close.emit(())
+            } else {
+                open.emit(())
+            }
+        })
+    };
+
+    // The listener is added once and reads the latest toggle and shortcut from here.
+    let shortcut = use_mut_ref(|| (toggle.clone(), props.shortcut_key.clone()));
+    *shortcut.borrow_mut() = (toggle.clone(), props.shortcut_key.clone());
+    use_effect_with((), move |_| {
+        let listener = EventListener::new(&document(), "keydown", move |event| {
+            let event: &KeyboardEvent = event.unchecked_ref();
+            let (toggle, shortcut_key) = shortcut.borrow().clone();
+            if (event.ctrl_key() || event.meta_key())
+                && event.key().eq_ignore_ascii_case(&shortcut_key)
+            {
+                event.prevent_default();
+                toggle.emit(());
+            }
+        });
+        move || drop(listener)
+    });
+
+    let context = CommandContext {
+        is_open: *is_open,
+        open,
+        close,
+        toggle,
+    };
+
+    html! {
+        <ContextProvider<CommandContext> context={context}>
+            { for props.children.iter() }
+        </ContextProvider<CommandContext>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CommandDialogProps<T: Clone + PartialEq + 'static> {
+    #[prop_or_default]
+    pub placeholder: String,
+    #[prop_or_default]
+    pub options: Vec<(T, String, String, Option<Html>)>,
+    #[prop_or_default]
+    pub items: Vec<CommandItem<T>>,
+    #[prop_or_default]
+    pub on_select: Callback<T>,
+    #[prop_or_default]
+    pub recent: Vec<T>,
+    #[prop_or(5)]
+    pub max_recent: usize,
+    #[prop_or_default]
+    pub on_recent_change: Callback<Vec<T>>,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// A modal `Command` palette, shown while the enclosing `CommandProvider` is open.
+#[function_component(CommandDialog)]
+pub fn command_dialog<T: Clone + PartialEq + 'static>(props: &CommandDialogProps<T>) -> Html {
+    #[cfg(feature = "ThemeProvider")]
+    let brandguide = use_brandguide();
+    #[cfg(not(feature = "ThemeProvider"))]
+    let brandguide = get_brandguide();
+    let context = use_command_palette();
+
+    if !context.is_open {
+        return html! {};
+    }
+
+    let on_select = {
+        let close = context.close.clone();
+        let on_select = props.on_select.clone();
+        Callback::from(move |value: T| {
+            close.emit(());
+            on_select.emit(value);
+        })
+    };
+
+    let onclick = {
+        let close = context.close.clone();
+        Callback::from(move |event: MouseEvent| {
+            if event.target() == event.current_target() {
+                close.emit(());
+            }
+        })
+    };
+
+    let onkeydown = {
+        let close = context.close.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if event.key() == "Escape" {
+                close.emit(());
+            }
+        })
+    };
+
+    html! {
+        <div class={classes!(&brandguide.command_dialog_overlay)} {onclick} {onkeydown}>
+            <div class={classes!(&brandguide.command_dialog_content)} role="dialog" aria-modal="true">
+                <Command<T>
+                    placeholder={props.placeholder.clone()}
+                    options={props.options.clone()}
+                    items={props.items.clone()}
+                    {on_select}
+                    recent={props.recent.clone()}
+                    max_recent={props.max_recent}
+                    on_recent_change={props.on_recent_change.clone()}
+                    expanded=true
+                    autofocus=true
+                    class={props.class.clone()}
+                />
+            </div>
         </div>
     }
 }
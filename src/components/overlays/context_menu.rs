@@ -1,15 +1,29 @@
-use crate::config::ClassesStr;
-use gloo_utils::document;
+use crate::utils::tw_merge;
+use gloo::events::{EventListener, EventListenerOptions};
+use gloo::timers::callback::Timeout;
+use gloo_utils::{document, window};
+use std::cell::RefCell;
 use std::rc::Rc;
 use wasm_bindgen::JsCast;
-use web_sys::{Element, MouseEvent};
+use web_sys::{Element, Event, HtmlElement, KeyboardEvent, MouseEvent, Node};
 use yew::prelude::*;
 
+// Distance kept between an open menu and the edges of the viewport.
+const VIEWPORT_MARGIN: f64 = 8.0;
+// Hover intent delays for opening and closing submenus.
+const SUBMENU_OPEN_DELAY_MS: u32 = 100;
+const SUBMENU_CLOSE_DELAY_MS: u32 = 300;
+// Keystrokes further apart than this start a new typeahead search.
+const TYPEAHEAD_RESET_MS: i64 = 500;
+
 #[derive(Clone, PartialEq)]
 pub struct ContextMenuState {
     pub is_open: bool,
     pub position: (i32, i32),
     pub toggle: Callback<(i32, i32)>,
+    pub open: Callback<(i32, i32)>,
+    pub close: Callback<()>,
+    pub content_ref: NodeRef,
 }
 
 #[derive(Properties, PartialEq)]
@@ -22,6 +36,33 @@ pub struct ContextMenuProps {
 pub fn context_menu(props: &ContextMenuProps) -> Html {
     let is_open = use_state(|| false);
     let position = use_state(|| (0, 0));
+    let content_ref = use_node_ref();
+    let restore_focus = use_mut_ref(|| None::<HtmlElement>);
+
+    let open = {
+        let is_open = is_open.clone();
+        let position = position.clone();
+        let restore_focus = restore_focus.clone();
+        Callback::from(move |(x, y): (i32, i32)| {
+            if !*is_open {
+                *restore_focus.borrow_mut() = document()
+                    .active_element()
+                    .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+            }
+            position.set((x, y));
+            is_open.set(true);
+        })
+    };
+
+    let close = {
+        let is_open = is_open.clone();
+        Callback::from(move |_| {
+            is_open.set(false);
+            if let Some(element) = restore_focus.borrow_mut().take() {
+                let _ = element.focus();
+            }
+        })
+    };
 
     let toggle = {
         let is_open = is_open.clone();
@@ -32,10 +73,49 @@ pub fn context_menu(props: &ContextMenuProps) -> Html {
         })
     };
 
+    // Dismiss on outside pointer presses, scrolling outside the menu and resizing.
+    {
+        let is_open = is_open.clone();
+        let content_ref = content_ref.clone();
+        use_effect_with(*is_open, move |open| {
+            let mut listeners = Vec::new();
+            if *open {
+                let options = EventListenerOptions::run_in_capture_phase();
+                for (target, event_type) in [
+                    (
+                        document().unchecked_into::<web_sys::EventTarget>(),
+                        "pointerdown",
+                    ),
+                    (window().unchecked_into::<web_sys::EventTarget>(), "scroll"),
+                ] {
+                    let is_open = is_open.clone();
+                    let content_ref = content_ref.clone();
+                    listeners.push(EventListener::new_with_options(
+                        &target,
+                        event_type,
+                        options,
+                        move |event| {
+                            if is_outside(&content_ref, event) {
+                                is_open.set(false);
+                            }
+                        },
+                    ));
+                }
+                listeners.push(EventListener::new(&window(), "resize", move |_| {
+                    is_open.set(false);
+                }));
+            }
+            move || drop(listeners)
+        });
+    }
+
     let state = Rc::new(ContextMenuState {
         is_open: *is_open,
         position: *position,
-        toggle: toggle.clone(),
+        toggle,
+        open,
+        close,
+        content_ref,
     });
 
     html! {
@@ -58,17 +138,36 @@ pub struct ContextMenuTriggerProps {
 #[function_component(ContextMenuTrigger)]
 pub fn context_menu_trigger(props: &ContextMenuTriggerProps) -> Html {
     let state = use_context::<Rc<ContextMenuState>>().expect("no context found");
+    let trigger_ref = use_node_ref();
 
     let oncontextmenu = {
-        let toggle = state.toggle.clone();
+        let open = state.open.clone();
         Callback::from(move |event: MouseEvent| {
             event.prevent_default();
-            toggle.emit((event.client_x(), event.client_y()));
+            open.emit((event.client_x(), event.client_y()));
+        })
+    };
+
+    // Shift+F10 and the dedicated context menu key open the menu below the trigger.
+    let onkeydown = {
+        let open = state.open.clone();
+        let trigger_ref = trigger_ref.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            let is_menu_key =
+                event.key() == "ContextMenu" || (event.shift_key() && event.key() == "F10");
+            if !is_menu_key {
+                return;
+            }
+            if let Some(trigger) = trigger_ref.cast::<Element>() {
+                event.prevent_default();
+                let rect = trigger.get_bounding_client_rect();
+                open.emit((rect.left() as i32, rect.bottom() as i32));
+            }
         })
     };
 
     html! {
-        <div {oncontextmenu} class={classes!(props.class.clone(), "cursor-pointer")}>
+        <div ref={trigger_ref} {oncontextmenu} {onkeydown} class={tw_merge(classes!("cursor-pointer", props.class.clone()))}>
             { for props.children.iter() }
         </div>
     }
@@ -85,18 +184,70 @@ pub struct ContextMenuContentProps {
 #[function_component(ContextMenuContent)]
 pub fn context_menu_content(props: &ContextMenuContentProps) -> Html {
     let state = use_context::<Rc<ContextMenuState>>().expect("no context found");
+    let typeahead = use_mut_ref(Typeahead::default);
+
+    // Clamp the menu into the viewport once its size is known, then focus it.
+    {
+        let content_ref = state.content_ref.clone();
+        use_effect_with((state.is_open, state.position), move |(is_open, (x, y))| {
+            if *is_open {
+                if let Some(content) = content_ref.cast::<HtmlElement>() {
+                    let rect = content.get_bounding_client_rect();
+                    let (viewport_width, viewport_height) = viewport_size();
+                    let left = clamp_to_viewport(*x as f64, rect.width(), viewport_width);
+                    let top = clamp_to_viewport(*y as f64, rect.height(), viewport_height);
+                    let style = content.style();
+                    let _ = style.set_property("left", &format!("{}px", left));
+                    let _ = style.set_property("top", &format!("{}px", top));
+                    let _ = content.focus();
+                }
+            }
+            || ()
+        });
+    }
 
     if !state.is_open {
         return html! {};
     }
 
+    let onkeydown = {
+        let content_ref = state.content_ref.clone();
+        let close = state.close.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if event.key() == "Escape" {
+                event.prevent_default();
+                close.emit(());
+                return;
+            }
+            // Tab closes the menu and lets focus move on from where it was opened.
+            if event.key() == "Tab" {
+                close.emit(());
+                return;
+            }
+            if let Some(menu) = content_ref.cast::<Element>() {
+                if handle_menu_keydown(&event, &menu, &typeahead) {
+                    event.prevent_default();
+                    event.stop_propagation();
+                }
+            }
+        })
+    };
+
     let style = format!(
         "position: fixed; left: {}px; top: {}px;",
         state.position.0, state.position.1
     );
 
     html! {
-        <div class={classes!(props.class.clone(), "bg-white","dark:bg-zinc-800","border","border-gray-200","dark:border-gray-700","rounded-md","shadow-lg","p-1","z-50")} {style}>
+        <div
+            ref={state.content_ref.clone()}
+            role="menu"
+            tabindex="-1"
+            aria-orientation="vertical"
+            class={tw_merge(classes!("bg-white","dark:bg-zinc-800","border","border-gray-200","dark:border-gray-700","rounded-md","shadow-lg","p-1","z-50","outline-none", props.class.clone()))}
+            {style}
+            {onkeydown}
+        >
             { for props.children.iter() }
         </div>
     }
@@ -110,10 +261,20 @@ pub struct ContextMenuItemProps {
     pub inset: bool,
     #[prop_or(false)]
     pub disabled: bool,
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
+    #[prop_or_default]
+    pub shortcut: Option<AttrValue>,
+    /// Label used for typeahead when the children are not plain text.
+    #[prop_or_default]
+    pub text_value: Option<AttrValue>,
 }
 
 #[function_component(ContextMenuItem)]
 pub fn context_menu_item(props: &ContextMenuItemProps) -> Html {
+    let state = use_context::<Rc<ContextMenuState>>().expect("no context found");
+    let item_ref = use_node_ref();
+
     let class = classes!(
         "flex",
         "items-center",
@@ -125,17 +286,50 @@ pub fn context_menu_item(props: &ContextMenuItemProps) -> Html {
         if props.disabled {
             "text-gray-400 dark:text-gray-500 cursor-not-allowed"
         } else {
-            "text-gray-700 dark:text-gray-200 hover:bg-gray-100 dark:hover:bg-zinc-700 cursor-pointer"
+            "text-gray-700 dark:text-gray-200 hover:bg-gray-100 dark:hover:bg-zinc-700 focus:bg-gray-100 dark:focus:bg-zinc-700 cursor-pointer"
         }
     );
 
+    let onclick = {
+        let onclick = props.onclick.clone();
+        let close = state.close.clone();
+        let disabled = props.disabled;
+        Callback::from(move |event: MouseEvent| {
+            if !disabled {
+                onclick.emit(event);
+                close.emit(());
+            }
+        })
+    };
+
     html! {
-        <div {class} role="menuitem" tabindex="-1">
+        <div
+            ref={item_ref.clone()}
+            {class}
+            role="menuitem"
+            tabindex="-1"
+            aria-disabled={props.disabled.then_some("true")}
+            data-text-value={props.text_value.clone()}
+            {onclick}
+            onmousemove={focus_on_hover(&item_ref, props.disabled)}
+        >
             { for props.children.iter() }
+            if let Some(shortcut) = props.shortcut.clone() {
+                <ContextMenuShortcut>{ shortcut }</ContextMenuShortcut>
+            }
         </div>
     }
 }
 
+#[derive(Clone, PartialEq)]
+pub struct ContextMenuSubState {
+    pub is_open: bool,
+    pub focus_first: bool,
+    pub open: Callback<bool>,
+    pub close: Callback<bool>,
+    pub trigger_ref: NodeRef,
+}
+
 #[derive(Properties, PartialEq)]
 pub struct ContextMenuSubProps {
     #[prop_or_default]
@@ -144,10 +338,73 @@ pub struct ContextMenuSubProps {
 
 #[function_component(ContextMenuSub)]
 pub fn context_menu_sub(props: &ContextMenuSubProps) -> Html {
+    let is_open = use_state(|| false);
+    let focus_first = use_state(|| false);
+    let trigger_ref = use_node_ref();
+    let pending = use_mut_ref(|| None::<Timeout>);
+
+    let open = {
+        let is_open = is_open.clone();
+        let focus_first = focus_first.clone();
+        let pending = pending.clone();
+        Callback::from(move |focus: bool| {
+            pending.borrow_mut().take();
+            focus_first.set(focus);
+            is_open.set(true);
+        })
+    };
+
+    let close = {
+        let is_open = is_open.clone();
+        let trigger_ref = trigger_ref.clone();
+        let pending = pending.clone();
+        Callback::from(move |restore_focus: bool| {
+            pending.borrow_mut().take();
+            is_open.set(false);
+            if restore_focus {
+                if let Some(trigger) = trigger_ref.cast::<HtmlElement>() {
+                    let _ = trigger.focus();
+                }
+            }
+        })
+    };
+
+    let onmouseenter = {
+        let open = open.clone();
+        let pending = pending.clone();
+        let is_open = *is_open;
+        Callback::from(move |_: MouseEvent| {
+            let open = open.clone();
+            *pending.borrow_mut() =
+                (!is_open).then(|| Timeout::new(SUBMENU_OPEN_DELAY_MS, move || open.emit(false)));
+        })
+    };
+
+    let onmouseleave = {
+        let close = close.clone();
+        let pending = pending.clone();
+        Callback::from(move |_: MouseEvent| {
+            let close = close.clone();
+            *pending.borrow_mut() = Some(Timeout::new(SUBMENU_CLOSE_DELAY_MS, move || {
+                close.emit(false)
+            }));
+        })
+    };
+
+    let state = Rc::new(ContextMenuSubState {
+        is_open: *is_open,
+        focus_first: *focus_first,
+        open,
+        close,
+        trigger_ref,
+    });
+
     html! {
-        <div class="relative">
-            { for props.children.iter() }
-        </div>
+        <ContextProvider<Rc<ContextMenuSubState>> context={state}>
+            <div class="relative" {onmouseenter} {onmouseleave}>
+                { for props.children.iter() }
+            </div>
+        </ContextProvider<Rc<ContextMenuSubState>>>
     }
 }
 
@@ -157,10 +414,14 @@ pub struct ContextMenuSubTriggerProps {
     pub children: Children,
     #[prop_or(false)]
     pub inset: bool,
+    #[prop_or_default]
+    pub text_value: Option<AttrValue>,
 }
 
 #[function_component(ContextMenuSubTrigger)]
 pub fn context_menu_sub_trigger(props: &ContextMenuSubTriggerProps) -> Html {
+    let sub = use_context::<Rc<ContextMenuSubState>>().expect("no submenu context found");
+
     let class = classes!(
         "flex",
         "items-center",
@@ -172,14 +433,48 @@ pub fn context_menu_sub_trigger(props: &ContextMenuSubTriggerProps) -> Html {
         "dark:text-gray-200",
         "hover:bg-gray-100",
         "dark:hover:bg-zinc-700",
+        "focus:bg-gray-100",
+        "dark:focus:bg-zinc-700",
         "cursor-pointer",
+        if sub.is_open {
+            "bg-gray-100 dark:bg-zinc-700"
+        } else {
+            ""
+        },
         if props.inset { "pl-8" } else { "" }
     );
 
+    let onkeydown = {
+        let open = sub.open.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if matches!(event.key().as_str(), "ArrowRight" | "Enter" | " ") {
+                event.prevent_default();
+                event.stop_propagation();
+                open.emit(true);
+            }
+        })
+    };
+
+    let onclick = {
+        let open = sub.open.clone();
+        Callback::from(move |_: MouseEvent| open.emit(false))
+    };
+
     html! {
-        <div {class} role="menuitem" tabindex="-1">
+        <div
+            ref={sub.trigger_ref.clone()}
+            {class}
+            role="menuitem"
+            tabindex="-1"
+            aria-haspopup="menu"
+            aria-expanded={sub.is_open.to_string()}
+            data-text-value={props.text_value.clone()}
+            {onkeydown}
+            {onclick}
+            onmousemove={focus_on_hover(&sub.trigger_ref, false)}
+        >
             { for props.children.iter() }
-            <span class="ml-auto pl-2">{ "▶" }</span>
+            <span class="ml-auto pl-2" aria-hidden="true">{ "▶" }</span>
         </div>
     }
 }
@@ -194,8 +489,76 @@ pub struct ContextMenuSubContentProps {
 
 #[function_component(ContextMenuSubContent)]
 pub fn context_menu_sub_content(props: &ContextMenuSubContentProps) -> Html {
+    let sub = use_context::<Rc<ContextMenuSubState>>().expect("no submenu context found");
+    let content_ref = use_node_ref();
+    let typeahead = use_mut_ref(Typeahead::default);
+
+    // Flip to the left of the trigger and shift upwards when the submenu would overflow.
+    {
+        let content_ref = content_ref.clone();
+        use_effect_with(
+            (sub.is_open, sub.focus_first),
+            move |(is_open, focus_first)| {
+                if *is_open {
+                    if let Some(content) = content_ref.cast::<HtmlElement>() {
+                        let rect = content.get_bounding_client_rect();
+                        let (viewport_width, viewport_height) = viewport_size();
+                        let style = content.style();
+                        if rect.right() > viewport_width - VIEWPORT_MARGIN {
+                            let _ = style.set_property("left", "auto");
+                            let _ = style.set_property("right", "100%");
+                        }
+                        let overflow = rect.bottom() - (viewport_height - VIEWPORT_MARGIN);
+                        if overflow > 0.0 {
+                            let shift = overflow.min(rect.top() - VIEWPORT_MARGIN).max(0.0);
+                            let _ = style.set_property("top", &format!("-{}px", shift));
+                        }
+                        if *focus_first {
+                            if let Some(first) = menu_items(&content).first() {
+                                let _ = first.focus();
+                            }
+                        }
+                    }
+                }
+                || ()
+            },
+        );
+    }
+
+    if !sub.is_open {
+        return html! {};
+    }
+
+    let onkeydown = {
+        let content_ref = content_ref.clone();
+        let close = sub.close.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            // Escape and Tab bubble up so the root menu closes entirely.
+            if event.key() == "Escape" || event.key() == "Tab" {
+                return;
+            }
+            event.stop_propagation();
+            if event.key() == "ArrowLeft" {
+                event.prevent_default();
+                close.emit(true);
+                return;
+            }
+            if let Some(menu) = content_ref.cast::<Element>() {
+                if handle_menu_keydown(&event, &menu, &typeahead) {
+                    event.prevent_default();
+                }
+            }
+        })
+    };
+
     html! {
-        <div class={classes!(props.class.clone(), "absolute","left-full","top-0","min-w-[8rem]","bg-white","dark:bg-zinc-800","border","border-gray-200","dark:border-gray-700","rounded-md","shadow-lg","p-1","z-50")}>
+        <div
+            ref={content_ref}
+            role="menu"
+            aria-orientation="vertical"
+            class={tw_merge(classes!("absolute","left-full","top-0","min-w-[8rem]","bg-white","dark:bg-zinc-800","border","border-gray-200","dark:border-gray-700","rounded-md","shadow-lg","p-1","z-50", props.class.clone()))}
+            {onkeydown}
+        >
             { for props.children.iter() }
         </div>
     }
@@ -214,10 +577,23 @@ pub struct ContextMenuCheckboxItemProps {
     pub children: Children,
     #[prop_or(false)]
     pub checked: bool,
+    #[prop_or(false)]
+    pub inset: bool,
+    #[prop_or(false)]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub onchange: Callback<bool>,
+    #[prop_or_default]
+    pub shortcut: Option<AttrValue>,
+    #[prop_or_default]
+    pub text_value: Option<AttrValue>,
 }
 
 #[function_component(ContextMenuCheckboxItem)]
 pub fn context_menu_checkbox_item(props: &ContextMenuCheckboxItemProps) -> Html {
+    let state = use_context::<Rc<ContextMenuState>>().expect("no context found");
+    let item_ref = use_node_ref();
+
     let class = classes!(
         "flex",
         "items-center",
@@ -225,38 +601,80 @@ pub fn context_menu_checkbox_item(props: &ContextMenuCheckboxItemProps) -> Html
         "py-1.5",
         "text-sm",
         "outline-none",
-        "text-gray-700",
-        "dark:text-gray-200",
-        "hover:bg-gray-100",
-        "dark:hover:bg-zinc-700",
-        "cursor-pointer"
+        if props.inset { "pl-8" } else { "" },
+        if props.disabled {
+            "text-gray-400 dark:text-gray-500 cursor-not-allowed"
+        } else {
+            "text-gray-700 dark:text-gray-200 hover:bg-gray-100 dark:hover:bg-zinc-700 focus:bg-gray-100 dark:focus:bg-zinc-700 cursor-pointer"
+        }
     );
 
+    let onclick = {
+        let onchange = props.onchange.clone();
+        let close = state.close.clone();
+        let checked = props.checked;
+        let disabled = props.disabled;
+        Callback::from(move |_: MouseEvent| {
+            if !disabled {
+                onchange.emit(!checked);
+                close.emit(());
+            }
+        })
+    };
+
     html! {
-        <div {class} role="menuitemcheckbox" aria-checked={props.checked.to_string()}>
-            <span class="mr-2">
+        <div
+            ref={item_ref.clone()}
+            {class}
+            role="menuitemcheckbox"
+            tabindex="-1"
+            aria-checked={props.checked.to_string()}
+            aria-disabled={props.disabled.then_some("true")}
+            data-text-value={props.text_value.clone()}
+            {onclick}
+            onmousemove={focus_on_hover(&item_ref, props.disabled)}
+        >
+            <span class="mr-2" aria-hidden="true">
                 if props.checked {
                     { "✓" }
                 }
             </span>
             { for props.children.iter() }
+            if let Some(shortcut) = props.shortcut.clone() {
+                <ContextMenuShortcut>{ shortcut }</ContextMenuShortcut>
+            }
         </div>
     }
 }
 
+#[derive(Clone, PartialEq)]
+pub struct ContextMenuRadioGroupState {
+    pub value: String,
+    pub onchange: Callback<String>,
+}
+
 #[derive(Properties, PartialEq)]
 pub struct ContextMenuRadioGroupProps {
     #[prop_or_default]
     pub children: Children,
     pub value: String,
+    #[prop_or_default]
+    pub onchange: Callback<String>,
 }
 
 #[function_component(ContextMenuRadioGroup)]
 pub fn context_menu_radio_group(props: &ContextMenuRadioGroupProps) -> Html {
+    let state = Rc::new(ContextMenuRadioGroupState {
+        value: props.value.clone(),
+        onchange: props.onchange.clone(),
+    });
+
     html! {
-        <div role="group">
-            { for props.children.iter() }
-        </div>
+        <ContextProvider<Rc<ContextMenuRadioGroupState>> context={state}>
+            <div role="group">
+                { for props.children.iter() }
+            </div>
+        </ContextProvider<Rc<ContextMenuRadioGroupState>>>
     }
 }
 
@@ -291,12 +709,22 @@ pub struct ContextMenuRadioItemProps {
     #[prop_or_default]
     pub children: Children,
     pub value: String,
+    #[prop_or(false)]
+    pub inset: bool,
+    #[prop_or(false)]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub shortcut: Option<AttrValue>,
+    #[prop_or_default]
+    pub text_value: Option<AttrValue>,
 }
 
 #[function_component(ContextMenuRadioItem)]
 pub fn context_menu_radio_item(props: &ContextMenuRadioItemProps) -> Html {
+    let state = use_context::<Rc<ContextMenuState>>().expect("no context found");
     let radio_group =
-        use_context::<Rc<ContextMenuRadioGroupProps>>().expect("no radio group context found");
+        use_context::<Rc<ContextMenuRadioGroupState>>().expect("no radio group context found");
+    let item_ref = use_node_ref();
     let is_selected = props.value == radio_group.value;
 
     let class = classes!(
@@ -306,21 +734,48 @@ pub fn context_menu_radio_item(props: &ContextMenuRadioItemProps) -> Html {
         "py-1.5",
         "text-sm",
         "outline-none",
-        "text-gray-700",
-        "dark:text-gray-200",
-        "hover:bg-gray-100",
-        "dark:hover:bg-zinc-700",
-        "cursor-pointer"
+        if props.inset { "pl-8" } else { "" },
+        if props.disabled {
+            "text-gray-400 dark:text-gray-500 cursor-not-allowed"
+        } else {
+            "text-gray-700 dark:text-gray-200 hover:bg-gray-100 dark:hover:bg-zinc-700 focus:bg-gray-100 dark:focus:bg-zinc-700 cursor-pointer"
+        }
     );
 
+    let onclick = {
+        let onchange = radio_group.onchange.clone();
+        let close = state.close.clone();
+        let value = props.value.clone();
+        let disabled = props.disabled;
+        Callback::from(move |_: MouseEvent| {
+            if !disabled {
+                onchange.emit(value.clone());
+                close.emit(());
+            }
+        })
+    };
+
     html! {
-        <div {class} role="menuitemradio" aria-checked={is_selected.to_string()}>
-            <span class="mr-2">
+        <div
+            ref={item_ref.clone()}
+            {class}
+            role="menuitemradio"
+            tabindex="-1"
+            aria-checked={is_selected.to_string()}
+            aria-disabled={props.disabled.then_some("true")}
+            data-text-value={props.text_value.clone()}
+            {onclick}
+            onmousemove={focus_on_hover(&item_ref, props.disabled)}
+        >
+            <span class="mr-2" aria-hidden="true">
                 if is_selected {
                     { "●" }
                 }
             </span>
             { for props.children.iter() }
+            if let Some(shortcut) = props.shortcut.clone() {
+                <ContextMenuShortcut>{ shortcut }</ContextMenuShortcut>
+            }
         </div>
     }
 }
@@ -339,3 +794,162 @@ pub fn context_menu_shortcut(props: &ContextMenuShortcutProps) -> Html {
         </span>
     }
 }
+
+#[derive(Default)]
+struct Typeahead {
+    query: String,
+    last_key_at: i64,
+}
+
+impl Typeahead {
+    fn push(&mut self, key: &str) -> &str {
+        let now = chrono::Utc::now().timestamp_millis();
+        if now - self.last_key_at > TYPEAHEAD_RESET_MS {
+            self.query.clear();
+        }
+        self.last_key_at = now;
+        self.query.push_str(&key.to_lowercase());
+        &self.query
+    }
+
+    fn is_active(&self) -> bool {
+        !self.query.is_empty()
+            && chrono::Utc::now().timestamp_millis() - self.last_key_at <= TYPEAHEAD_RESET_MS
+    }
+}
+
+// Moves focus between the enabled items of `menu`. Returns true when the key was handled.
+fn handle_menu_keydown(
+    event: &KeyboardEvent,
+    menu: &Element,
+    typeahead: &RefCell<Typeahead>,
+) -> bool {
+    let items = menu_items(menu);
+    if items.is_empty() {
+        return false;
+    }
+    let active = document().active_element();
+    let current = items
+        .iter()
+        .position(|item| Some(item.unchecked_ref::<Element>()) == active.as_ref());
+
+    let key = event.key();
+    let is_printable =
+        key.chars().count() == 1 && !event.ctrl_key() && !event.meta_key() && !event.alt_key();
+    let target = match key.as_str() {
+        " " if typeahead.borrow().is_active() => {
+            typeahead_match(&items, current, typeahead.borrow_mut().push(&key))
+        }
+        "ArrowDown" => Some(current.map_or(0, |index| (index + 1) % items.len())),
+        "ArrowUp" => Some(current.map_or(items.len() - 1, |index| {
+            (index + items.len() - 1) % items.len()
+        })),
+        "Home" => Some(0),
+        "End" => Some(items.len() - 1),
+        "Enter" | " " => {
+            if let Some(index) = current {
+                items[index].click();
+            }
+            return true;
+        }
+        _ if is_printable => typeahead_match(&items, current, typeahead.borrow_mut().push(&key)),
+        _ => return false,
+    };
+
+    if let Some(index) = target {
+        let _ = items[index].focus();
+    }
+    true
+}
+
+// Enabled items that belong directly to `menu`, excluding those of nested submenus.
+fn menu_items(menu: &Element) -> Vec<HtmlElement> {
+    let mut items = Vec::new();
+    if let Ok(nodes) = menu.query_selector_all("[role^='menuitem']") {
+        for index in 0..nodes.length() {
+            let Some(item) = nodes
+                .item(index)
+                .and_then(|node| node.dyn_into::<HtmlElement>().ok())
+            else {
+                continue;
+            };
+            let owner = item
+                .parent_element()
+                .and_then(|parent| parent.closest("[role='menu']").ok().flatten());
+            if owner.as_ref() == Some(menu)
+                && item.get_attribute("aria-disabled").as_deref() != Some("true")
+            {
+                items.push(item);
+            }
+        }
+    }
+    items
+}
+
+// Finds the next item after `current` whose label starts with `query`. Repeating a single
+// character cycles through the items starting with that character.
+fn typeahead_match(items: &[HtmlElement], current: Option<usize>, query: &str) -> Option<usize> {
+    let first = query.chars().next()?;
+    let query = if query.chars().all(|c| c == first) {
+        first.to_string()
+    } else {
+        query.to_string()
+    };
+    let start = current.map_or(0, |index| index + 1);
+    (0..items.len())
+        .map(|offset| (start + offset) % items.len())
+        .find(|&index| item_label(&items[index]).starts_with(&query))
+}
+
+fn item_label(item: &HtmlElement) -> String {
+    item.get_attribute("data-text-value")
+        .or_else(|| item.text_content())
+        .unwrap_or_default()
+        .trim_start_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+fn focus_on_hover(item_ref: &NodeRef, disabled: bool) -> Callback<MouseEvent> {
+    let item_ref = item_ref.clone();
+    Callback::from(move |_: MouseEvent| {
+        if disabled {
+            return;
+        }
+        if let Some(item) = item_ref.cast::<HtmlElement>() {
+            if document().active_element().as_ref() != Some(item.unchecked_ref::<Element>()) {
+                let _ = item.focus();
+            }
+        }
+    })
+}
+
+fn is_outside(content_ref: &NodeRef, event: &Event) -> bool {
+    let target = event
+        .target()
+        .and_then(|target| target.dyn_into::<Node>().ok());
+    match content_ref.cast::<Element>() {
+        Some(content) => !content.contains(target.as_ref()),
+        None => true,
+    }
+}
+
+fn viewport_size() -> (f64, f64) {
+    let window = window();
+    let width = window
+        .inner_width()
+        .ok()
+        .and_then(|value| value.as_f64())
+        .unwrap_or_default();
+    let height = window
+        .inner_height()
+        .ok()
+        .and_then(|value| value.as_f64())
+        .unwrap_or_default();
+    (width, height)
+}
+
+// Keeps a menu of `size` starting at `offset` inside a viewport of `viewport` pixels.
+fn clamp_to_viewport(offset: f64, size: f64, viewport: f64) -> f64 {
+    let max = (viewport - size - VIEWPORT_MARGIN).max(VIEWPORT_MARGIN);
+    offset.clamp(VIEWPORT_MARGIN, max)
+}
@@ -15,6 +15,9 @@ pub struct DialogContext {
 #[derive(Properties, PartialEq)]
 pub struct DialogProviderProps {
     pub children: Children,
+    /// Id of the dialog shown open at first, e.g. so that a server render includes it.
+    #[prop_or_default]
+    pub default_open: Option<String>,
 }
 
 #[function_component(DialogProvider)]
@@ -23,8 +26,8 @@ pub fn dialog_provider(props: &DialogProviderProps) -> Html {
     let brandguide = use_brandguide();
     #[cfg(not(feature = "ThemeProvider"))]
     let brandguide = get_brandguide();
-    let is_open = use_state(|| false);
-    let open_id = use_state(|| Vec::new());
+    let is_open = use_state(|| props.default_open.is_some());
+    let open_id = use_state(|| props.default_open.iter().cloned().collect::<Vec<_>>());
     let toggle = {
         let is_open = is_open.clone();
         let open_id = open_id.clone();
@@ -103,7 +106,7 @@ pub fn dialog(props: &DialogProps) -> Html {
 
     html! {
         <div class={&brandguide.dialog_container}>
-            <div class={&brandguide.dialog_content}>
+            <div role="dialog" aria-modal="true" class={&brandguide.dialog_content}>
                 { for props.children.iter() }
             </div>
         </div>
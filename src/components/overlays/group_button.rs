@@ -3,6 +3,7 @@ use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
 use crate::properties::FlexDirection;
+use crate::utils::tw_merge;
 use std::rc::Rc;
 use yew::function_component;
 use yew::html;
@@ -50,7 +51,7 @@ pub fn group_button(props: &GroupButtonProps) -> Html {
 
     html! {
         <ContextProvider<Rc<GroupButtonState>> context={state}>
-            <div role="group" class={classes!(props.class.clone(), &brandguide.group_button_container, props.direction.to_string(), &brandguide.group_button_list)}>
+            <div role="group" class={tw_merge(classes!(&brandguide.group_button_container, props.direction.to_string(), &brandguide.group_button_list, props.class.clone()))}>
                 { for props.children.iter() }
             </div>
         </ContextProvider<Rc<GroupButtonState>>>
@@ -93,11 +94,11 @@ pub fn group_button_trigger(props: &GroupButtonTriggerProps) -> Html {
             type="button"
             role="button"
             onclick={onclick}
-            class={classes!(
-                props.class.clone(),
+            class={tw_merge(classes!(
                 &brandguide.group_button_trigger,
                 if is_active { &brandguide.group_button_trigger_active } else { &brandguide.group_button_trigger_inactive },
-            )}
+                props.class.clone(),
+            ))}
         >
             { for props.children.iter() }
         </button>
@@ -30,7 +30,9 @@ pub use dropdown::{Dropdown, DropdownItem, DropdownProps};
 #[cfg(feature = "ContextMenu")]
 pub use context_menu::{ContextMenu, ContextMenuCheckboxItem, ContextMenuContent, ContextMenuItem, ContextMenuLabel, ContextMenuRadioGroup, ContextMenuRadioItem, ContextMenuSeparator, ContextMenuShortcut, ContextMenuSub, ContextMenuSubContent, ContextMenuSubTrigger, ContextMenuTrigger};
 #[cfg(feature = "Command")]
-pub use command::Command;
+pub use command::{
+    use_command_palette, Command, CommandContext, CommandDialog, CommandItem, CommandProvider,
+};
 #[cfg(feature = "Collapsible")]
 pub use collapsible::{Collapsible, CollapsibleContent, CollapsibleHeader, CollapsibleItem, CollapsibleTitle, CollapsibleTrigger};
 #[cfg(feature = "GroupButton")]
@@ -3,6 +3,7 @@ use crate::config::get_brandguide;
 #[cfg(feature = "ThemeProvider")]
 use crate::config::use_brandguide;
 use crate::config::{BrandGuideType, ClassesStr};
+use crate::utils::tw_merge;
 use gloo_console as console;
 use std::rc::Rc;
 use wasm_bindgen::JsCast;
@@ -68,7 +69,7 @@ pub fn popover(props: &PopoverProps) -> Html {
 
     html! {
         <ContextProvider<Rc<PopoverState>> context={state}>
-            <div ref={div_ref} class={classes!(&brandguide.popover_container, props.class.clone())} tabindex="0" onfocusout={close}>
+            <div ref={div_ref} class={tw_merge(classes!(&brandguide.popover_container, props.class.clone()))} tabindex="0" onfocusout={close}>
                 { for props.children.iter() }
             </div>
         </ContextProvider<Rc<PopoverState>>>
@@ -98,7 +99,7 @@ pub fn popover_trigger(props: &PopoverTriggerProps) -> Html {
     };
 
     html! {
-        <div class={classes!(&brandguide.popover_trigger, props.class.clone())} {onclick}>
+        <div class={tw_merge(classes!(&brandguide.popover_trigger, props.class.clone()))} {onclick}>
             { for props.children.iter() }
         </div>
     }
@@ -163,7 +164,7 @@ pub fn popover_content(props: &PopoverContentProps) -> Html {
     let position_class = props.position.to_class(&brandguide);
 
     html! {
-        <div class={classes!(&brandguide.popover_content, position_class, props.class.clone())}>
+        <div class={tw_merge(classes!(&brandguide.popover_content, position_class, props.class.clone()))}>
             { for props.children.iter() }
         </div>
     }
@@ -0,0 +1,248 @@
+use crate::components::utils::browser::RENDER_READS_BROWSER;
+use crate::components::utils::media_query::MediaQueryDefaultsContext;
+use crate::components::utils::window_provider::{current_window, WindowContext};
+use crate::config::BREAKPOINT_WIDTHS;
+use gloo::events::EventListener;
+use std::cell::{Cell, RefCell};
+use web_sys::Window;
+use yew::prelude::*;
+
+/// Tailwind's screens, `sm:` to `2xl:`. Each starts at the width of its `breakpoint_*` token in
+/// the brand config, 640px to 1536px by default like Tailwind's.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    Sm,
+    Md,
+    Lg,
+    Xl,
+    /// Tailwind's `2xl`.
+    Xxl,
+}
+
+impl Breakpoint {
+    /// Every breakpoint, from small to large.
+    pub const ALL: [Breakpoint; 5] = [
+        Breakpoint::Sm,
+        Breakpoint::Md,
+        Breakpoint::Lg,
+        Breakpoint::Xl,
+        Breakpoint::Xxl,
+    ];
+
+    /// The Tailwind prefix, e.g. `"2xl"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Breakpoint::Sm => "sm",
+            Breakpoint::Md => "md",
+            Breakpoint::Lg => "lg",
+            Breakpoint::Xl => "xl",
+            Breakpoint::Xxl => "2xl",
+        }
+    }
+
+    /// The width the breakpoint starts at, e.g. `"1024px"` for `Lg`.
+    pub fn min_width(self) -> &'static str {
+        BREAKPOINT_WIDTHS[self as usize]
+    }
+
+    /// The media query matching from this breakpoint up, e.g. `"(min-width: 1024px)"`. Pass it
+    /// to `MediaQueryDefaults` to pick the breakpoint of a server render.
+    pub fn media_query(self) -> String {
+        format!("(min-width: {})", self.min_width())
+    }
+}
+
+/// Values of `use_breakpoint_value`: `base` below the first breakpoint given, then the value of
+/// the largest breakpoint reached, like Tailwind's `md:` and `lg:` classes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BreakpointMap<T> {
+    base: T,
+    values: Vec<(Breakpoint, T)>,
+}
+
+impl<T> BreakpointMap<T> {
+    pub fn new(base: T) -> Self {
+        Self {
+            base,
+            values: Vec::new(),
+        }
+    }
+
+    /// Uses `value` from `breakpoint` up, replacing an earlier value for it.
+    pub fn with(mut self, breakpoint: Breakpoint, value: T) -> Self {
+        self.values.retain(|(existing, _)| *existing != breakpoint);
+        self.values.push((breakpoint, value));
+        self.values.sort_by_key(|(breakpoint, _)| *breakpoint);
+        self
+    }
+
+    /// The value at `breakpoint`, as returned by `use_breakpoint`.
+    pub fn get(&self, breakpoint: Option<Breakpoint>) -> &T {
+        self.values
+            .iter()
+            .rev()
+            .find(|(from, _)| Some(*from) <= breakpoint)
+            .map_or(&self.base, |(_, value)| value)
+    }
+}
+
+// The breakpoints of one window, watched with a `MediaQueryList` listener per breakpoint that
+// every `use_breakpoint` of the window shares. Dropping it removes the listeners.
+struct Watcher {
+    window: Window,
+    current: Option<Breakpoint>,
+    subscribers: Vec<(usize, Callback<Option<Breakpoint>>)>,
+    _listeners: Vec<EventListener>,
+}
+
+impl Watcher {
+    fn new(window: Window) -> Self {
+        let _listeners = Breakpoint::ALL
+            .iter()
+            .filter_map(|breakpoint| {
+                let query = window.match_media(&breakpoint.media_query()).ok()??;
+                let window = window.clone();
+                Some(EventListener::new(&query, "change", move |_event| {
+                    changed(&window)
+                }))
+            })
+            .collect();
+        Self {
+            current: largest_matching(&window),
+            window,
+            subscribers: Vec::new(),
+            _listeners,
+        }
+    }
+}
+
+thread_local! {
+    static WATCHERS: RefCell<Vec<Watcher>> = const { RefCell::new(Vec::new()) };
+    static NEXT_SUBSCRIBER: Cell<usize> = const { Cell::new(0) };
+}
+
+// Unsubscribes when dropped, and stops watching the window after its last subscriber
+struct Subscription {
+    window: Window,
+    id: usize,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        WATCHERS.with(|watchers| {
+            let mut watchers = watchers.borrow_mut();
+            let Some(index) = watchers.iter().position(|w| w.window == self.window) else {
+                return;
+            };
+            watchers[index].subscribers.retain(|(id, _)| *id != self.id);
+            if watchers[index].subscribers.is_empty() {
+                watchers.remove(index);
+            }
+        });
+    }
+}
+
+// Calls `callback` whenever the breakpoint of `window` changes, and returns the current one
+fn subscribe(
+    window: Window,
+    callback: Callback<Option<Breakpoint>>,
+) -> (Subscription, Option<Breakpoint>) {
+    let id = NEXT_SUBSCRIBER.with(|next| next.replace(next.get() + 1));
+    let current = WATCHERS.with(|watchers| {
+        let mut watchers = watchers.borrow_mut();
+        let index = match watchers.iter().position(|w| w.window == window) {
+            Some(index) => index,
+            None => {
+                watchers.push(Watcher::new(window.clone()));
+                watchers.len() - 1
+            }
+        };
+        watchers[index].subscribers.push((id, callback));
+        watchers[index].current
+    });
+    (Subscription { window, id }, current)
+}
+
+fn changed(window: &Window) {
+    let current = largest_matching(window);
+    // Emit outside the borrow, as subscribers may unsubscribe while handling the change
+    let subscribers = WATCHERS.with(|watchers| {
+        let mut watchers = watchers.borrow_mut();
+        let watcher = watchers.iter_mut().find(|w| w.window == *window)?;
+        if watcher.current == current {
+            return None;
+        }
+        watcher.current = current;
+        Some(
+            watcher
+                .subscribers
+                .iter()
+                .map(|(_, callback)| callback.clone())
+                .collect::<Vec<_>>(),
+        )
+    });
+    for callback in subscribers.into_iter().flatten() {
+        callback.emit(current);
+    }
+}
+
+fn largest_matching(window: &Window) -> Option<Breakpoint> {
+    Breakpoint::ALL.into_iter().rev().find(|breakpoint| {
+        window
+            .match_media(&breakpoint.media_query())
+            .ok()
+            .flatten()
+            .is_some_and(|query| query.matches())
+    })
+}
+
+/// The largest breakpoint the window reaches, or `None` below `sm`. Breakpoints compare in size
+/// order, so `use_breakpoint() >= Some(Breakpoint::Lg)` is Tailwind's `lg:`.
+#[hook]
+pub fn use_breakpoint() -> Option<Breakpoint> {
+    let window_context = use_context::<WindowContext>();
+    let defaults = use_context::<MediaQueryDefaultsContext>();
+    let window = window_context
+        .as_ref()
+        .and_then(|context| context.window.clone());
+    let breakpoint = use_state_eq(|| {
+        if RENDER_READS_BROWSER {
+            largest_matching(&current_window(window_context.as_ref()))
+        } else {
+            Breakpoint::ALL.into_iter().rev().find(|breakpoint| {
+                let query = breakpoint.media_query();
+                defaults
+                    .as_ref()
+                    .is_some_and(|defaults| defaults.0.iter().any(|default| *default == query))
+            })
+        }
+    });
+
+    {
+        let breakpoint = breakpoint.clone();
+        use_effect_with(window, move |window| {
+            let window = window.clone().unwrap_or_else(gloo::utils::window);
+            let set_breakpoint = {
+                let breakpoint = breakpoint.clone();
+                Callback::from(move |current| breakpoint.set(current))
+            };
+            let (subscription, current) = subscribe(window, set_breakpoint);
+            // Replaces the default of a server render or hydration
+            breakpoint.set(current);
+            move || drop(subscription)
+        });
+    }
+
+    *breakpoint
+}
+
+/// The value of `values` at the current breakpoint, e.g. a column count:
+/// `use_breakpoint_value(BreakpointMap::new(1).with(Breakpoint::Md, 2).with(Breakpoint::Xl, 4))`
+#[hook]
+pub fn use_breakpoint_value<T>(values: BreakpointMap<T>) -> T
+where
+    T: Clone + 'static,
+{
+    let breakpoint = use_breakpoint();
+    values.get(breakpoint).clone()
+}
@@ -0,0 +1,21 @@
+#[cfg(any(feature = "Iframe", feature = "ThemeProvider"))]
+use yew::prelude::*;
+
+/// Whether components may ask the browser while rendering. A server render (`ssr`) has no
+/// browser, and a hydrating client (`hydration`) must first render what the server did, so both
+/// render with defaults and read the browser from effects instead.
+pub(crate) const RENDER_READS_BROWSER: bool =
+    cfg!(not(any(feature = "ssr", feature = "hydration")));
+
+/// Whether this render may ask the browser: always in client-side apps, and under `ssr` or
+/// `hydration` from the render after the component mounted, which never happens on the server.
+#[cfg(any(feature = "Iframe", feature = "ThemeProvider"))]
+#[hook]
+pub(crate) fn use_browser_ready() -> bool {
+    let ready = use_state_eq(|| RENDER_READS_BROWSER);
+    {
+        let ready = ready.clone();
+        use_effect_with((), move |_| ready.set(true));
+    }
+    *ready
+}
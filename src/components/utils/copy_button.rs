@@ -17,6 +17,9 @@ pub struct CopyButtonProps {
     pub copied_timeout_ms: u32,
     #[prop_or_default]
     pub onclick: Option<Callback<MouseEvent>>,
+    /// Name read by screen readers, needed when the children are only an icon
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
 }
 
 /// A button that copies text to the clipboard when clicked
@@ -113,6 +116,7 @@ pub fn copy_button(props: &CopyButtonProps) -> Html {
             class={props.class.clone()}
             onclick={onclick}
             type="button"
+            aria-label={props.aria_label.clone()}
         >
             if *is_copied {
                 if let Some(text) = &props.copied_text {
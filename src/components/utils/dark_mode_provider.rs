@@ -1,6 +1,5 @@
 use crate::components::utils::media_query::use_media_query;
 use std::rc::Rc;
-use web_sys::window;
 use yew::prelude::*;
 
 #[derive(Clone, PartialEq)]
@@ -25,32 +24,37 @@ pub struct DarkModeProviderProps {
 #[function_component(DarkModeProvider)]
 pub fn dark_mode_provider(props: &DarkModeProviderProps) -> Html {
     let mode = use_state(|| DarkModeColor::System);
-    let document = window().unwrap().document().unwrap();
-    let body = document.body().unwrap();
-
     let mode_preference = use_media_query("(prefers-color-scheme: dark)");
 
     #[cfg(target_arch = "wasm32")]
     {
-        let body = body.clone();
         let mode = mode.clone();
         let mode_preference = mode_preference.clone();
 
+        // The body is only touched from the effect, so server renders leave it alone
         use_effect_with(
             (mode.clone(), mode_preference),
-            move |(mode, mode_preference)| match **mode {
-                DarkModeColor::Light => {
-                    body.class_list().remove_1("dark").unwrap();
-                }
-                DarkModeColor::Dark => {
-                    body.class_list().add_1("dark").unwrap();
-                }
-                DarkModeColor::System => {
-                    if *mode_preference {
-                        body.class_list().add_1("dark").unwrap();
-                    } else {
+            move |(mode, mode_preference)| {
+                let body = web_sys::window()
+                    .unwrap()
+                    .document()
+                    .unwrap()
+                    .body()
+                    .unwrap();
+                match **mode {
+                    DarkModeColor::Light => {
                         body.class_list().remove_1("dark").unwrap();
                     }
+                    DarkModeColor::Dark => {
+                        body.class_list().add_1("dark").unwrap();
+                    }
+                    DarkModeColor::System => {
+                        if *mode_preference {
+                            body.class_list().add_1("dark").unwrap();
+                        } else {
+                            body.class_list().remove_1("dark").unwrap();
+                        }
+                    }
                 }
             },
         );
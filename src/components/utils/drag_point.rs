@@ -8,6 +8,7 @@ pub fn drag_point(props: &DragPointProps) -> Html {
     let DragPointProps {
         onstart,
         onstop,
+        onmove,
         class,
         tag,
     } = props;
@@ -28,12 +29,23 @@ pub fn drag_point(props: &DragPointProps) -> Html {
         })
     };
 
+    // The pointer is captured, so its moves reach the element wherever it goes
+    let onpointermove = {
+        let active_pointer = active_pointer.clone();
+        let onmove = onmove.clone();
+        Callback::from(move |e: PointerEvent| {
+            if Some(e.pointer_id()) == *active_pointer {
+                onmove.emit(e);
+            }
+        })
+    };
+
     {
         let onstop = onstop.clone();
         let drag_point_ref = drag_point_ref.clone();
         let active_pointer = active_pointer.clone();
-        let onpointerup = {
-            Closure::wrap(Box::new(move |e: PointerEvent| {
+        use_effect_with((), move |_| {
+            let onpointerup = Closure::wrap(Box::new(move |e: PointerEvent| {
                 if Some(e.pointer_id()) == *active_pointer {
                     if let Some(element) = drag_point_ref.cast::<web_sys::Element>() {
                         element
@@ -43,9 +55,7 @@ pub fn drag_point(props: &DragPointProps) -> Html {
                 }
                 onstop.emit(());
                 active_pointer.set(None);
-            }) as Box<dyn FnMut(_)>)
-        };
-        use_effect_with((), move |_| {
+            }) as Box<dyn FnMut(_)>);
             let window = web_sys::window().expect("no global `window` exists");
 
             window
@@ -69,6 +79,7 @@ pub fn drag_point(props: &DragPointProps) -> Html {
             ref={drag_point_ref}
             class={class.clone()}
             onpointerdown={onpointerdown}
+            onpointermove={onpointermove}
 
         />
     }
@@ -78,6 +89,9 @@ pub fn drag_point(props: &DragPointProps) -> Html {
 pub struct DragPointProps {
     pub onstart: Callback<PointerEvent>,
     pub onstop: Callback<()>,
+    /// Called as the pointer moves during a drag.
+    #[prop_or_default]
+    pub onmove: Callback<PointerEvent>,
     pub class: Classes,
     #[prop_or("div".to_string())]
     pub tag: String,
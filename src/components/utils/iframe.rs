@@ -1,3 +1,4 @@
+use crate::components::utils::browser::use_browser_ready;
 use crate::components::utils::window_provider::WindowProvider;
 use gloo_console as console;
 use gloo_utils::document;
@@ -24,6 +25,7 @@ pub struct IframeProps {
 pub fn iframe(props: &IframeProps) -> Html {
     let script = "".to_string();
     let iframe_ref = use_node_ref();
+    let browser_ready = use_browser_ready();
     let body_ref = use_state(|| None);
     let head_ref = use_state(|| None);
     let script_state = use_state(|| script.clone());
@@ -177,24 +179,25 @@ pub fn iframe(props: &IframeProps) -> Html {
         });
     }
 
-    let master_document = document();
-
     // JIT
     let style = "".to_string(); // TODO: Add JIT compiler
                                 // console::log!( style);
 
-    // Creating link list
-    let links = master_document.query_selector_all("link").unwrap();
+    // Creating link list. The page's stylesheets are only known in the browser, so server
+    // renders and hydration leave them out until mounted.
     let mut link_list: Vec<Html> = Vec::new();
 
     let mut preamble = format!("<style>{}</style>", style,);
 
-    for i in 0..links.length() {
-        let link = links
-            .get(i)
-            .unwrap()
-            .dyn_into::<web_sys::HtmlLinkElement>()
-            .unwrap();
+    let links: Vec<web_sys::HtmlLinkElement> = if browser_ready {
+        let links = document().query_selector_all("link").unwrap();
+        (0..links.length())
+            .map(|i| links.get(i).unwrap().dyn_into().unwrap())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    for link in links {
         let rel = &link.get_attribute("rel").unwrap_or_default();
         let href = &link.get_attribute("href").unwrap_or_default();
 
@@ -1,22 +1,54 @@
-use crate::components::utils::window_provider::use_window;
+use crate::components::utils::browser::RENDER_READS_BROWSER;
+use crate::components::utils::window_provider::{current_window, WindowContext};
 use gloo::events::EventListener;
 use yew::prelude::*;
 
+#[derive(Clone, PartialEq)]
+pub(crate) struct MediaQueryDefaultsContext(pub(crate) Vec<AttrValue>);
+
+#[derive(Properties, PartialEq)]
+pub struct MediaQueryDefaultsProps {
+    /// Queries that match, e.g. `"(min-width: 1024px)"` to render the desktop layout.
+    #[prop_or_default]
+    pub matching: Vec<AttrValue>,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Answers the media queries of its children where the browser can't: while rendering on the
+/// server and during the first render of a hydrating client. Other queries don't match there,
+/// and the browser's answers take over once the page runs.
+#[function_component(MediaQueryDefaults)]
+pub fn media_query_defaults(props: &MediaQueryDefaultsProps) -> Html {
+    html! {
+        <ContextProvider<MediaQueryDefaultsContext> context={MediaQueryDefaultsContext(props.matching.clone())}>
+            { props.children.clone() }
+        </ContextProvider<MediaQueryDefaultsContext>>
+    }
+}
+
 #[hook]
 pub fn use_media_query(query: &str) -> bool {
-    let window = use_window();
+    let window_context = use_context::<WindowContext>();
+    let defaults = use_context::<MediaQueryDefaultsContext>();
     let query = query.to_string();
     let match_media = {
         let query = query.clone();
         move || {
-            window
+            current_window(window_context.as_ref())
                 .match_media(&query)
                 .expect("Failed to query media")
                 .expect("Media query not supported")
         }
     };
 
-    let state = use_state_eq(|| match_media().matches());
+    let state = use_state_eq(|| {
+        if RENDER_READS_BROWSER {
+            match_media().matches()
+        } else {
+            defaults.is_some_and(|defaults| defaults.0.iter().any(|default| *default == query))
+        }
+    });
     let state_clone = state.clone();
 
     {
@@ -24,6 +56,8 @@ pub fn use_media_query(query: &str) -> bool {
         use_effect_with(query, move |_| {
             let match_media = match_media();
             let match_media_clone = match_media.clone();
+            // Replaces the default of a server render or hydration
+            state_clone.set(match_media.matches());
 
             let listener = EventListener::new(&match_media, "change", move |_event| {
                 state_clone.set(match_media_clone.matches());
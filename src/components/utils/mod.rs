@@ -1,9 +1,13 @@
 // Utility components
 
+pub(crate) mod browser;
+pub mod unique_id;
 #[cfg(feature = "WindowProvider")]
 pub mod window_provider;
 #[cfg(feature = "MediaQuery")]
 pub mod media_query;
+#[cfg(feature = "Breakpoint")]
+pub mod breakpoint;
 #[cfg(feature = "DarkModeProvider")]
 pub mod dark_mode_provider;
 #[cfg(feature = "Calendar")]
@@ -20,10 +24,13 @@ pub mod drag_point;
 #[cfg(feature = "CopyButton")]
 pub mod copy_button;
 
+pub use unique_id::{use_unique_id, UniqueIdProvider};
 #[cfg(feature = "WindowProvider")]
 pub use window_provider::{use_window, WindowProvider};
 #[cfg(feature = "MediaQuery")]
-pub use media_query::use_media_query;
+pub use media_query::{use_media_query, MediaQueryDefaults};
+#[cfg(feature = "Breakpoint")]
+pub use breakpoint::{use_breakpoint, use_breakpoint_value, Breakpoint, BreakpointMap};
 #[cfg(feature = "DarkModeProvider")]
 pub use dark_mode_provider::{use_dark_mode, DarkModeColor, DarkModeProvider};
 #[cfg(feature = "Calendar")]
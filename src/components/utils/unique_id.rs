@@ -0,0 +1,55 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use yew::prelude::*;
+
+thread_local! {
+    // Counter of the components outside any `UniqueIdProvider`
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+// Counter shared by the components under one `UniqueIdProvider`
+#[derive(Clone, Default)]
+struct UniqueIds(Rc<Cell<usize>>);
+
+impl PartialEq for UniqueIds {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct UniqueIdProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Numbers the ids of `use_unique_id` from zero for every render of the app, in the order the
+/// components are created. Wrap the app in it when rendering on the server, so the ids of each
+/// page don't depend on the requests rendered before it and match the hydrating client's.
+#[function_component(UniqueIdProvider)]
+pub fn unique_id_provider(props: &UniqueIdProviderProps) -> Html {
+    let ids = use_state(UniqueIds::default);
+
+    html! {
+        <ContextProvider<UniqueIds> context={(*ids).clone()}>
+            { props.children.clone() }
+        </ContextProvider<UniqueIds>>
+    }
+}
+
+/// An id that stays the same for the life of the component, e.g. `"select-3"` for the prefix
+/// `"select"`, to link elements through `aria-controls` and the like. Ids are numbered by the
+/// nearest `UniqueIdProvider`, or per thread without one.
+#[hook]
+pub fn use_unique_id(prefix: &str) -> String {
+    let ids = use_context::<UniqueIds>();
+    let id = use_state(|| {
+        let next = |counter: &Cell<usize>| counter.replace(counter.get() + 1);
+        let number = match ids {
+            Some(ids) => next(&ids.0),
+            None => NEXT_ID.with(next),
+        };
+        format!("{}-{}", prefix, number)
+    });
+    (*id).clone()
+}
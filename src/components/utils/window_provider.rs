@@ -67,8 +67,12 @@ pub fn window_provider(props: &WindowProviderProps) -> Html {
 
 #[hook]
 pub fn use_window() -> web_sys::Window {
-    let window_context = use_context::<WindowContext>();
-    match window_context {
+    current_window(use_context::<WindowContext>().as_ref())
+}
+
+// The window of the nearest `WindowProvider`, else the global one. Only call this in the browser.
+pub(crate) fn current_window(context: Option<&WindowContext>) -> web_sys::Window {
+    match context {
         Some(context) => context.window.clone().unwrap_or_else(gloo_window),
         None => gloo_window(),
     }
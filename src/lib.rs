@@ -2,21 +2,37 @@
 mod components;
 mod config;
 mod properties;
+pub mod utils;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 #[cfg(feature = "ThemeProvider")]
 mod component_editor;
+#[cfg(feature = "ThemeProvider")]
+mod component_previews;
+#[cfg(feature = "ThemeProvider")]
+mod themes;
+#[cfg(feature = "ThemeProvider")]
+mod tokens;
 
 // Prelude for backward compatibility
 pub mod prelude;
 
 // Public exports
 pub use components::*;
-pub use config::BRANDGUIDE;
+pub use config::{BrandField, BRANDGUIDE, BRAND_FIELDS};
+#[cfg(feature = "CssVariables")]
+pub use config::CSS_VARIABLES;
 pub use properties::*;
 
 // Re-export hooks for backward compatibility
+pub use components::utils::unique_id::use_unique_id;
 #[cfg(feature = "MediaQuery")]
 pub use components::utils::media_query::use_media_query;
+#[cfg(feature = "Breakpoint")]
+pub use components::utils::breakpoint::{
+    use_breakpoint, use_breakpoint_value, Breakpoint, BreakpointMap,
+};
 #[cfg(feature = "WindowProvider")]
 pub use components::utils::window_provider::use_window;
 #[cfg(feature = "DarkModeProvider")]
@@ -56,4 +72,11 @@ pub use components::layout::container::{Container, ContainerVariant};
 #[cfg(feature = "ThemeProvider")]
 pub use component_editor::ComponentEditor;
 #[cfg(feature = "ThemeProvider")]
-pub use config::{use_brandguide, use_set_brandguide, BrandGuideType, ClassesStr, ThemeProvider};
+pub use config::{
+    use_brandguide, use_set_brandguide, use_set_token, use_set_tokens, use_theme, use_theme_tokens,
+    BrandGuideType, ClassesStr, ThemeProvider, ThemeScope,
+};
+#[cfg(feature = "ThemeProvider")]
+pub use themes::{Theme, ThemeHandle};
+#[cfg(feature = "ThemeProvider")]
+pub use tokens::{ThemeChange, ThemeTokens};
@@ -40,7 +40,9 @@ pub use crate::components::layout::layout::Layout;
 #[cfg(feature = "Layout")]
 pub use crate::components::layout::layout_context::{LayoutAction, LayoutContext};
 #[cfg(feature = "MulticolSidebar")]
-pub use crate::components::layout::multicol_sidebar::SidebarColumn;
+pub use crate::components::layout::multicol_sidebar::{SidebarColumn, SidebarResizeHandle};
+#[cfg(feature = "PanelGroup")]
+pub use crate::components::layout::panel_group::{Panel, PanelDirection, PanelGroup};
 #[cfg(feature = "Sidebar")]
 pub use crate::components::layout::sidebar::{
     Sidebar, SidebarHeader, SidebarHeading, SidebarLink, SidebarMenu,
@@ -73,24 +75,42 @@ pub use crate::components::overlays::{
 pub use crate::components::code_editor::{CodeEditor, Annotation, AnnotationType, Diff, DiffType, TypeHint};
 
 // Re-export utility components and their types
+pub use crate::components::utils::unique_id::{use_unique_id, UniqueIdProvider};
 #[cfg(feature = "CopyButton")]
 pub use crate::components::utils::copy_button::CopyButton;
 #[cfg(feature = "DarkModeProvider")]
 pub use crate::components::utils::dark_mode_provider::use_dark_mode;
 #[cfg(feature = "MediaQuery")]
-pub use crate::components::utils::media_query::use_media_query;
+pub use crate::components::utils::media_query::{use_media_query, MediaQueryDefaults};
+#[cfg(feature = "Breakpoint")]
+pub use crate::components::utils::breakpoint::{
+    use_breakpoint, use_breakpoint_value, Breakpoint, BreakpointMap,
+};
 #[cfg(feature = "WindowProvider")]
 pub use crate::components::utils::window_provider::use_window;
 #[cfg(feature = "DarkModeProvider")]
 pub use crate::components::utils::DarkModeColor;
 
+// Re-export class helpers
+pub use crate::utils::tw_merge;
+
+// Re-export the matcher of the searchable lists
+#[cfg(any(feature = "Combobox", feature = "Command"))]
+pub use crate::utils::fuzzy_match;
+
 // Re-export properties
 pub use crate::properties::FlexDirection;
 
 // Re-export theme and config related items
+pub use crate::config::{BrandField, BRAND_FIELDS};
 #[cfg(feature = "ThemeProvider")]
 pub use crate::component_editor::ComponentEditor;
 #[cfg(feature = "ThemeProvider")]
 pub use crate::config::{
-    use_brandguide, use_set_brandguide, BrandGuideType, ClassesStr, ThemeProvider, BRANDGUIDE,
+    use_brandguide, use_set_brandguide, use_set_token, use_set_tokens, use_theme, use_theme_tokens,
+    BrandGuideType, ClassesStr, ThemeProvider, ThemeScope, BRANDGUIDE,
 };
+#[cfg(feature = "ThemeProvider")]
+pub use crate::themes::{Theme, ThemeHandle};
+#[cfg(feature = "ThemeProvider")]
+pub use crate::tokens::{ThemeChange, ThemeTokens};
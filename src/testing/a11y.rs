@@ -0,0 +1,569 @@
+//! Accessibility checks over rendered HTML, e.g. the output of [`render`](super::render):
+//!
+//! ```ignore
+//! let html = render(|| html! { <CopyButton copy_text="cargo add wonopui" aria_label="Copy command">{icon}</CopyButton> });
+//! a11y::assert_accessible(&html);
+//! ```
+//!
+//! The rules cover what can be told from the markup alone:
+//!
+//! - `button-name`: buttons have text, an `aria-label`, `aria-labelledby`, `title` or `<label>`
+//! - `label`: form fields, checkboxes, switches and radios have a `<label>` or an ARIA name
+//! - `aria-required-parent`: tabs, options and menu items sit in a tablist, listbox or menu
+//! - `aria-required-children`: tablists contain tabs
+//! - `aria-allowed-attr`: `aria-selected`, `aria-checked` and `aria-modal` are only set on roles
+//!   that support them
+//! - `duplicate-id`: ids are unique
+//! - `idref`: `for` and the `aria-*` references point at existing ids
+//!
+//! Elements hidden with `hidden` or `aria-hidden="true"` are skipped.
+
+use super::{tag_end, VOID_ELEMENTS};
+use std::collections::HashMap;
+use std::fmt;
+
+// Roles that only make sense inside one of the given roles
+const REQUIRED_PARENTS: &[(&str, &[&str])] = &[
+    ("tab", &["tablist"]),
+    ("option", &["listbox"]),
+    ("menuitem", &["menu", "menubar"]),
+    ("menuitemcheckbox", &["menu", "menubar"]),
+    ("menuitemradio", &["menu", "menubar"]),
+    ("treeitem", &["tree"]),
+];
+
+// State attributes and the roles that support them
+const ROLE_ATTRIBUTES: &[(&str, &[&str])] = &[
+    (
+        "aria-selected",
+        &[
+            "tab",
+            "option",
+            "row",
+            "gridcell",
+            "columnheader",
+            "rowheader",
+            "treeitem",
+        ],
+    ),
+    (
+        "aria-checked",
+        &[
+            "checkbox",
+            "switch",
+            "radio",
+            "menuitemcheckbox",
+            "menuitemradio",
+            "option",
+            "treeitem",
+        ],
+    ),
+    ("aria-modal", &["dialog", "alertdialog"]),
+];
+
+// Elements a `<label>` can name
+const LABELABLE: &[&str] = &[
+    "button", "input", "meter", "output", "progress", "select", "textarea",
+];
+
+/// An accessibility issue found by [`check`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Issue {
+    /// The rule that failed, e.g. `button-name`
+    pub rule: &'static str,
+    /// The opening tag of the element, without its classes
+    pub element: String,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} at {}", self.rule, self.message, self.element)
+    }
+}
+
+/// The accessibility issues of `html`, in document order.
+pub fn check(html: &str) -> Vec<Issue> {
+    let tree = Tree::parse(html);
+    let mut issues = Vec::new();
+    let mut ids: HashMap<&str, usize> = HashMap::new();
+    for index in 1..tree.elements.len() {
+        if let Some(id) = tree.attribute(index, "id").filter(|id| !id.is_empty()) {
+            *ids.entry(id).or_default() += 1;
+        }
+    }
+
+    for index in 1..tree.elements.len() {
+        let mut issue = |rule: &'static str, message: String| {
+            issues.push(Issue {
+                rule,
+                element: tree.describe(index),
+                message,
+            })
+        };
+
+        if let Some(id) = tree
+            .attribute(index, "id")
+            .filter(|id| ids.get(id) > Some(&1))
+        {
+            issue(
+                "duplicate-id",
+                format!("the id `{}` is used more than once", id),
+            );
+        }
+        for (attribute, id) in tree.references(index) {
+            if !ids.contains_key(id) {
+                issue(
+                    "idref",
+                    format!("`{}` refers to the missing id `{}`", attribute, id),
+                );
+            }
+        }
+        if tree.is_hidden(index) {
+            continue;
+        }
+
+        let role = tree.role(index);
+        let role = role.as_deref();
+        match role {
+            Some("button") if tree.name(index).is_empty() => {
+                issue(
+                    "button-name",
+                    "the button has no accessible name".to_string(),
+                );
+            }
+            Some("checkbox" | "switch" | "radio") if tree.name(index).is_empty() => {
+                issue(
+                    "label",
+                    format!("the {} has no label", role.unwrap_or_default()),
+                );
+            }
+            Some(_) if tree.is_form_field(index) && tree.name(index).is_empty() => {
+                issue("label", "the form field has no label".to_string());
+            }
+            _ => {}
+        }
+        if let Some((role, parents)) = REQUIRED_PARENTS
+            .iter()
+            .find(|(required, _)| Some(*required) == role)
+        {
+            let parent = tree.parent_role(index);
+            if !parent
+                .as_deref()
+                .is_some_and(|parent| parents.contains(&parent))
+            {
+                issue(
+                    "aria-required-parent",
+                    format!("a `{}` must be inside a `{}`", role, parents.join("` or `")),
+                );
+            }
+        }
+        if role == Some("tablist") && !tree.has_descendant_role(index, "tab") {
+            issue(
+                "aria-required-children",
+                "the tablist has no tabs".to_string(),
+            );
+        }
+        for (attribute, roles) in ROLE_ATTRIBUTES {
+            if tree.attribute(index, attribute).is_some()
+                && !role.is_some_and(|role| roles.contains(&role))
+            {
+                issue(
+                    "aria-allowed-attr",
+                    format!(
+                        "`{}` isn't supported on {}",
+                        attribute,
+                        role.map_or("elements without a role".to_string(), |role| {
+                            format!("role `{}`", role)
+                        })
+                    ),
+                );
+            }
+        }
+    }
+    issues
+}
+
+/// Panics with every issue [`check`] finds in `html`.
+#[track_caller]
+pub fn assert_accessible(html: &str) {
+    let issues = check(html);
+    if !issues.is_empty() {
+        let list: Vec<String> = issues.iter().map(|issue| format!("- {}", issue)).collect();
+        panic!(
+            "{} accessibility issue(s):\n{}\nin:\n{}",
+            issues.len(),
+            list.join("\n"),
+            html
+        );
+    }
+}
+
+/// Panics unless a visible element of `html` has `role`, explicitly or by its tag, e.g. to make
+/// sure a dialog announces itself as one.
+#[track_caller]
+pub fn assert_role(html: &str, role: &str) {
+    let tree = Tree::parse(html);
+    let found = (1..tree.elements.len())
+        .any(|index| !tree.is_hidden(index) && tree.role(index).as_deref() == Some(role));
+    if !found {
+        panic!("no element with the role `{}` in:\n{}", role, html);
+    }
+}
+
+enum Node {
+    Element(usize),
+    Text(String),
+}
+
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    parent: Option<usize>,
+    children: Vec<Node>,
+}
+
+// The elements of a document, with a nameless root at index 0
+struct Tree {
+    elements: Vec<Element>,
+}
+
+impl Tree {
+    fn parse(html: &str) -> Self {
+        let mut elements = vec![Element {
+            name: String::new(),
+            attributes: Vec::new(),
+            parent: None,
+            children: Vec::new(),
+        }];
+        let mut open = vec![0];
+        let mut rest = html;
+        while !rest.is_empty() {
+            let parent = open.last().copied().unwrap_or_default();
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            } else if rest.starts_with("</") {
+                let end = tag_end(rest);
+                let name = rest[2..end]
+                    .trim_end_matches('>')
+                    .trim()
+                    .to_ascii_lowercase();
+                if let Some(depth) = open.iter().rposition(|&index| elements[index].name == name) {
+                    open.truncate(depth.max(1));
+                }
+                rest = &rest[end..];
+            } else if rest.starts_with('<') {
+                let end = tag_end(rest);
+                let tag = &rest[..end];
+                rest = &rest[end..];
+                let (name, attributes) = parse_tag(tag);
+                if name.is_empty() || name.starts_with('!') {
+                    continue;
+                }
+                let index = elements.len();
+                elements[parent].children.push(Node::Element(index));
+                if name == "script" || name == "style" {
+                    // Raw text, which may contain anything but its closing tag
+                    let close = format!("</{}", name);
+                    rest = &rest[rest.find(&close).unwrap_or(rest.len())..];
+                    open.push(index);
+                } else if !tag.ends_with("/>") && !VOID_ELEMENTS.contains(&name.as_str()) {
+                    open.push(index);
+                }
+                elements.push(Element {
+                    name,
+                    attributes,
+                    parent: Some(parent),
+                    children: Vec::new(),
+                });
+            } else {
+                let end = rest.find('<').unwrap_or(rest.len());
+                elements[parent]
+                    .children
+                    .push(Node::Text(decode(&rest[..end])));
+                rest = &rest[end..];
+            }
+        }
+        Tree { elements }
+    }
+
+    fn attribute(&self, index: usize, name: &str) -> Option<&str> {
+        self.elements[index]
+            .attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // The explicit role, else the implicit one of the tag
+    fn role(&self, index: usize) -> Option<String> {
+        if let Some(role) = self
+            .attribute(index, "role")
+            .and_then(|role| role.split_whitespace().next())
+        {
+            return Some(role.to_ascii_lowercase());
+        }
+        let role = match self.elements[index].name.as_str() {
+            "button" => "button",
+            "a" if self.attribute(index, "href").is_some() => "link",
+            "input" => match self.attribute(index, "type").unwrap_or("text") {
+                "hidden" => return None,
+                "checkbox" => "checkbox",
+                "radio" => "radio",
+                "range" => "slider",
+                "button" | "submit" | "reset" | "image" => "button",
+                _ => "textbox",
+            },
+            "select" if self.attribute(index, "multiple").is_some() => "listbox",
+            "select" => "combobox",
+            "textarea" => "textbox",
+            "ul" | "ol" => "list",
+            "li" => "listitem",
+            "table" => "table",
+            "tr" => "row",
+            "td" => "cell",
+            "th" => "columnheader",
+            "dialog" => "dialog",
+            "nav" => "navigation",
+            "img" => "img",
+            _ => return None,
+        };
+        Some(role.to_string())
+    }
+
+    // The role of the closest ancestor that isn't just structure
+    fn parent_role(&self, index: usize) -> Option<String> {
+        let mut current = self.elements[index].parent;
+        while let Some(ancestor) = current {
+            match self.role(ancestor).as_deref() {
+                None | Some("none" | "presentation" | "group") => {}
+                Some(role) => return Some(role.to_string()),
+            }
+            current = self.elements[ancestor].parent;
+        }
+        None
+    }
+
+    fn has_descendant_role(&self, index: usize, role: &str) -> bool {
+        self.elements[index]
+            .children
+            .iter()
+            .any(|child| match child {
+                Node::Element(child) => {
+                    self.role(*child).as_deref() == Some(role)
+                        || self.has_descendant_role(*child, role)
+                }
+                Node::Text(_) => false,
+            })
+    }
+
+    fn is_hidden(&self, index: usize) -> bool {
+        let mut current = Some(index);
+        while let Some(element) = current {
+            if self.attribute(element, "hidden").is_some()
+                || self.attribute(element, "aria-hidden") == Some("true")
+            {
+                return true;
+            }
+            current = self.elements[element].parent;
+        }
+        false
+    }
+
+    fn is_form_field(&self, index: usize) -> bool {
+        match self.elements[index].name.as_str() {
+            "select" | "textarea" => true,
+            "input" => !matches!(
+                self.attribute(index, "type"),
+                Some("hidden" | "button" | "submit" | "reset" | "image")
+            ),
+            _ => false,
+        }
+    }
+
+    // The accessible name, simplified from the accname algorithm
+    fn name(&self, index: usize) -> String {
+        let labelledby: Vec<String> = self
+            .attribute(index, "aria-labelledby")
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter_map(|id| self.by_id(id))
+            .map(|label| self.text(label))
+            .collect();
+        let candidates = [
+            labelledby.join(" "),
+            self.attribute(index, "aria-label")
+                .unwrap_or_default()
+                .to_string(),
+            self.labels(index)
+                .into_iter()
+                .map(|label| self.text(label))
+                .collect::<Vec<_>>()
+                .join(" "),
+            if self.is_form_field(index) {
+                self.attribute(index, "placeholder")
+                    .unwrap_or_default()
+                    .to_string()
+            } else {
+                self.text(index)
+            },
+            match (
+                self.elements[index].name.as_str(),
+                self.attribute(index, "type"),
+            ) {
+                ("input", Some("button" | "submit" | "reset")) => self
+                    .attribute(index, "value")
+                    .unwrap_or_default()
+                    .to_string(),
+                _ => String::new(),
+            },
+            self.attribute(index, "title")
+                .unwrap_or_default()
+                .to_string(),
+        ];
+        candidates
+            .into_iter()
+            .map(|name| name.split_whitespace().collect::<Vec<_>>().join(" "))
+            .find(|name| !name.is_empty())
+            .unwrap_or_default()
+    }
+
+    // The `<label>`s naming a labelable element: by `for` and around it
+    fn labels(&self, index: usize) -> Vec<usize> {
+        if !LABELABLE.contains(&self.elements[index].name.as_str()) {
+            return Vec::new();
+        }
+        let id = self.attribute(index, "id").filter(|id| !id.is_empty());
+        let mut labels: Vec<usize> = (1..self.elements.len())
+            .filter(|&label| {
+                self.elements[label].name == "label"
+                    && id.is_some()
+                    && self.attribute(label, "for") == id
+            })
+            .collect();
+        let mut current = self.elements[index].parent;
+        while let Some(ancestor) = current {
+            if self.elements[ancestor].name == "label" {
+                labels.push(ancestor);
+            }
+            current = self.elements[ancestor].parent;
+        }
+        labels
+    }
+
+    // The visible text of an element, using the names of the elements inside it
+    fn text(&self, index: usize) -> String {
+        let mut text = String::new();
+        for child in &self.elements[index].children {
+            match child {
+                Node::Text(content) => text.push_str(content),
+                Node::Element(child) => {
+                    if self.attribute(*child, "hidden").is_some()
+                        || self.attribute(*child, "aria-hidden") == Some("true")
+                    {
+                        continue;
+                    }
+                    let part = match (
+                        self.attribute(*child, "aria-label"),
+                        self.elements[*child].name.as_str(),
+                    ) {
+                        (Some(label), _) if !label.trim().is_empty() => label.to_string(),
+                        (_, "img") => self
+                            .attribute(*child, "alt")
+                            .unwrap_or_default()
+                            .to_string(),
+                        _ => self.text(*child),
+                    };
+                    text.push(' ');
+                    text.push_str(&part);
+                    text.push(' ');
+                }
+            }
+        }
+        text
+    }
+
+    fn by_id(&self, id: &str) -> Option<usize> {
+        (1..self.elements.len()).find(|&index| self.attribute(index, "id") == Some(id))
+    }
+
+    // The ids an element refers to, with the attribute doing so. `aria-controls` of a collapsed
+    // element may point at content that isn't rendered yet.
+    fn references(&self, index: usize) -> Vec<(&str, &str)> {
+        let collapsed = self.attribute(index, "aria-expanded") == Some("false");
+        let mut references = Vec::new();
+        for (attribute, value) in &self.elements[index].attributes {
+            let refers = match attribute.as_str() {
+                "for" => self.elements[index].name == "label",
+                "aria-labelledby" | "aria-describedby" | "aria-activedescendant" => true,
+                "aria-controls" => !collapsed,
+                _ => false,
+            };
+            if refers {
+                references.extend(value.split_whitespace().map(|id| (attribute.as_str(), id)));
+            }
+        }
+        references
+    }
+
+    // The opening tag without classes, to point at an element in messages
+    fn describe(&self, index: usize) -> String {
+        let element = &self.elements[index];
+        let attributes: String = element
+            .attributes
+            .iter()
+            .filter(|(key, _)| key != "class" && key != "style")
+            .map(|(key, value)| format!(" {}=\"{}\"", key, value))
+            .collect();
+        format!("<{}{}>", element.name, attributes)
+    }
+}
+
+// The lowercase name and the attributes of an opening tag
+fn parse_tag(tag: &str) -> (String, Vec<(String, String)>) {
+    let inner = tag
+        .trim_start_matches('<')
+        .trim_end_matches('>')
+        .trim_end_matches('/');
+    let name_end = inner.find(char::is_whitespace).unwrap_or(inner.len());
+    let name = inner[..name_end].to_ascii_lowercase();
+    let mut attributes = Vec::new();
+    let mut rest = inner[name_end..].trim_start();
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=')
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_ascii_lowercase();
+        rest = rest[key_end..].trim_start();
+        let mut value = "";
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            match after.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let end = after[1..].find(quote).map_or(after.len(), |end| end + 1);
+                    value = &after[1..end];
+                    rest = after.get(end + 1..).unwrap_or_default();
+                }
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    value = &after[..end];
+                    rest = &after[end..];
+                }
+            }
+        }
+        if !key.is_empty() {
+            attributes.push((key, decode(value)));
+        }
+        rest = rest.trim_start();
+    }
+    (name, attributes)
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,220 @@
+//! Renders components to HTML on the host through yew's server renderer and compares the result
+//! with stored snapshots, so class regressions show up in plain `cargo test`:
+//!
+//! ```ignore
+//! use wonopui::testing::{assert_snapshot, render};
+//!
+//! #[test]
+//! fn primary_button() {
+//!     assert_snapshot("primary_button", &render(|| html! { <Button>{"Save"}</Button> }));
+//! }
+//! ```
+//!
+//! Snapshots live in `tests/snapshots/<name>.html` of the crate under test. A missing snapshot
+//! fails like a changed one; `WONOPUI_UPDATE_SNAPSHOTS=1` writes new snapshots and accepts the
+//! changed ones.
+//!
+//! [`a11y`] checks the same rendered HTML for common accessibility issues.
+
+pub mod a11y;
+
+use crate::components::utils::UniqueIdProvider;
+#[cfg(feature = "ThemeProvider")]
+use crate::config::ThemeProvider;
+#[cfg(feature = "ThemeProvider")]
+use crate::themes::Theme;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew::ServerRenderer;
+
+const UPDATE_SNAPSHOTS: &str = "WONOPUI_UPDATE_SNAPSHOTS";
+
+// Elements without a closing tag, which don't indent what follows
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Renders `view` with the classes of the static `BRANDGUIDE` and returns the normalized HTML.
+pub fn render(view: impl Fn() -> Html + Send + 'static) -> String {
+    render_root(move || RootProps {
+        #[cfg(feature = "ThemeProvider")]
+        theme: None,
+        view: Rc::new(view),
+    })
+}
+
+/// Renders `view` inside a `ThemeProvider` using `theme`, e.g. one made with `Theme::new` from
+/// a few `wonopui.json` entries, and returns the normalized HTML.
+#[cfg(feature = "ThemeProvider")]
+pub fn render_with_theme(theme: Theme, view: impl Fn() -> Html + Send + 'static) -> String {
+    render_root(move || RootProps {
+        theme: Some(theme),
+        view: Rc::new(view),
+    })
+}
+
+/// Compares `html` with the snapshot `name`. Panics with a line diff when they differ, or when
+/// the snapshot doesn't exist, unless `WONOPUI_UPDATE_SNAPSHOTS` is set to write it instead.
+#[track_caller]
+pub fn assert_snapshot(name: &str, html: &str) {
+    let path = snapshot_dir().join(format!("{}.html", name));
+    let actual = format!("{}\n", html.trim_end());
+    let expected = fs::read_to_string(&path).ok();
+    if expected.as_deref() == Some(actual.as_str()) {
+        return;
+    }
+    if env::var_os(UPDATE_SNAPSHOTS).is_some() {
+        fs::create_dir_all(path.parent().expect("snapshot path has a parent"))
+            .expect("Failed to create the snapshot directory");
+        fs::write(&path, &actual)
+            .unwrap_or_else(|err| panic!("Failed to write {}: {}", path.display(), err));
+        eprintln!("wrote snapshot {}", path.display());
+        return;
+    }
+    let Some(expected) = expected else {
+        panic!(
+            "snapshot `{}` is missing, expected at {} (set {}=1 to write it)",
+            name,
+            path.display(),
+            UPDATE_SNAPSHOTS
+        );
+    };
+    panic!(
+        "snapshot `{}` differs from {} (set {}=1 to accept the new output):\n{}",
+        name,
+        path.display(),
+        UPDATE_SNAPSHOTS,
+        line_diff(&expected, &actual)
+    );
+}
+
+/// One tag or text per line, indented by nesting, with runs of whitespace collapsed, so
+/// snapshots don't depend on how the markup was written and diff line by line.
+pub fn normalize(html: &str) -> String {
+    let mut lines = Vec::new();
+    let mut depth = 0usize;
+    let mut rest = html;
+    while !rest.is_empty() {
+        let (token, tail) = if rest.starts_with('<') {
+            rest.split_at(tag_end(rest))
+        } else {
+            rest.split_at(rest.find('<').unwrap_or(rest.len()))
+        };
+        rest = tail;
+        let token = token.split_whitespace().collect::<Vec<_>>().join(" ");
+        if token.is_empty() {
+            continue;
+        }
+        if token.starts_with("</") {
+            depth = depth.saturating_sub(1);
+            lines.push(format!("{}{}", "  ".repeat(depth), token));
+        } else if token.starts_with('<') && !token.starts_with("<!") {
+            let token = token.replace("=\" ", "=\"").replace(" \"", "\"");
+            lines.push(format!("{}{}", "  ".repeat(depth), token));
+            let name = token[1..]
+                .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            if !token.ends_with("/>") && !VOID_ELEMENTS.contains(&name.as_str()) {
+                depth += 1;
+            }
+        } else {
+            lines.push(format!("{}{}", "  ".repeat(depth), token));
+        }
+    }
+    lines.join("\n")
+}
+
+#[derive(Properties)]
+struct RootProps {
+    #[cfg(feature = "ThemeProvider")]
+    theme: Option<Theme>,
+    view: Rc<dyn Fn() -> Html>,
+}
+
+impl PartialEq for RootProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.view, &other.view)
+    }
+}
+
+#[function_component(Root)]
+fn root(props: &RootProps) -> Html {
+    let view = html! { <UniqueIdProvider>{(props.view)()}</UniqueIdProvider> };
+    #[cfg(feature = "ThemeProvider")]
+    {
+        let themes: Vec<Theme> = props.theme.iter().cloned().collect();
+        let default_theme = props.theme.as_ref().map(|theme| theme.name.clone());
+        html! {
+            <ThemeProvider {themes} {default_theme} storage_key={None::<AttrValue>}>
+                {view}
+            </ThemeProvider>
+        }
+    }
+    #[cfg(not(feature = "ThemeProvider"))]
+    view
+}
+
+// The props are made on the renderer's thread, since views aren't `Send` once wrapped
+fn render_root(props: impl FnOnce() -> RootProps + Send + 'static) -> String {
+    let renderer = ServerRenderer::<Root>::with_props(props).hydratable(false);
+    normalize(&futures::executor::block_on(renderer.render()))
+}
+
+// `tests/snapshots` of the crate whose tests are running
+fn snapshot_dir() -> PathBuf {
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap_or_else(|| ".".into());
+    PathBuf::from(manifest_dir).join("tests").join("snapshots")
+}
+
+// Length of the tag at the start of `html`, ignoring `>` inside quoted attribute values
+fn tag_end(html: &str) -> usize {
+    let mut quote = None;
+    for (i, c) in html.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if c == open => quote = None,
+            (None, '>') => return i + 1,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+// The lines only in `expected` as `-` and only in `actual` as `+`, around the common ones
+fn line_diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+    // Longest common subsequence lengths of every pair of suffixes
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            diff.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    diff.join("\n")
+}
@@ -0,0 +1,98 @@
+use crate::component_editor::BrandGuideValueAccessor;
+use crate::config::{BrandGuide, THEME_TEMPLATES, THEME_TOKENS};
+use crate::tokens::json_entries;
+use yew::Callback;
+
+/// A named set of brand guide entries and tokens that `ThemeProvider` can switch to.
+///
+/// Entries use the format of `wonopui.json`: any subset of the keys, each a template that may
+/// refer to tokens as `{{ name }}`. Everything not listed keeps the build-time value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    entries: Vec<(String, String)>,
+}
+
+impl Theme {
+    /// Name of the theme built into the crate, used when no other theme is chosen.
+    pub const DEFAULT: &'static str = "default";
+
+    pub fn new<K: Into<String>, V: Into<String>>(
+        name: impl Into<String>,
+        entries: impl IntoIterator<Item = (K, V)>,
+    ) -> Self {
+        Theme {
+            name: name.into(),
+            entries: entries
+                .into_iter()
+                .map(|(key, template)| (key.into(), template.into()))
+                .collect(),
+        }
+    }
+
+    /// Parses a theme in the `wonopui.json` format, e.g. one compiled in with `include_str!`,
+    /// including the keys of custom components under `"components"`.
+    pub fn from_json(name: impl Into<String>, json: &str) -> Result<Self, serde_json::Error> {
+        let entries = json_entries(serde_json::from_str(json)?)?;
+        Ok(Theme::new(name, entries))
+    }
+
+    /// Loads a theme in the `wonopui.json` format from `url`.
+    pub async fn fetch(name: impl Into<String>, url: &str) -> Result<Self, gloo::net::Error> {
+        let json: serde_json::Value = gloo::net::http::Request::get(url)
+            .send()
+            .await?
+            .json()
+            .await?;
+        Ok(Theme::new(name, json_entries(json)?))
+    }
+
+    /// A theme with every entry of `brandguide`.
+    pub fn from_brandguide(name: impl Into<String>, brandguide: &BrandGuide<String>) -> Self {
+        let entries = THEME_TEMPLATES
+            .iter()
+            .filter(|(key, _)| !THEME_TOKENS.contains(key))
+            .map(|(key, _)| (key.to_string(), brandguide.get_value(key)));
+        Theme::new(name, entries)
+    }
+
+    pub fn entries(&self) -> &[(String, String)] {
+        &self.entries
+    }
+}
+
+/// The themes of a `ThemeProvider` and the one in use, returned by `use_theme()`.
+#[derive(Clone, PartialEq)]
+pub struct ThemeHandle {
+    pub(crate) current: String,
+    pub(crate) names: Vec<String>,
+    pub(crate) set_theme: Callback<Option<String>>,
+    pub(crate) register_theme: Callback<Theme>,
+}
+
+impl ThemeHandle {
+    /// Name of the theme in use.
+    pub fn current(&self) -> &str {
+        &self.current
+    }
+
+    /// Names of all themes, starting with [`Theme::DEFAULT`].
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Switches to the theme called `name` and remembers the choice.
+    pub fn set(&self, name: impl Into<String>) {
+        self.set_theme.emit(Some(name.into()));
+    }
+
+    /// Forgets the chosen theme, going back to the default or the high-contrast theme.
+    pub fn reset(&self) {
+        self.set_theme.emit(None);
+    }
+
+    /// Adds a theme, or replaces the one with the same name.
+    pub fn register(&self, theme: Theme) {
+        self.register_theme.emit(theme);
+    }
+}
@@ -0,0 +1,342 @@
+use crate::component_editor::BrandGuideValueAccessor;
+#[cfg(feature = "CssVariables")]
+use crate::config::CSS_VARIABLES;
+use crate::config::{
+    BrandField, BrandGuide, BRANDGUIDE, THEME_OVERRIDES, THEME_TEMPLATES, THEME_TOKENS,
+};
+use std::collections::{HashMap, HashSet};
+
+/// An entry whose template differs from the build-time config.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeChange {
+    pub key: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// The design tokens (colors, shades, opacities, rounding, ...) the brand guide is built from.
+///
+/// Tokens are the same templates as in `wonopui.json`: a token may refer to earlier tokens as
+/// `{{ name }}`. Changing a token re-renders every template that depends on it, and
+/// [`ThemeTokens::brandguide`] derives a complete `BrandGuide` from the result.
+///
+/// Tailwind only generates the classes it finds at build time, so a runtime value such as
+/// `emerald-600` only takes effect if the classes it produces are part of your CSS. With the
+/// `CssVariables` feature the brand colors are CSS variables instead, which can be set to any
+/// color at runtime.
+#[derive(Clone, PartialEq)]
+pub struct ThemeTokens {
+    overrides: HashMap<String, String>,
+    values: HashMap<String, String>,
+    #[cfg(feature = "CssVariables")]
+    variables: Vec<(String, String)>,
+}
+
+impl Default for ThemeTokens {
+    fn default() -> Self {
+        let mut tokens = ThemeTokens {
+            overrides: HashMap::new(),
+            values: HashMap::new(),
+            #[cfg(feature = "CssVariables")]
+            variables: CSS_VARIABLES
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        };
+        tokens.render();
+        tokens
+    }
+}
+
+impl ThemeTokens {
+    /// Names of all tokens, in definition order.
+    pub fn names(&self) -> &'static [&'static str] {
+        THEME_TOKENS
+    }
+
+    /// The rendered value of a token or brand guide entry.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    /// The template a token or brand guide entry is rendered from.
+    pub fn template(&self, name: &str) -> Option<&str> {
+        self.overrides.get(name).map(String::as_str).or_else(|| {
+            THEME_TEMPLATES
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, template)| *template)
+        })
+    }
+
+    /// Replaces the template of `name` and re-renders everything that depends on it.
+    ///
+    /// With the `CssVariables` feature, `name` may also be a color variable such as
+    /// `--wonop-primary` (or just `primary`), which changes the variable instead.
+    pub fn set(&mut self, name: &str, template: impl Into<String>) {
+        if self.insert(name, template.into()) {
+            self.render();
+        }
+    }
+
+    /// Sets several templates at once, e.g. the entries of a [`Theme`](crate::Theme).
+    pub fn set_all<'a>(&mut self, entries: impl IntoIterator<Item = &'a (String, String)>) {
+        let mut changed = false;
+        for (name, template) in entries {
+            changed |= self.insert(name, template.clone());
+        }
+        if changed {
+            self.render();
+        }
+    }
+
+    /// Restores the build-time template of `name`.
+    pub fn reset(&mut self, name: &str) {
+        if self.overrides.remove(name).is_some() {
+            self.render();
+        }
+    }
+
+    /// Tokens `name` is built from, directly or through other tokens.
+    pub fn references(&self, name: &str) -> Vec<&'static str> {
+        let mut found = HashSet::new();
+        let mut pending = vec![name.to_string()];
+        while let Some(current) = pending.pop() {
+            let Some(template) = self.template(&current) else {
+                continue;
+            };
+            for reference in placeholders(template) {
+                if let Some(token) = THEME_TOKENS.iter().find(|token| **token == reference) {
+                    if found.insert(*token) {
+                        pending.push(reference.to_string());
+                    }
+                }
+            }
+        }
+        // Keep definition order so the most basic tokens come first
+        THEME_TOKENS
+            .iter()
+            .copied()
+            .filter(|token| found.contains(token))
+            .collect()
+    }
+
+    /// The color variables and their current values.
+    #[cfg(feature = "CssVariables")]
+    pub fn variables(&self) -> &[(String, String)] {
+        &self.variables
+    }
+
+    /// The current color of a variable, given as `--wonop-primary` or `primary`.
+    #[cfg(feature = "CssVariables")]
+    pub fn variable(&self, name: &str) -> Option<&str> {
+        let variable = self.variable_name(name)?;
+        self.variables
+            .iter()
+            .find(|(n, _)| *n == variable)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The full name of a color variable, if `name` refers to one.
+    #[cfg(feature = "CssVariables")]
+    pub fn variable_name(&self, name: &str) -> Option<String> {
+        let variable = if name.starts_with("--") {
+            name.to_string()
+        } else {
+            format!("--wonop-{}", name)
+        };
+        self.variables
+            .iter()
+            .any(|(n, _)| *n == variable)
+            .then_some(variable)
+    }
+
+    /// Color variables the rendered value of `name` uses.
+    #[cfg(feature = "CssVariables")]
+    pub fn variables_of(&self, name: &str) -> Vec<&str> {
+        let value = self.get(name).unwrap_or_default();
+        self.variables
+            .iter()
+            .filter(|(n, _)| value.contains(&format!("var({})", n)))
+            .map(|(n, _)| n.as_str())
+            .collect()
+    }
+
+    /// Entries and variables that differ from the build-time config, in definition order.
+    pub fn changes(&self) -> Vec<ThemeChange> {
+        let changes: Vec<ThemeChange> = THEME_TEMPLATES
+            .iter()
+            .filter_map(|(key, before)| {
+                let after = self.overrides.get(*key)?;
+                (after != before).then(|| ThemeChange {
+                    key: key.to_string(),
+                    before: before.to_string(),
+                    after: after.clone(),
+                })
+            })
+            .collect();
+        #[cfg(feature = "CssVariables")]
+        let changes = {
+            let mut changes = changes;
+            changes.extend(
+                CSS_VARIABLES
+                    .iter()
+                    .zip(&self.variables)
+                    .filter(|((_, before), (_, after))| after != before)
+                    .map(|((name, before), (_, after))| ThemeChange {
+                        key: name.to_string(),
+                        before: before.to_string(),
+                        after: after.clone(),
+                    }),
+            );
+            changes
+        };
+        changes
+    }
+
+    /// The config as a `wonopui.json`: the entries of the build-time file plus the changes, with
+    /// the keys of custom components under `"components"`.
+    pub fn to_json(&self) -> String {
+        let changes = self.changes();
+        let mut entries: Vec<(&str, &str)> = THEME_OVERRIDES.to_vec();
+        for change in &changes {
+            match entries.iter_mut().find(|(key, _)| *key == change.key) {
+                Some(entry) => entry.1 = &change.after,
+                None => entries.push((&change.key, &change.after)),
+            }
+        }
+        // Keep the order of the defaults rather than the order things were changed in
+        let position = |key: &str| THEME_TEMPLATES.iter().position(|(k, _)| *k == key);
+        entries.sort_by_key(|(key, _)| position(key).unwrap_or(usize::MAX));
+        let quote = |text: &str| serde_json::Value::from(text).to_string();
+        let mut lines: Vec<String> = Vec::new();
+        let mut components: Vec<(&str, Vec<String>)> = Vec::new();
+        for (key, template) in entries {
+            match BrandField::find(key).filter(|field| field.custom) {
+                Some(field) => {
+                    let line = format!("      {}: {}", quote(key), quote(template));
+                    match components.iter_mut().find(|(c, _)| *c == field.component) {
+                        Some((_, keys)) => keys.push(line),
+                        None => components.push((field.component, vec![line])),
+                    }
+                }
+                None => lines.push(format!("  {}: {}", quote(key), quote(template))),
+            }
+        }
+        if !components.is_empty() {
+            let components: Vec<String> = components
+                .iter()
+                .map(|(component, keys)| {
+                    format!("    {}: {{\n{}\n    }}", quote(component), keys.join(",\n"))
+                })
+                .collect();
+            lines.push(format!(
+                "  \"components\": {{\n{}\n  }}",
+                components.join(",\n")
+            ));
+        }
+        if lines.is_empty() {
+            return "{}\n".to_string();
+        }
+        format!("{{\n{}\n}}\n", lines.join(",\n"))
+    }
+
+    /// Tokens with the entries of a `wonopui.json` applied to the build-time config, including
+    /// the keys of custom components under `"components"`. Unknown keys are ignored.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let entries = json_entries(serde_json::from_str(json)?)?;
+        let mut tokens = ThemeTokens::default();
+        tokens.set_all(&entries);
+        Ok(tokens)
+    }
+
+    /// A brand guide with every entry rendered from the current tokens.
+    pub fn brandguide(&self) -> BrandGuide<String> {
+        let mut brandguide = BRANDGUIDE.to_owned();
+        for (key, _) in THEME_TEMPLATES {
+            if THEME_TOKENS.contains(key) {
+                continue;
+            }
+            if let Some(value) = self.values.get(*key) {
+                brandguide.set_value(key, value.clone());
+            }
+        }
+        brandguide
+    }
+
+    // Stores a template or variable, returning whether the values need rendering again.
+    fn insert(&mut self, name: &str, template: String) -> bool {
+        #[cfg(feature = "CssVariables")]
+        if let Some(variable) = self.variable_name(name) {
+            if let Some(entry) = self.variables.iter_mut().find(|(n, _)| *n == variable) {
+                entry.1 = template;
+            }
+            return false;
+        }
+        if !THEME_TEMPLATES.iter().any(|(key, _)| *key == name) {
+            return false;
+        }
+        self.overrides.insert(name.to_string(), template);
+        true
+    }
+
+    fn render(&mut self) {
+        let mut values = HashMap::with_capacity(THEME_TEMPLATES.len());
+        for (key, template) in THEME_TEMPLATES {
+            let template = self.overrides.get(*key).map_or(*template, String::as_str);
+            let rendered = interpolate(template, &values);
+            values.insert(key.to_string(), rendered);
+        }
+        self.values = values;
+    }
+}
+
+// The entries of a `wonopui.json`, with the keys of the custom components under `"components"`
+// flattened in among the others.
+pub(crate) fn json_entries(
+    json: serde_json::Value,
+) -> Result<Vec<(String, String)>, serde_json::Error> {
+    let mut entries: HashMap<String, serde_json::Value> = serde_json::from_value(json)?;
+    let components: HashMap<String, HashMap<String, String>> = match entries.remove("components") {
+        Some(components) => serde_json::from_value(components)?,
+        None => HashMap::new(),
+    };
+    let mut entries: Vec<(String, String)> = entries
+        .into_iter()
+        .map(|(key, value)| Ok((key, serde_json::from_value(value)?)))
+        .collect::<Result<_, serde_json::Error>>()?;
+    entries.extend(components.into_values().flatten());
+    Ok(entries)
+}
+
+// Renders `{{ name }}` placeholders. Unknown names render as nothing.
+fn interpolate(template: &str, values: &HashMap<String, String>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        let name = rest[start + 2..start + end].trim();
+        if let Some(value) = values.get(name) {
+            output.push_str(value);
+        }
+        rest = &rest[start + end + 2..];
+    }
+    output.push_str(rest);
+    output
+}
+
+fn placeholders(template: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        names.push(rest[start + 2..start + end].trim());
+        rest = &rest[start + end + 2..];
+    }
+    names
+}
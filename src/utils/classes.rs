@@ -1,12 +1,13 @@
 // Utility module for CSS class handling
 
-use yew::Classes;
+use std::collections::HashSet;
+use yew::{classes, Classes};
 
 /// Combines multiple class strings into a single Classes instance
 pub fn combine_classes(classes: &[&str]) -> Classes {
     let mut result = Classes::new();
     for class in classes {
-        result.push(*class);
+        result.push(class.to_string());
     }
     result
 }
@@ -14,8 +15,616 @@ pub fn combine_classes(classes: &[&str]) -> Classes {
 /// Conditionally add a class
 pub fn conditional_class(condition: bool, class: &str) -> Classes {
     if condition {
-        classes!(class)
+        classes!(class.to_string())
     } else {
         Classes::new()
     }
-}
\ No newline at end of file
+}
+
+/// Drops the classes that a later class overrides, so the last one wins like in Tailwind.
+///
+/// Two classes conflict when they set the same property under the same variants, e.g.
+/// `bg-indigo-500` and `bg-red-500`, or `hover:px-2` and `hover:p-4`. Components merge their
+/// theme classes with `props.class` this way, so `class="bg-red-500"` replaces the background
+/// instead of depending on the CSS order. Classes that aren't recognised are always kept.
+pub fn tw_merge(classes: impl Into<Classes>) -> Classes {
+    let classes: Vec<_> = classes.into().into_iter().collect();
+    let mut seen = HashSet::new();
+    let mut kept = Vec::with_capacity(classes.len());
+    for class in classes.into_iter().rev() {
+        let (variants, base) = split_variants(&class);
+        let (important, base) = match base.strip_prefix('!') {
+            Some(base) => (true, base),
+            None => match base.strip_suffix('!') {
+                Some(base) => (true, base),
+                None => (false, base),
+            },
+        };
+        let Some(group) = class_group(base) else {
+            kept.push(class);
+            continue;
+        };
+        let mut variants = variants;
+        variants.sort_unstable();
+        let prefix = format!(
+            "{}{}",
+            variants.join(":"),
+            if important { "!" } else { ":" }
+        );
+        if !seen.insert(format!("{}{}", prefix, group)) {
+            continue;
+        }
+        for overridden in conflicting_groups(&group) {
+            seen.insert(format!("{}{}", prefix, overridden));
+        }
+        kept.push(class);
+    }
+    kept.into_iter().rev().collect()
+}
+
+// Splits `dark:hover:bg-red-500` into its variants and the utility, ignoring colons inside
+// arbitrary values such as `[&:hover]`.
+fn split_variants(class: &str) -> (Vec<&str>, &str) {
+    let mut variants = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in class.char_indices() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth -= 1,
+            ':' if depth == 0 => {
+                variants.push(&class[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    (variants, &class[start..])
+}
+
+const STANDALONE: &[(&str, &str)] = &[
+    ("block", "display"),
+    ("inline-block", "display"),
+    ("inline", "display"),
+    ("flex", "display"),
+    ("inline-flex", "display"),
+    ("table", "display"),
+    ("inline-table", "display"),
+    ("table-caption", "display"),
+    ("table-cell", "display"),
+    ("table-column", "display"),
+    ("table-column-group", "display"),
+    ("table-footer-group", "display"),
+    ("table-header-group", "display"),
+    ("table-row-group", "display"),
+    ("table-row", "display"),
+    ("flow-root", "display"),
+    ("grid", "display"),
+    ("inline-grid", "display"),
+    ("contents", "display"),
+    ("list-item", "display"),
+    ("hidden", "display"),
+    ("static", "position"),
+    ("fixed", "position"),
+    ("absolute", "position"),
+    ("relative", "position"),
+    ("sticky", "position"),
+    ("visible", "visibility"),
+    ("invisible", "visibility"),
+    ("collapse", "visibility"),
+    ("underline", "text-decoration"),
+    ("overline", "text-decoration"),
+    ("line-through", "text-decoration"),
+    ("no-underline", "text-decoration"),
+    ("uppercase", "text-transform"),
+    ("lowercase", "text-transform"),
+    ("capitalize", "text-transform"),
+    ("normal-case", "text-transform"),
+    ("italic", "font-style"),
+    ("not-italic", "font-style"),
+    ("truncate", "text-overflow"),
+    ("sr-only", "sr"),
+    ("not-sr-only", "sr"),
+    ("isolate", "isolation"),
+    ("isolation-auto", "isolation"),
+    ("antialiased", "font-smoothing"),
+    ("subpixel-antialiased", "font-smoothing"),
+    ("grow", "grow"),
+    ("shrink", "shrink"),
+];
+
+// Utilities that take a value. Each comes before any utility it starts with, so `border-t` is
+// tried before `border`.
+const PREFIXES: &[&str] = &[
+    "underline-offset",
+    "pointer-events",
+    "border-spacing",
+    "outline-offset",
+    "place-content",
+    "justify-items",
+    "place-items",
+    "place-self",
+    "justify-self",
+    "ring-offset",
+    "will-change",
+    "line-clamp",
+    "translate-x",
+    "translate-y",
+    "overflow-x",
+    "overflow-y",
+    "overscroll",
+    "decoration",
+    "appearance",
+    "transition",
+    "rounded-tl",
+    "rounded-tr",
+    "rounded-br",
+    "rounded-bl",
+    "rounded-ss",
+    "rounded-se",
+    "rounded-es",
+    "rounded-ee",
+    "grid-flow",
+    "grid-cols",
+    "grid-rows",
+    "col-start",
+    "row-start",
+    "auto-cols",
+    "auto-rows",
+    "mix-blend",
+    "rounded-t",
+    "rounded-r",
+    "rounded-b",
+    "rounded-l",
+    "rounded-s",
+    "rounded-e",
+    "row-span",
+    "col-span",
+    "border-x",
+    "border-y",
+    "border-t",
+    "border-r",
+    "border-b",
+    "border-l",
+    "border-s",
+    "border-e",
+    "bg-blend",
+    "divide-x",
+    "divide-y",
+    "tracking",
+    "overflow",
+    "duration",
+    "col-end",
+    "row-end",
+    "inset-x",
+    "inset-y",
+    "space-x",
+    "space-y",
+    "justify",
+    "leading",
+    "rounded",
+    "opacity",
+    "animate",
+    "scale-x",
+    "scale-y",
+    "columns",
+    "content",
+    "outline",
+    "whitespace",
+    "skew-x",
+    "skew-y",
+    "rotate",
+    "origin",
+    "cursor",
+    "select",
+    "resize",
+    "object",
+    "aspect",
+    "accent",
+    "border",
+    "divide",
+    "shadow",
+    "stroke",
+    "bottom",
+    "indent",
+    "gap-x",
+    "gap-y",
+    "min-w",
+    "max-w",
+    "min-h",
+    "max-h",
+    "inset",
+    "right",
+    "start",
+    "order",
+    "basis",
+    "items",
+    "align",
+    "break",
+    "delay",
+    "scale",
+    "float",
+    "clear",
+    "caret",
+    "shrink",
+    "grow",
+    "flex",
+    "size",
+    "left",
+    "self",
+    "text",
+    "font",
+    "list",
+    "from",
+    "ring",
+    "ease",
+    "fill",
+    "box",
+    "gap",
+    "top",
+    "end",
+    "via",
+    "col",
+    "row",
+    "px",
+    "py",
+    "pt",
+    "pr",
+    "pb",
+    "pl",
+    "ps",
+    "pe",
+    "mx",
+    "my",
+    "mt",
+    "mr",
+    "mb",
+    "ml",
+    "ms",
+    "me",
+    "bg",
+    "to",
+    "p",
+    "m",
+    "w",
+    "h",
+    "z",
+];
+
+const FONT_SIZES: &[&str] = &[
+    "xs", "sm", "base", "lg", "xl", "2xl", "3xl", "4xl", "5xl", "6xl", "7xl", "8xl", "9xl",
+];
+const FONT_WEIGHTS: &[&str] = &[
+    "thin",
+    "extralight",
+    "light",
+    "normal",
+    "medium",
+    "semibold",
+    "bold",
+    "extrabold",
+    "black",
+];
+const BORDER_STYLES: &[&str] = &["solid", "dashed", "dotted", "double", "hidden", "none"];
+const SHADOW_SIZES: &[&str] = &["", "xs", "sm", "md", "lg", "xl", "2xl", "inner", "none"];
+
+// The property a utility sets, or `None` if it isn't recognised.
+fn class_group(base: &str) -> Option<String> {
+    let base = base.strip_prefix('-').unwrap_or(base);
+    if let Some(property) = base.strip_prefix('[') {
+        // Arbitrary property such as `[mask-type:luminance]`
+        return property
+            .split_once(':')
+            .map(|(property, _)| format!("[{}]", property));
+    }
+    if let Some((_, group)) = STANDALONE.iter().find(|(class, _)| *class == base) {
+        return Some(group.to_string());
+    }
+    let (prefix, value) = PREFIXES.iter().find_map(|prefix| {
+        let value = base.strip_prefix(prefix)?;
+        match value.strip_prefix('-') {
+            Some(value) => Some((*prefix, value)),
+            None if value.is_empty() => Some((*prefix, value)),
+            None => None,
+        }
+    })?;
+    let group = match prefix {
+        "flex" => match value {
+            "row" | "row-reverse" | "col" | "col-reverse" => "flex-direction",
+            "wrap" | "wrap-reverse" | "nowrap" => "flex-wrap",
+            _ if value.starts_with("grow") => "grow",
+            _ if value.starts_with("shrink") => "shrink",
+            _ => "flex",
+        },
+        "text" => match value {
+            "left" | "center" | "right" | "justify" | "start" | "end" => "text-align",
+            "ellipsis" | "clip" => "text-overflow",
+            "wrap" | "nowrap" | "balance" | "pretty" => "text-wrap",
+            _ if value.starts_with("opacity-") => "text-opacity",
+            _ if FONT_SIZES.contains(&value.split('/').next().unwrap_or(value)) => "font-size",
+            _ if is_length(value) => "font-size",
+            _ => "text-color",
+        },
+        "font" => match value {
+            "sans" | "serif" | "mono" => "font-family",
+            _ if FONT_WEIGHTS.contains(&value) || is_number(value) => "font-weight",
+            _ => "font-family",
+        },
+        "bg" => match value {
+            "fixed" | "local" | "scroll" => "bg-attachment",
+            "bottom" | "center" | "left" | "left-bottom" | "left-top" | "right"
+            | "right-bottom" | "right-top" | "top" => "bg-position",
+            "repeat" | "no-repeat" | "repeat-x" | "repeat-y" | "repeat-round" | "repeat-space" => {
+                "bg-repeat"
+            }
+            "auto" | "cover" | "contain" => "bg-size",
+            "none" => "bg-image",
+            _ if value.starts_with("gradient-") => "bg-image",
+            _ if value.starts_with("clip-") => "bg-clip",
+            _ if value.starts_with("origin-") => "bg-origin",
+            _ if value.starts_with("opacity-") => "bg-opacity",
+            _ if value.starts_with("[url(") || value.contains("gradient(") => "bg-image",
+            _ if value.starts_with("[length:") || value.starts_with("[size:") => "bg-size",
+            _ if value.starts_with("[position:") => "bg-position",
+            _ => "bg-color",
+        },
+        "from" | "via" | "to" => {
+            let kind = if value.ends_with('%') {
+                "position"
+            } else {
+                "color"
+            };
+            return Some(format!("{}-{}", prefix, kind));
+        }
+        "border" | "border-x" | "border-y" | "border-t" | "border-r" | "border-b" | "border-l"
+        | "border-s" | "border-e" => {
+            let side = &prefix["border".len()..];
+            let kind = match value {
+                "collapse" | "separate" if side.is_empty() => "collapse",
+                _ if side.is_empty() && BORDER_STYLES.contains(&value) => "style",
+                _ if value.starts_with("opacity-") => "opacity",
+                _ if value.is_empty() || is_number(value) || is_length(value) => "w",
+                _ => "color",
+            };
+            return Some(format!("border-{}{}", kind, side));
+        }
+        "divide" => match value {
+            _ if BORDER_STYLES.contains(&value) => "divide-style",
+            _ => "divide-color",
+        },
+        "outline" => match value {
+            "" | "none" | "dashed" | "dotted" | "double" => "outline-style",
+            _ if is_number(value) || is_length(value) => "outline-w",
+            _ => "outline-color",
+        },
+        "ring" => match value {
+            "inset" => "ring-inset",
+            _ if value.starts_with("opacity-") => "ring-opacity",
+            _ if value.is_empty() || is_number(value) || is_length(value) => "ring-w",
+            _ => "ring-color",
+        },
+        "ring-offset" => match value {
+            _ if is_number(value) || is_length(value) => "ring-offset-w",
+            _ => "ring-offset-color",
+        },
+        "shadow" => match value {
+            _ if SHADOW_SIZES.contains(&value) => "shadow",
+            _ if value.starts_with('[') && value.contains('_') => "shadow",
+            _ => "shadow-color",
+        },
+        "decoration" => match value {
+            "solid" | "double" | "dotted" | "dashed" | "wavy" => "decoration-style",
+            "slice" | "clone" => "decoration-break",
+            "auto" | "from-font" => "decoration-thickness",
+            _ if is_number(value) || is_length(value) => "decoration-thickness",
+            _ => "decoration-color",
+        },
+        "stroke" => match value {
+            _ if is_number(value) || is_length(value) => "stroke-w",
+            _ => "stroke",
+        },
+        "object" => match value {
+            "contain" | "cover" | "fill" | "none" | "scale-down" => "object-fit",
+            _ => "object-position",
+        },
+        "list" => match value {
+            "inside" | "outside" => "list-position",
+            _ => "list-style",
+        },
+        "break" => match value {
+            "normal" | "words" | "all" | "keep" => "word-break",
+            _ => return None,
+        },
+        "content" => match value {
+            "none" => "content",
+            _ if value.starts_with('[') => "content",
+            _ => "align-content",
+        },
+        "justify" => "justify-content",
+        "items" => "align-items",
+        "self" => "align-self",
+        "align" => "vertical-align",
+        "box" => match value {
+            "border" | "content" => "box-sizing",
+            _ => "box-decoration",
+        },
+        _ => prefix,
+    };
+    Some(group.to_string())
+}
+
+// Groups a utility overrides as a whole, e.g. `p-4` replaces an earlier `px-2`.
+fn conflicting_groups(group: &str) -> &'static [&'static str] {
+    match group {
+        "p" => &["px", "py", "pt", "pr", "pb", "pl", "ps", "pe"],
+        "px" => &["pr", "pl", "ps", "pe"],
+        "py" => &["pt", "pb"],
+        "m" => &["mx", "my", "mt", "mr", "mb", "ml", "ms", "me"],
+        "mx" => &["mr", "ml", "ms", "me"],
+        "my" => &["mt", "mb"],
+        "gap" => &["gap-x", "gap-y"],
+        "size" => &["w", "h"],
+        "inset" => &[
+            "inset-x", "inset-y", "top", "right", "bottom", "left", "start", "end",
+        ],
+        "inset-x" => &["right", "left", "start", "end"],
+        "inset-y" => &["top", "bottom"],
+        "overflow" => &["overflow-x", "overflow-y"],
+        "scale" => &["scale-x", "scale-y"],
+        "font-size" => &["leading"],
+        "rounded" => &[
+            "rounded-t",
+            "rounded-r",
+            "rounded-b",
+            "rounded-l",
+            "rounded-s",
+            "rounded-e",
+            "rounded-tl",
+            "rounded-tr",
+            "rounded-br",
+            "rounded-bl",
+            "rounded-ss",
+            "rounded-se",
+            "rounded-es",
+            "rounded-ee",
+        ],
+        "rounded-t" => &["rounded-tl", "rounded-tr"],
+        "rounded-r" => &["rounded-tr", "rounded-br"],
+        "rounded-b" => &["rounded-br", "rounded-bl"],
+        "rounded-l" => &["rounded-tl", "rounded-bl"],
+        "rounded-s" => &["rounded-ss", "rounded-es"],
+        "rounded-e" => &["rounded-se", "rounded-ee"],
+        "border-w" => &[
+            "border-w-x",
+            "border-w-y",
+            "border-w-t",
+            "border-w-r",
+            "border-w-b",
+            "border-w-l",
+            "border-w-s",
+            "border-w-e",
+        ],
+        "border-w-x" => &["border-w-r", "border-w-l"],
+        "border-w-y" => &["border-w-t", "border-w-b"],
+        "border-color" => &[
+            "border-color-x",
+            "border-color-y",
+            "border-color-t",
+            "border-color-r",
+            "border-color-b",
+            "border-color-l",
+            "border-color-s",
+            "border-color-e",
+        ],
+        "border-color-x" => &["border-color-r", "border-color-l"],
+        "border-color-y" => &["border-color-t", "border-color-b"],
+        _ => &[],
+    }
+}
+
+fn is_number(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == '.')
+}
+
+// Arbitrary lengths such as `[3px]`, `[1.5rem]` or `[length:var(--x)]`
+fn is_length(value: &str) -> bool {
+    let Some(inner) = value.strip_prefix('[') else {
+        return false;
+    };
+    inner.starts_with("length:")
+        || inner.starts_with("calc(")
+        || inner.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tw_merge;
+    use yew::classes;
+
+    fn merge(classes: &'static str) -> String {
+        tw_merge(classes!(classes)).to_string()
+    }
+
+    #[test]
+    fn later_class_of_a_group_wins() {
+        assert_eq!(
+            merge("px-2 py-1 bg-white bg-red-500"),
+            "px-2 py-1 bg-red-500"
+        );
+        assert_eq!(
+            merge("border border-zinc-200 border-red-500"),
+            "border border-red-500"
+        );
+        assert_eq!(merge("flex hidden"), "hidden");
+    }
+
+    #[test]
+    fn shorthands_override_their_parts() {
+        assert_eq!(merge("px-2 pt-1 p-4"), "p-4");
+        assert_eq!(merge("p-4 px-2"), "p-4 px-2");
+        assert_eq!(merge("border-t-4 border-2"), "border-2");
+        assert_eq!(merge("rounded-tl-md rounded-lg"), "rounded-lg");
+        assert_eq!(merge("leading-6 text-sm"), "text-sm");
+    }
+
+    #[test]
+    fn distinct_groups_of_a_prefix_are_kept() {
+        assert_eq!(merge("text-sm text-red-500"), "text-sm text-red-500");
+        assert_eq!(merge("border-2 border-red-500"), "border-2 border-red-500");
+        assert_eq!(merge("flex-col flex-wrap"), "flex-col flex-wrap");
+        assert_eq!(
+            merge("shadow-md shadow-red-500"),
+            "shadow-md shadow-red-500"
+        );
+    }
+
+    #[test]
+    fn variants_only_conflict_with_the_same_variants() {
+        assert_eq!(
+            merge("bg-white hover:bg-red-500"),
+            "bg-white hover:bg-red-500"
+        );
+        assert_eq!(merge("hover:bg-white hover:bg-red-500"), "hover:bg-red-500");
+        assert_eq!(merge("dark:hover:p-2 hover:dark:p-4"), "hover:dark:p-4");
+        assert_eq!(merge("md:p-2 lg:p-4"), "md:p-2 lg:p-4");
+    }
+
+    #[test]
+    fn important_only_conflicts_with_important() {
+        assert_eq!(merge("!p-2 p-4"), "!p-2 p-4");
+        assert_eq!(merge("!p-2 !p-4"), "!p-4");
+        assert_eq!(merge("p-2! !p-4"), "!p-4");
+        assert_eq!(
+            merge("hover:!bg-white hover:!bg-red-500"),
+            "hover:!bg-red-500"
+        );
+    }
+
+    #[test]
+    fn arbitrary_values() {
+        assert_eq!(merge("w-[10px] w-[calc(100%-2rem)]"), "w-[calc(100%-2rem)]");
+        assert_eq!(
+            merge("text-[14px] text-red-500"),
+            "text-[14px] text-red-500"
+        );
+        assert_eq!(
+            merge("bg-[url(/a.png)] bg-red-500"),
+            "bg-[url(/a.png)] bg-red-500"
+        );
+        assert_eq!(
+            merge("[mask-type:luminance] [mask-type:alpha]"),
+            "[mask-type:alpha]"
+        );
+        assert_eq!(merge("[&:hover]:p-2 [&:hover]:p-4"), "[&:hover]:p-4");
+        assert_eq!(merge("-mt-2 mt-[3px]"), "mt-[3px]");
+    }
+
+    #[test]
+    fn unknown_classes_are_kept() {
+        assert_eq!(
+            merge("lucide lucide-chevron-down p-2"),
+            "lucide lucide-chevron-down p-2"
+        );
+        assert_eq!(merge("card p-2 card-body"), "card p-2 card-body");
+    }
+}
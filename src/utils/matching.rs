@@ -0,0 +1,95 @@
+// Text matching shared by the searchable lists, e.g. `Combobox` and `Command`
+
+use yew::{html, Classes, Html};
+
+/// Matches `query` against `text` as an in-order subsequence, ignoring case.
+///
+/// Returns a score, higher being better, and the indices of the matched characters.
+/// Matches at the start of the text or of a word and runs of adjacent characters score higher.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<(i32, Vec<usize>)> {
+    let query: Vec<char> = query.chars().map(fold_case).collect();
+    let original: Vec<char> = text.chars().collect();
+    let text: Vec<char> = original.iter().copied().map(fold_case).collect();
+    let first = *query.first()?;
+    let is_word_start = |index: usize| {
+        index == 0
+            || !original[index - 1].is_alphanumeric()
+            || (original[index - 1].is_lowercase() && original[index].is_uppercase())
+    };
+    let mut best: Option<(i32, Vec<usize>)> = None;
+    for start in (0..text.len()).filter(|index| text[*index] == first) {
+        let mut matched = vec![start];
+        let mut position = start + 1;
+        for wanted in &query[1..] {
+            // Prefer the next character, then a word start, then any occurrence.
+            let next = if text.get(position) == Some(wanted) {
+                Some(position)
+            } else {
+                let mut candidates = (position..text.len()).filter(|index| text[*index] == *wanted);
+                let fallback = candidates.clone().next();
+                candidates.find(|index| is_word_start(*index)).or(fallback)
+            };
+            let Some(index) = next else {
+                matched.clear();
+                break;
+            };
+            matched.push(index);
+            position = index + 1;
+        }
+        if matched.is_empty() {
+            break;
+        }
+        let mut score = 0;
+        for (n, index) in matched.iter().enumerate() {
+            score += 1;
+            if *index == 0 {
+                score += 8;
+            } else if is_word_start(*index) {
+                score += 5;
+            }
+            if n > 0 {
+                let gap = (index - matched[n - 1] - 1) as i32;
+                score += if gap == 0 { 4 } else { -gap.min(5) };
+            }
+        }
+        if best.as_ref().is_none_or(|(best, _)| score > *best) {
+            best = Some((score, matched));
+        }
+    }
+    best
+}
+
+// Wraps runs of matched characters in `<mark>` elements.
+pub(crate) fn highlight(label: &str, matched: &[usize], class: Classes) -> Html {
+    if matched.is_empty() {
+        return html! { { label } };
+    }
+    let mut parts = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (index, c) in label.chars().enumerate() {
+        let is_matched = matched.contains(&index);
+        if is_matched != run_matched && !run.is_empty() {
+            parts.push((std::mem::take(&mut run), run_matched));
+        }
+        run_matched = is_matched;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        parts.push((run, run_matched));
+    }
+    parts
+        .into_iter()
+        .map(|(text, is_matched)| {
+            if is_matched {
+                html! { <mark class={class.clone()}>{ text }</mark> }
+            } else {
+                html! { { text } }
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn fold_case(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
@@ -1,3 +1,14 @@
 mod classes;
+#[cfg(any(feature = "Combobox", feature = "Command"))]
+mod matching;
 
-pub use classes::{combine_classes, conditional_class};
\ No newline at end of file
+pub use classes::{combine_classes, conditional_class, tw_merge};
+#[cfg(feature = "Combobox")]
+pub(crate) use matching::fold_case;
+#[cfg(any(feature = "Combobox", feature = "Command"))]
+pub use matching::fuzzy_match;
+#[cfg(any(feature = "Combobox", feature = "Command"))]
+pub(crate) use matching::highlight;
+
+// Utility components, previously reachable here through `components::*`
+pub use crate::components::utils::*;
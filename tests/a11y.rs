@@ -0,0 +1,216 @@
+//! Accessibility checks of the rendered components, see `wonopui::testing::a11y`.
+
+use wonopui::testing::a11y::{assert_accessible, assert_role, check};
+use wonopui::testing::render;
+use wonopui::*;
+use yew::prelude::*;
+
+fn copy_icon() -> Html {
+    html! {
+        <svg aria-hidden="true" viewBox="0 0 24 24"><rect x="9" y="9" width="13" height="13" /></svg>
+    }
+}
+
+fn rules(html: &str) -> Vec<&'static str> {
+    check(html).into_iter().map(|issue| issue.rule).collect()
+}
+
+#[test]
+fn buttons() {
+    assert_accessible(&render(|| {
+        html! {
+            <>
+                <Button variant={ButtonVariant::Primary}>{"Save"}</Button>
+                <Button disabled=true>{"Disabled"}</Button>
+            </>
+        }
+    }));
+}
+
+#[cfg(feature = "CopyButton")]
+#[test]
+fn copy_buttons() {
+    assert_accessible(&render(|| {
+        html! {
+            <>
+                <CopyButton copy_text="cargo add wonopui" />
+                <CopyButton copy_text="cargo add wonopui" aria_label="Copy command">{copy_icon()}</CopyButton>
+            </>
+        }
+    }));
+}
+
+#[cfg(feature = "CopyButton")]
+#[test]
+fn icon_only_copy_button_needs_a_label() {
+    let html =
+        render(|| html! { <CopyButton copy_text="cargo add wonopui">{copy_icon()}</CopyButton> });
+    assert_eq!(rules(&html), ["button-name"]);
+}
+
+#[test]
+fn mobile_menu_button() {
+    assert_accessible(&render(|| {
+        html! {
+            <LayoutProvider>
+                <MobileMenuButton>{copy_icon()}</MobileMenuButton>
+            </LayoutProvider>
+        }
+    }));
+}
+
+#[test]
+fn resizable_sidebar() {
+    let html = render(|| {
+        html! {
+            <LayoutProvider storage_key="layout" shortcuts=true>
+                <Sidebar resizable=true>{"Menu"}</Sidebar>
+            </LayoutProvider>
+        }
+    });
+    assert_accessible(&html);
+    assert_role(&html, "separator");
+}
+
+#[test]
+fn panel_group() {
+    let html = render(|| {
+        html! {
+            <PanelGroup storage_key="editor">
+                <Panel id="files" default_size={20.} min_size={10.} collapsible=true>{"Files"}</Panel>
+                <Panel id="editor">{"Editor"}</Panel>
+                <Panel default_size={25.} max_size={40.}>{"Preview"}</Panel>
+            </PanelGroup>
+        }
+    });
+    assert_accessible(&html);
+    assert_role(&html, "separator");
+}
+
+#[test]
+fn form_fields() {
+    assert_accessible(&render(|| {
+        html! {
+            <>
+                <Label for_id="name">{"Name"}</Label>
+                <Input id="name" />
+                <Input id="search" placeholder="Search" />
+                <Label for_id="bio" description="Shown on your profile">{"Bio"}</Label>
+                <Textarea id="bio" />
+                <Label for_id="terms">{"Accept the terms"}</Label>
+                <Checkbox id="terms" />
+                <Label for_id="notifications">{"Notifications"}</Label>
+                <SwitchButton id="notifications" checked={Some(true)} />
+                <Select<SelectOption>
+                    placeholder="Pick a fruit"
+                    options={vec![SelectOption { value: "apple".into(), label: "Apple".into() }]}
+                />
+            </>
+        }
+    }));
+}
+
+#[test]
+fn unlabelled_fields() {
+    let html = render(|| {
+        html! {
+            <>
+                <Input id="name" />
+                <Checkbox id="terms" />
+                <Label for_id="missing">{"Missing"}</Label>
+            </>
+        }
+    });
+    assert_eq!(rules(&html), ["label", "label", "idref"]);
+}
+
+#[test]
+fn tabs() {
+    let html = render(|| {
+        html! {
+            <Tabs default_value="account">
+                <TabsList>
+                    <TabsTrigger value="account">{"Account"}</TabsTrigger>
+                    <TabsTrigger value="password">{"Password"}</TabsTrigger>
+                </TabsList>
+                <TabsContent value="account">{"Account settings"}</TabsContent>
+                <TabsContent value="password">{"Password settings"}</TabsContent>
+            </Tabs>
+        }
+    });
+    assert_accessible(&html);
+    assert_role(&html, "tablist");
+    assert_role(&html, "tabpanel");
+}
+
+#[test]
+fn open_dialog() {
+    let html = render(|| {
+        html! {
+            <DialogProvider default_open="confirm">
+                <DialogTrigger id="confirm">
+                    <Button>{"Delete project"}</Button>
+                </DialogTrigger>
+                <Dialog id="confirm">
+                    <DialogHeader>
+                        <DialogTitle>{"Delete the project?"}</DialogTitle>
+                    </DialogHeader>
+                    <DialogBody>{"This can't be undone."}</DialogBody>
+                    <DialogFooter>
+                        <Button variant={ButtonVariant::Danger}>{"Delete"}</Button>
+                    </DialogFooter>
+                </Dialog>
+            </DialogProvider>
+        }
+    });
+    assert_accessible(&html);
+    assert_role(&html, "dialog");
+}
+
+#[test]
+fn select() {
+    let html = render(|| {
+        html! {
+            <Select<SelectOption>
+                placeholder="Pick a fruit"
+                groups={vec![SelectGroup {
+                    label: "Fruit".into(),
+                    options: vec![SelectOption { value: "apple".into(), label: "Apple".into() }],
+                }]}
+            />
+        }
+    });
+    assert_accessible(&html);
+    assert_role(&html, "combobox");
+}
+
+#[test]
+fn select_ids_restart_per_render() {
+    let select = || html! { <Select<SelectOption> placeholder="Pick a fruit" options={vec![]} /> };
+    let first = render(select);
+    assert!(first.contains("select-0"), "{first}");
+    assert_eq!(render(select), first);
+}
+
+#[test]
+fn structure_rules() {
+    let html = r#"
+        <div id="a"><button role="tab">One</button></div>
+        <div id="a" role="tablist"></div>
+        <li role="option" aria-selected="true">Apple</li>
+        <div aria-selected="true"></div>
+        <div role="dialog" aria-modal="true" aria-labelledby="title"></div>
+    "#;
+    assert_eq!(
+        rules(html),
+        [
+            "duplicate-id",
+            "aria-required-parent",
+            "duplicate-id",
+            "aria-required-children",
+            "aria-required-parent",
+            "aria-allowed-attr",
+            "idref",
+        ]
+    );
+}
@@ -0,0 +1,55 @@
+//! Breakpoints as a server render sees them, see `wonopui::Breakpoint`.
+
+use wonopui::testing::render;
+use wonopui::*;
+use yew::prelude::*;
+
+#[function_component(Columns)]
+fn columns() -> Html {
+    let breakpoint = use_breakpoint();
+    let columns = use_breakpoint_value(
+        BreakpointMap::new(1)
+            .with(Breakpoint::Xl, 4)
+            .with(Breakpoint::Md, 2),
+    );
+    html! {
+        <p>{format!("{} {}", breakpoint.map_or("none", Breakpoint::name), columns)}</p>
+    }
+}
+
+#[test]
+fn widths_follow_tailwind() {
+    let widths: Vec<&str> = Breakpoint::ALL
+        .into_iter()
+        .map(Breakpoint::min_width)
+        .collect();
+    assert_eq!(widths, ["640px", "768px", "1024px", "1280px", "1536px"]);
+    assert_eq!(Breakpoint::Lg.media_query(), "(min-width: 1024px)");
+    assert_eq!(Breakpoint::Xxl.name(), "2xl");
+    assert!(Some(Breakpoint::Sm) > None);
+}
+
+#[test]
+fn map_picks_the_largest_breakpoint_reached() {
+    let map = BreakpointMap::new("base")
+        .with(Breakpoint::Lg, "lg")
+        .with(Breakpoint::Sm, "sm")
+        .with(Breakpoint::Lg, "large");
+    assert_eq!(*map.get(None), "base");
+    assert_eq!(*map.get(Some(Breakpoint::Md)), "sm");
+    assert_eq!(*map.get(Some(Breakpoint::Lg)), "large");
+    assert_eq!(*map.get(Some(Breakpoint::Xxl)), "large");
+}
+
+#[test]
+fn server_render_uses_defaults() {
+    assert!(render(|| html! { <Columns /> }).contains("none 1"));
+    let html = render(|| {
+        html! {
+            <MediaQueryDefaults matching={vec![Breakpoint::Lg.media_query().into()]}>
+                <Columns />
+            </MediaQueryDefaults>
+        }
+    });
+    assert!(html.contains("lg 2"), "{}", html);
+}
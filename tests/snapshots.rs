@@ -0,0 +1,115 @@
+//! Snapshots of the rendered components, see `wonopui::testing`. Run with
+//! `cargo test --features testing,Alert,Badge,Button,Table` and add `ThemeProvider` (with
+//! `Input,Label,Select,TagInput`) for the themed ones.
+
+#[cfg(feature = "ThemeProvider")]
+use wonopui::testing::render_with_theme;
+use wonopui::testing::{assert_snapshot, render};
+use wonopui::*;
+use yew::prelude::*;
+
+#[test]
+fn button_variants() {
+    let html = render(|| {
+        html! {
+            <>
+                <Button variant={ButtonVariant::Primary}>{"Primary"}</Button>
+                <Button variant={ButtonVariant::Secondary}>{"Secondary"}</Button>
+                <Button variant={ButtonVariant::Success}>{"Success"}</Button>
+                <Button variant={ButtonVariant::Warning}>{"Warning"}</Button>
+                <Button variant={ButtonVariant::Danger}>{"Danger"}</Button>
+                <Button variant={ButtonVariant::Ghost}>{"Ghost"}</Button>
+                <Button>{"Default"}</Button>
+            </>
+        }
+    });
+    assert_snapshot("button_variants", &html);
+}
+
+#[test]
+fn button_sizes() {
+    let html = render(|| {
+        html! {
+            <>
+                <Button size={ButtonSize::Small}>{"Small"}</Button>
+                <Button size={ButtonSize::Large}>{"Large"}</Button>
+                <Button disabled=true kind="submit">{"Disabled"}</Button>
+            </>
+        }
+    });
+    assert_snapshot("button_sizes", &html);
+}
+
+#[test]
+fn button_class_overrides_theme() {
+    let html = render(|| html! { <Button class="bg-red-500">{"Delete"}</Button> });
+    assert_snapshot("button_class_overrides_theme", &html);
+}
+
+#[test]
+fn alert() {
+    let html = render(|| {
+        html! {
+            <>
+                <Alert alert_type={AlertType::Success}>
+                    <AlertTitle>{"Saved"}</AlertTitle>
+                    <AlertDescription>{"Your changes are live."}</AlertDescription>
+                </Alert>
+                <Alert alert_type={AlertType::Error}>
+                    <AlertTitle>{"Failed"}</AlertTitle>
+                </Alert>
+            </>
+        }
+    });
+    assert_snapshot("alert", &html);
+}
+
+#[test]
+fn badge() {
+    let html = render(|| {
+        html! {
+            <>
+                <Badge label="Default" />
+                <Badge label="Info" badge_type={BadgeType::Info} />
+                <Badge label="Error" badge_type={BadgeType::Error} />
+            </>
+        }
+    });
+    assert_snapshot("badge", &html);
+}
+
+#[test]
+fn table() {
+    let html = render(|| {
+        html! {
+            <Table>
+                <TableHead>
+                    <TableRow>
+                        <TableCell>{"Name"}</TableCell>
+                        <TableCell>{"Role"}</TableCell>
+                    </TableRow>
+                </TableHead>
+                <TableBody>
+                    <TableRow>
+                        <TableCell>{"Ada"}</TableCell>
+                        <TableCell colspan={Some(1)}>{"Engineer"}</TableCell>
+                    </TableRow>
+                </TableBody>
+            </Table>
+        }
+    });
+    assert_snapshot("table", &html);
+}
+
+#[cfg(feature = "ThemeProvider")]
+#[test]
+fn button_with_theme() {
+    let theme = Theme::new(
+        "rounded",
+        [("button_primary", "rounded-full bg-emerald-600 text-white")],
+    );
+    let html = render_with_theme(theme, || {
+        html! { <Button variant={ButtonVariant::Primary}>{"Primary"}</Button> }
+    });
+    assert_snapshot("button_with_theme", &html);
+}